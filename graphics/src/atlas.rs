@@ -0,0 +1,91 @@
+//! Packing of rectangles into a texture atlas.
+//!
+//! An [`AtlasAllocator`] hands out non-overlapping areas of a texture, for
+//! things like glyph caches and sprite sheets. It only does the bookkeeping;
+//! uploading pixels into the allocated areas is up to the caller.
+//!
+//! ```
+//! # use crossd_graphics::atlas::AtlasAllocator;
+//! # use crossd_graphics::geometry::Size2;
+//! #
+//! let mut atlas = AtlasAllocator::new(Size2::new(256, 256));
+//!
+//! let (a, rect) = atlas.allocate(Size2::new(100, 20)).unwrap();
+//! let (_, other) = atlas.allocate(Size2::new(100, 16)).unwrap();
+//! assert!(!rect.intersects(other));
+//!
+//! atlas.deallocate(a);
+//! assert_eq!(atlas.stats().allocations, 1);
+//! ```
+
+use crate::geometry::{Physical, Size2};
+
+/// Implementations for [`AtlasAllocator`], [`AllocId`] and [`AtlasStats`].
+mod allocator;
+
+/// Allocates rectangles in a texture using shelf packing.
+///
+/// The texture is split into horizontal shelves, each as tall as the first
+/// rectangle placed in it. Rectangles are placed side by side in the shelf
+/// wasting the least height, and space is reused once freed.
+#[derive(Debug, Clone)]
+pub struct AtlasAllocator {
+    size: Size2<u32, Physical>,
+    /// Shelves from top to bottom.
+    shelves: Vec<Shelf>,
+    /// The location of each allocation, by [`AllocId::index`].
+    slots: Vec<Slot>,
+    /// Indices of unused slots.
+    free_slots: Vec<u32>,
+}
+
+/// Identifies an allocation in an [`AtlasAllocator`].
+///
+/// Ids of deallocated rectangles are never confused with later ones.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct AllocId {
+    index: u32,
+    /// The amount of times the slot was used before.
+    generation: u32,
+}
+
+/// Statistics on the space used in an [`AtlasAllocator`].
+#[derive(Debug, Default, Clone, Copy, PartialEq)]
+pub struct AtlasStats {
+    /// The amount of allocated rectangles.
+    pub allocations: usize,
+    /// The area of the atlas, in pixels.
+    pub total_area: u64,
+    /// The area of all allocated rectangles.
+    pub allocated_area: u64,
+    /// The area of the shelves, allocated or not.
+    pub shelf_area: u64,
+}
+
+/// A row of rectangles.
+#[derive(Debug, Clone)]
+struct Shelf {
+    y: u32,
+    height: u32,
+    /// Spans covering the width of the atlas from left to right, with no two
+    /// free spans next to each other.
+    spans: Vec<Span>,
+}
+
+/// A part of a [`Shelf`], either free or allocated.
+#[derive(Debug, Clone, Copy)]
+struct Span {
+    x: u32,
+    width: u32,
+    /// The slot of the allocation, or `None` if the span is free.
+    slot: Option<u32>,
+}
+
+/// Where an allocation is.
+#[derive(Debug, Clone, Copy)]
+struct Slot {
+    generation: u32,
+    /// The shelf, `x` and size of the allocation, or `None` if the slot is
+    /// unused.
+    alloc: Option<(usize, u32, Size2<u32, Physical>)>,
+}
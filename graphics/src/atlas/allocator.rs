@@ -0,0 +1,476 @@
+use super::{AllocId, AtlasAllocator, AtlasStats, Shelf, Slot, Span};
+use crate::geometry::{Physical, Point2, Rect, Size2};
+
+impl AtlasAllocator {
+    /// A new empty atlas of the given size.
+    #[inline]
+    #[must_use]
+    pub const fn new(size: Size2<u32, Physical>) -> Self {
+        Self { size, shelves: Vec::new(), slots: Vec::new(), free_slots: Vec::new() }
+    }
+
+    /// The size of the atlas.
+    #[inline]
+    #[must_use]
+    pub const fn size(&self) -> Size2<u32, Physical> {
+        self.size
+    }
+
+    /// Whether nothing is allocated.
+    #[inline]
+    #[must_use]
+    pub fn is_empty(&self) -> bool {
+        self.slots.len() == self.free_slots.len()
+    }
+
+    /// Allocate a rectangle of the given size.
+    ///
+    /// Returns `None` if the size is empty, or there is no space left for it.
+    /// [`AtlasAllocator::grow`] can make more space.
+    ///
+    /// ```
+    /// # use crossd_graphics::atlas::AtlasAllocator;
+    /// # use crossd_graphics::geometry::{Point2, Rect, Size2};
+    /// #
+    /// let mut atlas = AtlasAllocator::new(Size2::new(64, 64));
+    ///
+    /// let (_, a) = atlas.allocate(Size2::new(40, 10)).unwrap();
+    /// let (_, b) = atlas.allocate(Size2::new(20, 10)).unwrap();
+    /// let (_, c) = atlas.allocate(Size2::new(40, 10)).unwrap();
+    ///
+    /// // `b` fits next to `a`, `c` needs a new shelf
+    /// assert_eq!(a, Rect::new(Point2::new(0, 0), Size2::new(40, 10)));
+    /// assert_eq!(b, Rect::new(Point2::new(40, 0), Size2::new(20, 10)));
+    /// assert_eq!(c, Rect::new(Point2::new(0, 10), Size2::new(40, 10)));
+    ///
+    /// assert_eq!(atlas.allocate(Size2::new(65, 1)), None);
+    /// ```
+    pub fn allocate(
+        &mut self,
+        size: Size2<u32, Physical>,
+    ) -> Option<(AllocId, Rect<u32, Physical>)> {
+        if size.w == 0 || size.h == 0 || size.w > self.size.w || size.h > self.size.h {
+            return None;
+        }
+
+        // the shelf wasting the least height with a free span that is wide
+        // enough
+        let best = self
+            .shelves
+            .iter()
+            .enumerate()
+            .filter(|(_, shelf)| shelf.height >= size.h)
+            .filter_map(|(i, shelf)| {
+                let span = shelf
+                    .spans
+                    .iter()
+                    .position(|span| span.slot.is_none() && span.width >= size.w)?;
+
+                Some((i, span, shelf.height - size.h))
+            })
+            .min_by_key(|(_, _, waste)| *waste);
+
+        let bottom = self.shelves.last().map_or(0, |shelf| shelf.y + shelf.height);
+        let fits_new_shelf = self.size.h - bottom >= size.h;
+
+        let (shelf, span) = match best {
+            // only waste more than the height of the rectangle if there's no
+            // other way
+            Some((shelf, span, waste)) if waste <= size.h || !fits_new_shelf => {
+                (shelf, span)
+            },
+            _ if fits_new_shelf => {
+                self.shelves.push(Shelf {
+                    y: bottom,
+                    height: size.h,
+                    spans: vec![Span { x: 0, width: self.size.w, slot: None }],
+                });
+
+                (self.shelves.len() - 1, 0)
+            },
+            _ => return None,
+        };
+
+        let index = self.free_slots.pop().unwrap_or_else(|| {
+            self.slots.push(Slot { generation: 0, alloc: None });
+            (self.slots.len() - 1) as u32
+        });
+
+        let Shelf { y, spans, .. } = &mut self.shelves[shelf];
+        let free = spans[span];
+
+        spans[span] = Span { x: free.x, width: size.w, slot: Some(index) };
+
+        if free.width > size.w {
+            let rest =
+                Span { x: free.x + size.w, width: free.width - size.w, slot: None };
+            spans.insert(span + 1, rest);
+        }
+
+        let slot = &mut self.slots[index as usize];
+        slot.alloc = Some((shelf, free.x, size));
+
+        let id = AllocId { index, generation: slot.generation };
+
+        Some((id, Rect::new(Point2::new(free.x, *y), size)))
+    }
+
+    /// Free an allocated rectangle, so that it's space can be reused.
+    ///
+    /// Returns `false` if the rectangle was already deallocated.
+    ///
+    /// ```
+    /// # use crossd_graphics::atlas::AtlasAllocator;
+    /// # use crossd_graphics::geometry::Size2;
+    /// #
+    /// let mut atlas = AtlasAllocator::new(Size2::new(64, 64));
+    ///
+    /// let (id, rect) = atlas.allocate(Size2::new(64, 64)).unwrap();
+    /// assert_eq!(atlas.allocate(Size2::new(1, 1)), None);
+    ///
+    /// assert!(atlas.deallocate(id));
+    /// assert!(!atlas.deallocate(id));
+    /// assert_eq!(atlas.allocate(Size2::new(64, 64)).unwrap().1, rect);
+    /// ```
+    pub fn deallocate(&mut self, id: AllocId) -> bool {
+        let Some(slot) = self.slots.get_mut(id.index as usize) else {
+            return false;
+        };
+
+        let alloc = slot.alloc.filter(|_| slot.generation == id.generation);
+        let Some((shelf, x, _)) = alloc else {
+            return false;
+        };
+
+        slot.alloc = None;
+        slot.generation = slot.generation.wrapping_add(1);
+        self.free_slots.push(id.index);
+
+        let spans = &mut self.shelves[shelf].spans;
+        let Ok(i) = spans.binary_search_by_key(&x, |span| span.x) else {
+            unreachable!("allocations always start a span");
+        };
+
+        spans[i].slot = None;
+
+        // merge with free neighbours
+        if spans.get(i + 1).is_some_and(|span| span.slot.is_none()) {
+            spans[i].width += spans.remove(i + 1).width;
+        }
+
+        if i > 0 && spans[i - 1].slot.is_none() {
+            spans[i - 1].width += spans.remove(i).width;
+        }
+
+        // empty shelves at the bottom can be replaced with any height
+        while self.shelves.last().is_some_and(Shelf::is_empty) {
+            self.shelves.pop();
+        }
+
+        true
+    }
+
+    /// The rectangle of an allocation, or `None` if it was deallocated.
+    #[must_use]
+    pub fn get(&self, id: AllocId) -> Option<Rect<u32, Physical>> {
+        let slot = self.slots.get(id.index as usize)?;
+        let (shelf, x, size) = slot.alloc.filter(|_| slot.generation == id.generation)?;
+
+        Some(Rect::new(Point2::new(x, self.shelves[shelf].y), size))
+    }
+
+    /// Grow the atlas to at least the given size, keeping all allocations
+    /// where they are.
+    ///
+    /// The atlas never shrinks, so a smaller width or height is ignored.
+    ///
+    /// ```
+    /// # use crossd_graphics::atlas::AtlasAllocator;
+    /// # use crossd_graphics::geometry::Size2;
+    /// #
+    /// let mut atlas = AtlasAllocator::new(Size2::new(32, 32));
+    ///
+    /// atlas.allocate(Size2::new(32, 32)).unwrap();
+    /// assert_eq!(atlas.allocate(Size2::new(32, 32)), None);
+    ///
+    /// atlas.grow(Size2::new(64, 32));
+    /// assert_eq!(atlas.allocate(Size2::new(32, 32)).unwrap().1.loc.x, 32);
+    /// assert_eq!(atlas.size(), Size2::new(64, 32));
+    /// ```
+    pub fn grow(&mut self, size: Size2<u32, Physical>) {
+        let size = self.size.max(size);
+        let extra = size.w - self.size.w;
+
+        if extra > 0 {
+            for shelf in &mut self.shelves {
+                match shelf.spans.last_mut() {
+                    Some(span) if span.slot.is_none() => span.width += extra,
+                    _ => shelf.spans.push(Span {
+                        x: self.size.w,
+                        width: extra,
+                        slot: None,
+                    }),
+                }
+            }
+        }
+
+        self.size = size;
+    }
+
+    /// Deallocate everything.
+    ///
+    /// All previous [`AllocId`]s are invalidated.
+    pub fn clear(&mut self) {
+        self.shelves.clear();
+        self.free_slots.clear();
+
+        for (index, slot) in self.slots.iter_mut().enumerate().rev() {
+            if slot.alloc.take().is_some() {
+                slot.generation = slot.generation.wrapping_add(1);
+            }
+
+            self.free_slots.push(index as u32);
+        }
+    }
+
+    /// Statistics on the space used in the atlas.
+    #[must_use]
+    pub fn stats(&self) -> AtlasStats {
+        let area = |size: Size2<u32, Physical>| u64::from(size.w) * u64::from(size.h);
+
+        AtlasStats {
+            allocations: self.slots.len() - self.free_slots.len(),
+            total_area: area(self.size),
+            allocated_area: self
+                .slots
+                .iter()
+                .filter_map(|slot| slot.alloc)
+                .map(|(_, _, size)| area(size))
+                .sum(),
+            shelf_area: self
+                .shelves
+                .iter()
+                .map(|shelf| area(Size2::new(self.size.w, shelf.height)))
+                .sum(),
+        }
+    }
+}
+
+impl AtlasStats {
+    /// The area that isn't allocated.
+    #[inline]
+    #[must_use]
+    pub const fn free_area(self) -> u64 {
+        self.total_area - self.allocated_area
+    }
+
+    /// The part of the free area that is inside shelves, between `0` and `1`.
+    ///
+    /// Space in shelves can only be used by rectangles no taller than the
+    /// shelf, so a high fragmentation means large rectangles may not fit even
+    /// though there is enough free area. Rebuilding the atlas from scratch
+    /// reduces it.
+    ///
+    /// ```
+    /// # use crossd_graphics::atlas::AtlasAllocator;
+    /// # use crossd_graphics::geometry::Size2;
+    /// #
+    /// let mut atlas = AtlasAllocator::new(Size2::new(100, 100));
+    ///
+    /// let (a, _) = atlas.allocate(Size2::new(50, 50)).unwrap();
+    /// atlas.allocate(Size2::new(50, 50)).unwrap();
+    /// assert_eq!(atlas.stats().fragmentation(), 0.0);
+    ///
+    /// // the space of `a` can only be reused by rectangles up to 50 tall
+    /// atlas.deallocate(a);
+    /// assert_eq!(atlas.stats().fragmentation(), 2500.0 / 7500.0);
+    /// ```
+    #[must_use]
+    pub fn fragmentation(self) -> f32 {
+        match self.free_area() {
+            0 => 0.0,
+            free => (self.shelf_area - self.allocated_area) as f32 / free as f32,
+        }
+    }
+}
+
+impl Shelf {
+    /// Whether nothing is allocated in the shelf.
+    fn is_empty(&self) -> bool {
+        matches!(self.spans[..], [Span { slot: None, .. }])
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::super::{AllocId, AtlasAllocator};
+    use crate::geometry::{Physical, Point2, Rect, Size2};
+
+    fn size(w: u32, h: u32) -> Size2<u32, Physical> {
+        Size2::new(w, h)
+    }
+
+    /// A xorshift generator, so that the sizes are the same every run.
+    struct Rng(u32);
+
+    impl Rng {
+        /// A number from `1` to `max`.
+        fn next(&mut self, max: u32) -> u32 {
+            self.0 ^= self.0 << 13;
+            self.0 ^= self.0 >> 17;
+            self.0 ^= self.0 << 5;
+
+            1 + self.0 % max
+        }
+    }
+
+    /// Assert that the allocations are where they were allocated, inside the
+    /// atlas and not overlapping.
+    #[track_caller]
+    fn assert_valid(atlas: &AtlasAllocator, allocs: &[(AllocId, Rect<u32, Physical>)]) {
+        let bounds = Rect::new(Point2::new(0, 0), atlas.size());
+
+        for (i, (id, rect)) in allocs.iter().enumerate() {
+            assert_eq!(atlas.get(*id), Some(*rect));
+            assert_eq!(rect.union(bounds), bounds, "{rect:?} is outside the atlas");
+
+            for (_, other) in &allocs[i + 1..] {
+                assert!(!rect.intersects(*other), "{rect:?} overlaps {other:?}");
+            }
+        }
+
+        let stats = atlas.stats();
+        let area: u64 = allocs.iter().map(|(_, r)| u64::from(r.size.w * r.size.h)).sum();
+
+        assert_eq!(stats.allocations, allocs.len());
+        assert_eq!(stats.allocated_area, area);
+    }
+
+    #[test]
+    fn random() {
+        let mut atlas = AtlasAllocator::new(size(256, 256));
+        let mut allocs = Vec::new();
+        let mut rng = Rng(0x2545_f491);
+
+        for _ in 0..2000 {
+            if allocs.is_empty() || rng.next(3) > 1 {
+                if let Some(alloc) = atlas.allocate(size(rng.next(40), rng.next(40))) {
+                    allocs.push(alloc);
+                }
+            } else {
+                let (id, _) =
+                    allocs.swap_remove(rng.next(allocs.len() as u32) as usize - 1);
+
+                assert!(atlas.deallocate(id));
+            }
+
+            assert_valid(&atlas, &allocs);
+        }
+
+        for (id, _) in allocs.drain(..) {
+            assert!(atlas.deallocate(id));
+        }
+
+        assert!(atlas.is_empty());
+        assert_eq!(atlas.stats().shelf_area, 0);
+    }
+
+    #[test]
+    fn reuse() {
+        let mut atlas = AtlasAllocator::new(size(64, 64));
+        let allocs: Vec<_> =
+            (0..16).map(|_| atlas.allocate(size(16, 16)).unwrap()).collect();
+
+        assert_eq!(atlas.allocate(size(1, 1)), None);
+
+        let (id, rect) = allocs[5];
+        atlas.deallocate(id);
+
+        // the freed space fits anything up to it's size
+        let (_, small) = atlas.allocate(size(8, 16)).unwrap();
+        let (_, rest) = atlas.allocate(size(8, 10)).unwrap();
+
+        assert_eq!(small, Rect::new(rect.loc, size(8, 16)));
+        assert_eq!(rest.loc, Point2::new(rect.loc.x + 8, rect.loc.y));
+        // spans are only split across, so the space below `rest` is lost
+        assert_eq!(atlas.allocate(size(1, 1)), None);
+    }
+
+    #[test]
+    fn stale_ids() {
+        let mut atlas = AtlasAllocator::new(size(64, 64));
+        let (old, _) = atlas.allocate(size(10, 10)).unwrap();
+
+        assert!(atlas.deallocate(old));
+
+        // the slot of `old` is reused
+        let (new, rect) = atlas.allocate(size(20, 20)).unwrap();
+
+        assert_ne!(new, old);
+        assert_eq!(atlas.get(old), None);
+        assert!(!atlas.deallocate(old));
+        assert_eq!(atlas.get(new), Some(rect));
+
+        atlas.clear();
+
+        assert!(atlas.is_empty());
+        assert_eq!(atlas.get(new), None);
+        assert!(!atlas.deallocate(new));
+    }
+
+    #[test]
+    fn grow() {
+        let mut atlas = AtlasAllocator::new(size(32, 32));
+        let mut allocs = vec![
+            atlas.allocate(size(20, 10)).unwrap(),
+            atlas.allocate(size(12, 10)).unwrap(),
+            atlas.allocate(size(10, 22)).unwrap(),
+        ];
+
+        assert_eq!(atlas.allocate(size(32, 10)), None);
+
+        atlas.grow(size(64, 64));
+        assert_valid(&atlas, &allocs);
+
+        // the new space is to the right of full shelves, and below them
+        allocs.push(atlas.allocate(size(32, 10)).unwrap());
+        allocs.push(atlas.allocate(size(64, 32)).unwrap());
+
+        assert_eq!(allocs[3].1.loc, Point2::new(32, 0));
+        assert_eq!(allocs[4].1.loc, Point2::new(0, 32));
+        assert_valid(&atlas, &allocs);
+
+        // growing to a smaller size does nothing
+        atlas.grow(size(16, 128));
+        assert_eq!(atlas.size(), size(64, 128));
+        assert_valid(&atlas, &allocs);
+    }
+
+    #[test]
+    fn stats() {
+        let mut atlas = AtlasAllocator::new(size(100, 100));
+
+        atlas.allocate(size(30, 10)).unwrap();
+        atlas.allocate(size(20, 10)).unwrap();
+        let (tall, _) = atlas.allocate(size(50, 20)).unwrap();
+
+        let stats = atlas.stats();
+
+        assert_eq!(stats.allocations, 3);
+        assert_eq!(stats.total_area, 10_000);
+        assert_eq!(stats.allocated_area, 1500);
+        assert_eq!(stats.shelf_area, 3000);
+        assert_eq!(stats.free_area(), 8500);
+        assert_eq!(stats.fragmentation(), 1500.0 / 8500.0);
+
+        // the empty shelf at the bottom is removed
+        atlas.deallocate(tall);
+        let stats = atlas.stats();
+
+        assert_eq!(stats.shelf_area, 1000);
+        assert_eq!(stats.fragmentation(), 500.0 / 9500.0);
+
+        atlas.clear();
+        assert_eq!(atlas.stats().fragmentation(), 0.0);
+    }
+}
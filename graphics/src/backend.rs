@@ -18,6 +18,8 @@ use wgpu::{
     TextureFormat,
 };
 
+use crate::color::{linear_to_srgb, LinearRgba, PremulColor};
+
 /// A low-level abstsraction over Wgpu.
 pub struct Backend {
     instance: Instance,
@@ -111,6 +113,35 @@ impl Backend {
     pub fn format(&self) -> TextureFormat {
         self.format
     }
+
+    /// Whether shaders write linear colors to the texture format.
+    ///
+    /// sRGB formats encode linear colors themselves, and float formats like
+    /// [`TextureFormat::Rgba16Float`] hold linear colors with extended range.
+    /// Other formats are written sRGB encoded colors.
+    #[must_use]
+    pub fn writes_linear(&self) -> bool {
+        self.format.is_srgb()
+            || matches!(
+                self.format,
+                TextureFormat::Rgba16Float
+                    | TextureFormat::Rgba32Float
+                    | TextureFormat::Rg11b10Float
+            )
+    }
+
+    /// The premultiplied color shaders write for `color`, see
+    /// [`Backend::writes_linear`].
+    pub(crate) fn target_color(&self, color: LinearRgba) -> PremulColor {
+        if self.writes_linear() {
+            return color.premultiply();
+        }
+
+        let LinearRgba { r, g, b, a } = color;
+        let [r, g, b] = [r, g, b].map(linear_to_srgb);
+
+        LinearRgba::new(r, g, b, a).premultiply()
+    }
 }
 
 impl<'a> RendererBuilder<'a> {
@@ -125,6 +156,18 @@ impl<'a> RendererBuilder<'a> {
         Self { format, instance, adapter, device, trace_path }
     }
 
+    // target ---
+
+    /// Set the texture format to render to.
+    ///
+    /// Float formats like [`TextureFormat::Rgba16Float`] can show colors
+    /// outside of sRGB, such as [`DisplayP3`](crate::color::DisplayP3) ones,
+    /// where the display supports them.
+    #[must_use]
+    pub fn format(self, format: TextureFormat) -> Self {
+        Self { format, ..self }
+    }
+
     // instance ---
 
     #[must_use]
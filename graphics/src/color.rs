@@ -1,9 +1,51 @@
-//! Type for representing colors.
+//! Types for representing colors.
+//!
+//! [`Color`] stores 8-bit sRGB components, which is compact and what images
+//! and most color pickers use. sRGB isn't linear in light intensity though,
+//! so blending and interpolation should be done on a [`LinearRgba`].
+//! [`Hsl`], [`Hsv`] and [`Hwb`] describe sRGB colors in terms that are easier
+//! to adjust, and [`Oklab`] and [`Oklch`] in terms of how they are perceived.
+//! [`DisplayP3`] and [`Rec2020`] hold colors of wider gamuts, which convert
+//! to a [`LinearRgba`] with components outside of `0.0..=1.0`.
+//! The renderer composites [`PremulColor`]s, as described by [`PorterDuff`]
+//! operators and [`BlendMode`]s. Colors are interpolated in a
+//! [`ColorSpace`], and text can be checked for a [`MinContrast`] with it's
+//! background.
+//!
+//! ```
+//! # use crossd_graphics::color::{Color, ColorSpace};
+//! #
+//! // half way between black and white, by amount of light
+//! let gray = Color::BLACK.mix(Color::WHITE, 0.5, ColorSpace::LinearSrgb);
+//!
+//! assert_eq!(gray, Color::splat_opaque(188));
+//! ```
 
 use std::mem;
 
 use bytemuck::{Pod, Zeroable};
 
+/// Implementations for [`BlendMode`].
+mod blend;
+/// Contrast between [`Color`]s, and [`MinContrast`].
+mod contrast;
+/// Parsing and formatting of [`Color`] as CSS.
+mod css;
+/// Implementations for [`Hsl`], [`Hsv`] and [`Hwb`].
+mod hsl;
+/// Implementations for [`LinearRgba`].
+mod linear;
+/// Implementations for [`Oklab`] and [`Oklch`].
+mod oklab;
+/// Packing of [`Color`] into integers.
+mod packed;
+/// Implementations for [`PremulColor`] and [`PorterDuff`].
+mod premul;
+/// Implementations for [`ColorSpace`] and [`HueInterpolation`].
+mod space;
+/// Implementations for [`DisplayP3`] and [`Rec2020`].
+mod wide_gamut;
+
 /// An RGBA color.
 ///
 /// Is equivalent to `[u8; 4]` or WGSL `vec4<u8>`.
@@ -22,6 +64,387 @@ pub struct Color {
     pub a: u8,
 }
 
+/// A color with linear RGB components and straight alpha, usually between
+/// `0.0` and `1.0`.
+///
+/// Is equivalent to `[f32; 4]` or WGSL `vec4<f32>`.
+#[repr(C)]
+#[derive(Debug, Default, Clone, Copy, PartialEq, PartialOrd, Pod, Zeroable)]
+pub struct LinearRgba {
+    /// The red component.
+    pub r: f32,
+    /// The green component.
+    pub g: f32,
+    /// The blue component.
+    pub b: f32,
+    /// The alpha component.
+    pub a: f32,
+}
+
+/// A color with linear RGB components multiplied by alpha.
+///
+/// Premultiplied colors blend and filter without dark fringes around
+/// transparent areas, and are what the renderer blends with. Is equivalent
+/// to `[f32; 4]` or WGSL `vec4<f32>`.
+#[repr(C)]
+#[derive(Debug, Default, Clone, Copy, PartialEq, PartialOrd, Pod, Zeroable)]
+pub struct PremulColor {
+    /// The red component, times alpha.
+    pub r: f32,
+    /// The green component, times alpha.
+    pub g: f32,
+    /// The blue component, times alpha.
+    pub b: f32,
+    /// The alpha component.
+    pub a: f32,
+}
+
+/// A Porter-Duff operator, deciding how a source color is composited with
+/// the destination color it's drawn onto.
+///
+/// Each operator keeps some of the source and some of the destination,
+/// depending on how much they cover each other.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum PorterDuff {
+    /// Nothing.
+    Clear,
+    /// Only the source.
+    Src,
+    /// Only the destination.
+    Dst,
+    /// The source on top of the destination, the usual way of drawing.
+    #[default]
+    SrcOver,
+    /// The destination on top of the source.
+    DstOver,
+    /// The source where the destination is.
+    SrcIn,
+    /// The destination where the source is.
+    DstIn,
+    /// The source where the destination isn't.
+    SrcOut,
+    /// The destination where the source isn't.
+    DstOut,
+    /// The source on top of the destination, only where the destination is.
+    SrcAtop,
+    /// The destination on top of the source, only where the source is.
+    DstAtop,
+    /// The source where the destination isn't, and the destination where the
+    /// source isn't.
+    Xor,
+}
+
+/// How the colors of a source and the backdrop it's drawn onto are mixed,
+/// before the result is composited over the backdrop.
+///
+/// These are the blend modes of the W3C Compositing and Blending
+/// specification, also found in CSS and most design tools. Separable modes
+/// mix each RGB component on it's own, the others mix hue, saturation and
+/// luminosity.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum BlendMode {
+    /// The source color.
+    #[default]
+    Normal,
+    /// The product of the colors, always darker.
+    Multiply,
+    /// The inverse of the product of the inverse colors, always lighter.
+    Screen,
+    /// Multiply or screen, depending on the backdrop.
+    Overlay,
+    /// The darker of the colors.
+    Darken,
+    /// The lighter of the colors.
+    Lighten,
+    /// Brighten the backdrop to reflect the source.
+    ColorDodge,
+    /// Darken the backdrop to reflect the source.
+    ColorBurn,
+    /// Multiply or screen, depending on the source.
+    HardLight,
+    /// Darken or lighten, depending on the source.
+    SoftLight,
+    /// The difference between the colors.
+    Difference,
+    /// Like difference, but with lower contrast.
+    Exclusion,
+    /// The hue of the source with the saturation and luminosity of the
+    /// backdrop.
+    Hue,
+    /// The saturation of the source with the hue and luminosity of the
+    /// backdrop.
+    Saturation,
+    /// The hue and saturation of the source with the luminosity of the
+    /// backdrop.
+    Color,
+    /// The luminosity of the source with the hue and saturation of the
+    /// backdrop.
+    Luminosity,
+}
+
+/// A color by hue, saturation and lightness, with straight alpha.
+///
+/// Like CSS `hsl()`, this is a different way of describing sRGB colors, so
+/// the same lightness doesn't mean the same perceived brightness for
+/// different hues.
+#[derive(Debug, Default, Clone, Copy, PartialEq, PartialOrd)]
+pub struct Hsl {
+    /// The hue in degrees, from `0.0` (red) through `120.0` (green) and
+    /// `240.0` (blue) up to `360.0`.
+    pub h: f32,
+    /// The saturation, from `0.0` (gray) to `1.0`.
+    pub s: f32,
+    /// The lightness, from `0.0` (black) to `1.0` (white).
+    pub l: f32,
+    /// The alpha component.
+    pub a: f32,
+}
+
+/// A color by hue, saturation and value, with straight alpha.
+///
+/// Also known as HSB. Like [`Hsl`], this is a different way of describing
+/// sRGB colors.
+#[derive(Debug, Default, Clone, Copy, PartialEq, PartialOrd)]
+pub struct Hsv {
+    /// The hue in degrees, from `0.0` (red) through `120.0` (green) and
+    /// `240.0` (blue) up to `360.0`.
+    pub h: f32,
+    /// The saturation, from `0.0` (gray) to `1.0`.
+    pub s: f32,
+    /// The value, from `0.0` (black) to `1.0` (full brightness).
+    pub v: f32,
+    /// The alpha component.
+    pub a: f32,
+}
+
+/// A color by hue, whiteness and blackness, with straight alpha.
+///
+/// Like CSS `hwb()`, this is a different way of describing sRGB colors. If
+/// whiteness and blackness add up to more than `1.0`, the color is a gray.
+#[derive(Debug, Default, Clone, Copy, PartialEq, PartialOrd)]
+pub struct Hwb {
+    /// The hue in degrees, from `0.0` (red) through `120.0` (green) and
+    /// `240.0` (blue) up to `360.0`.
+    pub h: f32,
+    /// The amount of white mixed in, from `0.0` to `1.0`.
+    pub w: f32,
+    /// The amount of black mixed in, from `0.0` to `1.0`.
+    pub b: f32,
+    /// The alpha component.
+    pub a: f32,
+}
+
+/// A color in the OKLab perceptual color space, with straight alpha.
+///
+/// Equal distances between OKLab colors look about equally different, which
+/// makes it good for generating palettes and gradients. Converting to an
+/// RGB type maps colors outside of sRGB into it, see [`Oklch`].
+#[derive(Debug, Default, Clone, Copy, PartialEq, PartialOrd)]
+pub struct Oklab {
+    /// The perceived lightness, from `0.0` (black) to `1.0` (white).
+    pub l: f32,
+    /// How green (negative) or red (positive) the color is, at most about
+    /// `0.4` either way.
+    pub a: f32,
+    /// How blue (negative) or yellow (positive) the color is, at most about
+    /// `0.4` either way.
+    pub b: f32,
+    /// The alpha component.
+    pub alpha: f32,
+}
+
+/// A color in the OKLab color space by lightness, chroma and hue, with
+/// straight alpha.
+///
+/// Like CSS `oklch()`, converting to an RGB type maps colors outside of sRGB
+/// into it by reducing their chroma, keeping their lightness and hue.
+///
+/// ```
+/// # use crossd_graphics::color::{Color, Oklch};
+/// #
+/// // a palette of equally light colors
+/// let palette: Vec<Color> =
+///     (0..6).map(|i| Oklch::new_opaque(0.7, 0.15, i as f32 * 60.0).into()).collect();
+///
+/// assert_eq!(palette[0], Color::new_opaque(231, 114, 155));
+/// ```
+#[derive(Debug, Default, Clone, Copy, PartialEq, PartialOrd)]
+pub struct Oklch {
+    /// The perceived lightness, from `0.0` (black) to `1.0` (white).
+    pub l: f32,
+    /// The chroma, from `0.0` (gray) up to about `0.4`.
+    pub c: f32,
+    /// The hue in degrees, from `0.0` (pink) through about `140.0` (green) and
+    /// `265.0` (blue) up to `360.0`.
+    pub h: f32,
+    /// The alpha component.
+    pub alpha: f32,
+}
+
+/// A color in the Display P3 color space, with straight alpha.
+///
+/// Display P3 has the same white point and transfer function as sRGB, but
+/// about 50% more colors, and is what most recent phone and laptop displays
+/// show. Like CSS `color(display-p3 ...)`, the components are encoded and
+/// between `0.0` and `1.0`.
+///
+/// ```
+/// # use crossd_graphics::color::{Color, DisplayP3, LinearRgba};
+/// #
+/// // redder than sRGB can show
+/// let red = LinearRgba::from(DisplayP3::new_opaque(1.0, 0.0, 0.0));
+///
+/// assert!(red.r > 1.0 && red.g < 0.0);
+/// // mapped into sRGB, keeping it's hue
+/// assert_eq!(
+///     Color::from(DisplayP3::new_opaque(1.0, 0.0, 0.0)),
+///     Color::new_opaque(255, 11, 12)
+/// );
+/// ```
+#[derive(Debug, Default, Clone, Copy, PartialEq, PartialOrd)]
+pub struct DisplayP3 {
+    /// The red component.
+    pub r: f32,
+    /// The green component.
+    pub g: f32,
+    /// The blue component.
+    pub b: f32,
+    /// The alpha component.
+    pub a: f32,
+}
+
+/// A color in the ITU-R BT.2020 color space, with straight alpha.
+///
+/// Rec. 2020 is used for UHD and HDR video, and covers even more colors than
+/// [`DisplayP3`]. Like CSS `color(rec2020 ...)`, the components are encoded
+/// with the BT.2020 transfer function and between `0.0` and `1.0`.
+#[derive(Debug, Default, Clone, Copy, PartialEq, PartialOrd)]
+pub struct Rec2020 {
+    /// The red component.
+    pub r: f32,
+    /// The green component.
+    pub g: f32,
+    /// The blue component.
+    pub b: f32,
+    /// The alpha component.
+    pub a: f32,
+}
+
+/// The color space colors are interpolated in, for example by [`Color::mix`]
+/// or between the stops of a [`Gradient`](crate::paint::Gradient).
+///
+/// Like CSS, interpolation is done on premultiplied components, so mixing
+/// with a transparent color doesn't bring in it's RGB components.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum ColorSpace {
+    /// sRGB encoded components, the way CSS used to interpolate.
+    Srgb,
+    /// Linear sRGB components, mixing the amount of light.
+    LinearSrgb,
+    /// [`Oklab`], which looks even to the eye.
+    #[default]
+    Oklab,
+    /// [`Oklch`], going around the hue wheel as decided by the
+    /// [`HueInterpolation`].
+    Oklch(HueInterpolation),
+}
+
+/// Which way around the hue wheel to interpolate hues, as in CSS.
+///
+/// When one of the colors is a gray, it has no hue and the hue of the other
+/// color is used for both.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum HueInterpolation {
+    /// The shorter way, going through `0.0` if that's shorter.
+    #[default]
+    Shorter,
+    /// The longer way, going through `0.0` if that's longer.
+    Longer,
+    /// Towards increasing hues, going through `0.0` if the hue decreases.
+    Increasing,
+    /// Towards decreasing hues, going through `0.0` if the hue increases.
+    Decreasing,
+}
+
+/// A minimum contrast between text and it's background, to keep the text
+/// readable.
+///
+/// ```
+/// # use crossd_graphics::color::{Color, MinContrast};
+/// #
+/// let gray = Color::splat_opaque(118);
+///
+/// assert!(MinContrast::AA.is_met(gray, Color::WHITE));
+/// assert!(!MinContrast::AAA.is_met(gray, Color::WHITE));
+/// assert!(!MinContrast::Apca(75.0).is_met(gray, Color::WHITE));
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum MinContrast {
+    /// A WCAG 2.x contrast ratio, see [`Color::contrast_ratio`].
+    Wcag(f32),
+    /// An APCA lightness contrast in either polarity, see
+    /// [`Color::apca_contrast`].
+    ///
+    /// APCA recommends at least `75.0` for body text, `60.0` for other
+    /// text and `45.0` for large text.
+    Apca(f32),
+}
+
+/// An error from parsing a CSS color.
+///
+/// ```
+/// # use crossd_graphics::color::{Color, ParseColorError};
+/// #
+/// assert_eq!("#12345".parse::<Color>(), Err(ParseColorError::HexLength(5)));
+/// assert_eq!("rgb(1 2 3".parse::<Color>(), Err(ParseColorError::Expected(9, ')')));
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq, thiserror::Error)]
+pub enum ParseColorError {
+    #[error("empty color")]
+    Empty,
+    #[error("unexpected character at byte {0}")]
+    Unexpected(usize),
+    #[error("invalid hex digit at byte {0}")]
+    InvalidHex(usize),
+    #[error("expected 3, 4, 6 or 8 hex digits, found {0}")]
+    HexLength(usize),
+    #[error("unknown color name {0:?}")]
+    UnknownName(String),
+    #[error("unknown color function {0:?}")]
+    UnknownFunction(String),
+    #[error("expected a number at byte {0}")]
+    ExpectedNumber(usize),
+    #[error("invalid unit at byte {0}")]
+    InvalidUnit(usize),
+    #[error("expected {1:?} at byte {0}")]
+    Expected(usize, char),
+}
+
+/// Convert an sRGB encoded component between `0.0` and `1.0` to linear.
+#[must_use]
+pub fn srgb_to_linear(v: f32) -> f32 {
+    if v <= 0.04045 {
+        v / 12.92
+    } else {
+        ((v + 0.055) / 1.055).powf(2.4)
+    }
+}
+
+/// Convert a linear component between `0.0` and `1.0` to sRGB encoded.
+#[must_use]
+pub fn linear_to_srgb(v: f32) -> f32 {
+    if v <= 0.003_130_8 {
+        v * 12.92
+    } else {
+        1.055 * v.powf(1.0 / 2.4) - 0.055
+    }
+}
+
+/// A component between `0.0` and `1.0` as a byte, rounding to the nearest.
+fn to_u8(v: f32) -> u8 {
+    (v.clamp(0.0, 1.0) * 255.0).round() as u8
+}
+
 impl Color {
     /// Color black.
     ///
@@ -39,6 +462,8 @@ impl Color {
     ///
     /// Fully opaque.
     pub const RED: Self = Self::new_opaque(255, 0, 0);
+    /// Fully transparent black.
+    pub const TRANSPARENT: Self = Self::splat(0);
     /// Color white.
     ///
     /// Fully opaque.
@@ -86,30 +511,34 @@ impl Color {
 
     /// Convert to the hexadecimal representation of the color (`0xAARRGGBB`).
     ///
+    /// This is the same as [`Color::to_argb_u32`].
+    ///
     /// ```
     /// # use crossd_graphics::color::Color;
-    /// let color = Color::BLACK;
+    /// let color = Color::new_opaque(0x12, 0x34, 0x56);
     /// let hex = color.to_hex();
     ///
-    /// assert_eq!(hex, 0xFF000000);
+    /// assert_eq!(hex, 0xFF123456);
     /// ```
     #[must_use]
     pub const fn to_hex(self) -> u32 {
-        unsafe { mem::transmute(self) }
+        self.to_argb_u32()
     }
 
     /// Color from a hex code of format `0xAARRGGBB`.
     ///
+    /// This is the same as [`Color::from_argb_u32`].
+    ///
     /// ```
     /// # use crossd_graphics::color::Color;
-    /// let hex = 0xffffffff;
+    /// let hex = 0x80FF0000;
     /// let color = Color::from_hex(hex);
     ///
-    /// assert_eq!(color, Color::WHITE);
+    /// assert_eq!(color, Color::new(255, 0, 0, 128));
     /// ```
     #[must_use]
     pub const fn from_hex(hex: u32) -> Self {
-        unsafe { mem::transmute(hex) }
+        Self::from_argb_u32(hex)
     }
 }
 
@@ -0,0 +1,301 @@
+use wgpu::{BlendComponent, BlendFactor, BlendOperation, BlendState};
+
+use super::{BlendMode, PorterDuff, PremulColor};
+
+impl BlendMode {
+    /// All blend modes.
+    pub const ALL: [Self; 16] = [
+        Self::Normal,
+        Self::Multiply,
+        Self::Screen,
+        Self::Overlay,
+        Self::Darken,
+        Self::Lighten,
+        Self::ColorDodge,
+        Self::ColorBurn,
+        Self::HardLight,
+        Self::SoftLight,
+        Self::Difference,
+        Self::Exclusion,
+        Self::Hue,
+        Self::Saturation,
+        Self::Color,
+        Self::Luminosity,
+    ];
+
+    /// Whether the mode mixes each RGB component on it's own.
+    #[must_use]
+    pub const fn is_separable(self) -> bool {
+        !matches!(self, Self::Hue | Self::Saturation | Self::Color | Self::Luminosity)
+    }
+
+    /// Draw `src` onto `dst` on the CPU, mixing their colors with the mode
+    /// where they overlap.
+    ///
+    /// The formulas work on the components as they are. Blending linear
+    /// colors, as [`PremulColor`] has, gives physically based results, while
+    /// CSS and most design tools blend sRGB encoded components.
+    ///
+    /// ```
+    /// # use crossd_graphics::color::{BlendMode, PremulColor};
+    /// #
+    /// let src = PremulColor::new(0.5, 0.5, 0.0, 1.0);
+    /// let dst = PremulColor::new(0.5, 0.0, 1.0, 1.0);
+    ///
+    /// assert_eq!(
+    ///     BlendMode::Multiply.blend(src, dst),
+    ///     PremulColor::new(0.25, 0.0, 0.0, 1.0)
+    /// );
+    /// assert_eq!(BlendMode::Screen.blend(src, dst), PremulColor::new(0.75, 0.5, 1.0, 1.0));
+    ///
+    /// // only the overlapping part is mixed
+    /// let half = PremulColor::new(0.25, 0.25, 0.0, 0.5);
+    /// assert_eq!(BlendMode::Darken.blend(half, dst), PremulColor::new(0.5, 0.0, 0.5, 1.0));
+    /// ```
+    #[must_use]
+    pub fn blend(self, src: PremulColor, dst: PremulColor) -> PremulColor {
+        let (src_alpha, dst_alpha) = (src.a, dst.a);
+        let [source, backdrop] = [src, dst].map(|color| {
+            let [r, g, b, _] = color.unpremultiply().to_array();
+
+            [r, g, b]
+        });
+
+        let mixed = self.mix(backdrop, source);
+        let [src, dst] = [src.to_array(), dst.to_array()];
+        let component = |i: usize| {
+            src[i] * (1.0 - dst_alpha)
+                + dst[i] * (1.0 - src_alpha)
+                + src_alpha * dst_alpha * mixed[i]
+        };
+
+        PremulColor::new(
+            component(0),
+            component(1),
+            component(2),
+            src_alpha + dst_alpha - src_alpha * dst_alpha,
+        )
+    }
+
+    /// The blend state for drawing premultiplied colors with the mode on the
+    /// GPU, or `None` if the mode needs to read the destination in a shader.
+    ///
+    /// Only [`BlendMode::Normal`] and [`BlendMode::Screen`] can be done with
+    /// blend states alone.
+    #[must_use]
+    pub fn blend_state(self) -> Option<BlendState> {
+        match self {
+            Self::Normal => Some(PorterDuff::SrcOver.blend_state()),
+            // `src + dst - src * dst`
+            Self::Screen => Some(BlendState {
+                color: BlendComponent {
+                    src_factor: BlendFactor::One,
+                    dst_factor: BlendFactor::OneMinusSrc,
+                    operation: BlendOperation::Add,
+                },
+                alpha: PorterDuff::SrcOver.blend_state().alpha,
+            }),
+            _ => None,
+        }
+    }
+
+    /// Mix straight source and backdrop colors.
+    fn mix(self, backdrop: [f32; 3], source: [f32; 3]) -> [f32; 3] {
+        let separable =
+            |f: fn(f32, f32) -> f32| [0, 1, 2].map(|i| f(backdrop[i], source[i]));
+
+        match self {
+            Self::Normal => source,
+            Self::Multiply => separable(multiply),
+            Self::Screen => separable(screen),
+            Self::Overlay => separable(|b, s| hard_light(s, b)),
+            Self::Darken => separable(f32::min),
+            Self::Lighten => separable(f32::max),
+            Self::ColorDodge => separable(color_dodge),
+            Self::ColorBurn => separable(color_burn),
+            Self::HardLight => separable(hard_light),
+            Self::SoftLight => separable(soft_light),
+            Self::Difference => separable(|b, s| (b - s).abs()),
+            Self::Exclusion => separable(|b, s| b + s - 2.0 * b * s),
+            Self::Hue => set_lum(set_sat(source, sat(backdrop)), lum(backdrop)),
+            Self::Saturation => set_lum(set_sat(backdrop, sat(source)), lum(backdrop)),
+            Self::Color => set_lum(source, lum(backdrop)),
+            Self::Luminosity => set_lum(backdrop, lum(source)),
+        }
+    }
+}
+
+fn multiply(backdrop: f32, source: f32) -> f32 {
+    backdrop * source
+}
+
+fn screen(backdrop: f32, source: f32) -> f32 {
+    backdrop + source - backdrop * source
+}
+
+fn hard_light(backdrop: f32, source: f32) -> f32 {
+    if source <= 0.5 {
+        multiply(backdrop, 2.0 * source)
+    } else {
+        screen(backdrop, 2.0 * source - 1.0)
+    }
+}
+
+fn color_dodge(backdrop: f32, source: f32) -> f32 {
+    if backdrop == 0.0 {
+        0.0
+    } else if source >= 1.0 {
+        1.0
+    } else {
+        (backdrop / (1.0 - source)).min(1.0)
+    }
+}
+
+fn color_burn(backdrop: f32, source: f32) -> f32 {
+    if backdrop >= 1.0 {
+        1.0
+    } else if source == 0.0 {
+        0.0
+    } else {
+        1.0 - ((1.0 - backdrop) / source).min(1.0)
+    }
+}
+
+fn soft_light(backdrop: f32, source: f32) -> f32 {
+    if source <= 0.5 {
+        backdrop - (1.0 - 2.0 * source) * backdrop * (1.0 - backdrop)
+    } else {
+        let d = if backdrop <= 0.25 {
+            ((16.0 * backdrop - 12.0) * backdrop + 4.0) * backdrop
+        } else {
+            backdrop.sqrt()
+        };
+
+        backdrop + (2.0 * source - 1.0) * (d - backdrop)
+    }
+}
+
+/// The luminosity of a color, as defined for blending.
+fn lum([r, g, b]: [f32; 3]) -> f32 {
+    0.3 * r + 0.59 * g + 0.11 * b
+}
+
+/// The color with it's luminosity changed to `l`, keeping the components
+/// between `0.0` and `1.0` by moving them towards `l`.
+fn set_lum(color: [f32; 3], l: f32) -> [f32; 3] {
+    let d = l - lum(color);
+    let color = color.map(|c| c + d);
+
+    let l = lum(color);
+    let (min, max) =
+        (color[0].min(color[1]).min(color[2]), color[0].max(color[1]).max(color[2]));
+
+    color.map(|c| {
+        let c = if min < 0.0 { l + (c - l) * l / (l - min) } else { c };
+
+        if max > 1.0 {
+            l + (c - l) * (1.0 - l) / (max - l)
+        } else {
+            c
+        }
+    })
+}
+
+/// The saturation of a color, as defined for blending.
+fn sat([r, g, b]: [f32; 3]) -> f32 {
+    r.max(g).max(b) - r.min(g).min(b)
+}
+
+/// The color with it's saturation changed to `s`.
+fn set_sat(color: [f32; 3], s: f32) -> [f32; 3] {
+    let (min, max) =
+        (color[0].min(color[1]).min(color[2]), color[0].max(color[1]).max(color[2]));
+
+    if max > min {
+        color.map(|c| (c - min) * s / (max - min))
+    } else {
+        [0.0; 3]
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::super::{BlendMode, PremulColor};
+
+    fn opaque(r: f32, g: f32, b: f32) -> PremulColor {
+        PremulColor::new(r, g, b, 1.0)
+    }
+
+    fn assert_close(a: PremulColor, b: PremulColor) {
+        let close =
+            a.to_array().iter().zip(b.to_array()).all(|(a, b)| (a - b).abs() < 1e-5);
+
+        assert!(close, "{a:?} is not close to {b:?}");
+    }
+
+    #[test]
+    fn separable() {
+        let src = opaque(0.25, 0.5, 0.75);
+        let dst = opaque(0.5, 0.8, 0.2);
+
+        let expected = [
+            (BlendMode::Normal, [0.25, 0.5, 0.75]),
+            (BlendMode::Multiply, [0.125, 0.4, 0.15]),
+            (BlendMode::Screen, [0.625, 0.9, 0.8]),
+            (BlendMode::Overlay, [0.25, 0.8, 0.3]),
+            (BlendMode::Darken, [0.25, 0.5, 0.2]),
+            (BlendMode::Lighten, [0.5, 0.8, 0.75]),
+            (BlendMode::ColorDodge, [2.0 / 3.0, 1.0, 0.8]),
+            (BlendMode::ColorBurn, [0.0, 0.6, 0.0]),
+            (BlendMode::HardLight, [0.25, 0.8, 0.6]),
+            (BlendMode::SoftLight, [0.375, 0.8, 0.324]),
+            (BlendMode::Difference, [0.25, 0.3, 0.55]),
+            (BlendMode::Exclusion, [0.5, 0.5, 0.65]),
+        ];
+
+        for (mode, [r, g, b]) in expected {
+            assert!(mode.is_separable());
+            assert_close(mode.blend(src, dst), opaque(r, g, b));
+        }
+    }
+
+    #[test]
+    fn non_separable() {
+        let red = opaque(1.0, 0.0, 0.0);
+        let gray = opaque(0.5, 0.5, 0.5);
+
+        // gray has no hue or saturation to give
+        assert_close(BlendMode::Saturation.blend(gray, red), opaque(0.3, 0.3, 0.3));
+        // red moved to the luminosity of gray, then back into range
+        let light_red = opaque(1.0, 0.285_714_3, 0.285_714_3);
+        assert_close(BlendMode::Luminosity.blend(gray, red), light_red);
+        assert_close(BlendMode::Color.blend(red, gray), light_red);
+        assert_close(BlendMode::Hue.blend(red, gray), gray);
+
+        for mode in [
+            BlendMode::Hue,
+            BlendMode::Saturation,
+            BlendMode::Color,
+            BlendMode::Luminosity,
+        ] {
+            assert!(!mode.is_separable());
+            assert_close(mode.blend(gray, gray), gray);
+        }
+    }
+
+    #[test]
+    fn alpha() {
+        let src = PremulColor::new(0.25, 0.0, 0.0, 0.5);
+        let dst = PremulColor::new(0.0, 0.0, 0.5, 0.5);
+
+        for mode in BlendMode::ALL {
+            let result = mode.blend(src, dst);
+
+            assert!((result.a - 0.75).abs() < 1e-6, "{mode:?}");
+            assert_close(mode.blend(src, PremulColor::TRANSPARENT), src);
+            assert_close(mode.blend(PremulColor::TRANSPARENT, dst), dst);
+        }
+
+        assert_close(BlendMode::Normal.blend(src, dst), src.over(dst));
+    }
+}
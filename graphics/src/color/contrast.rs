@@ -0,0 +1,301 @@
+use super::{Color, LinearRgba, MinContrast, Oklch};
+
+impl Color {
+    /// The relative luminance as defined by WCAG 2.x, from `0.0` for black to
+    /// `1.0` for white.
+    ///
+    /// Alpha is ignored, so translucent colors should be composited onto
+    /// their background first. This goes for all contrast measurements.
+    #[must_use]
+    pub fn relative_luminance(self) -> f32 {
+        let LinearRgba { r, g, b, .. } = self.into();
+
+        0.2126 * r + 0.7152 * g + 0.0722 * b
+    }
+
+    /// The WCAG 2.x contrast ratio between two colors, from `1.0` for equal
+    /// luminances to `21.0` for black and white.
+    ///
+    /// WCAG level AA asks for at least `4.5` for text, or `3.0` for large
+    /// text, see [`MinContrast`].
+    ///
+    /// ```
+    /// # use crossd_graphics::color::Color;
+    /// #
+    /// let ratio = |a: Color, b| (a.contrast_ratio(b) * 100.0).round() / 100.0;
+    ///
+    /// assert_eq!(ratio(Color::BLACK, Color::WHITE), 21.0);
+    /// assert_eq!(ratio(Color::WHITE, Color::BLACK), 21.0);
+    /// assert_eq!(ratio(Color::RED, Color::WHITE), 4.0);
+    /// assert_eq!(ratio(Color::RED, Color::RED), 1.0);
+    /// ```
+    #[must_use]
+    pub fn contrast_ratio(self, other: Self) -> f32 {
+        let (a, b) = (self.relative_luminance(), other.relative_luminance());
+
+        (a.max(b) + 0.05) / (a.min(b) + 0.05)
+    }
+
+    /// The APCA lightness contrast of the color as text on `background`, using
+    /// the APCA-W3 0.0.98G constants.
+    ///
+    /// Unlike [`Color::contrast_ratio`] this depends on which color is the
+    /// text. It's positive for dark text on a light background, up to about
+    /// `106.0`, and negative for light text on a dark background, down to
+    /// about `-108.0`. Contrasts too low to read are `0.0`.
+    ///
+    /// ```
+    /// # use crossd_graphics::color::Color;
+    /// #
+    /// let lc = |text: Color, background| text.apca_contrast(background).round();
+    ///
+    /// assert_eq!(lc(Color::BLACK, Color::WHITE), 106.0);
+    /// assert_eq!(lc(Color::WHITE, Color::BLACK), -108.0);
+    /// assert_eq!(lc(Color::splat_opaque(0x88), Color::WHITE), 63.0);
+    /// ```
+    #[must_use]
+    pub fn apca_contrast(self, background: Self) -> f32 {
+        const BLACK_THRESHOLD: f32 = 0.022;
+        const BLACK_CLAMP: f32 = 1.414;
+        const SCALE: f32 = 1.14;
+        const OFFSET: f32 = 0.027;
+        const LOW_CLIP: f32 = 0.1;
+        const DELTA_Y_MIN: f32 = 0.0005;
+
+        let luminance = |color: Self| {
+            let [r, g, b, _] = color.to_array().map(|v| (f32::from(v) / 255.0).powf(2.4));
+            let y = 0.212_672_9 * r + 0.715_152_2 * g + 0.072_175 * b;
+
+            // soft clamp near black, for flare
+            if y < BLACK_THRESHOLD {
+                y + (BLACK_THRESHOLD - y).powf(BLACK_CLAMP)
+            } else {
+                y
+            }
+        };
+
+        let (text, background) = (luminance(self), luminance(background));
+
+        if (background - text).abs() < DELTA_Y_MIN {
+            return 0.0;
+        }
+
+        let contrast = if background > text {
+            // dark text on a light background
+            let sapc = (background.powf(0.56) - text.powf(0.57)) * SCALE;
+
+            if sapc < LOW_CLIP {
+                0.0
+            } else {
+                sapc - OFFSET
+            }
+        } else {
+            let sapc = (background.powf(0.65) - text.powf(0.62)) * SCALE;
+
+            if sapc > -LOW_CLIP {
+                0.0
+            } else {
+                sapc + OFFSET
+            }
+        };
+
+        contrast * 100.0
+    }
+
+    /// The first of `candidates` that has at least `min` contrast as text on
+    /// `background`.
+    ///
+    /// ```
+    /// # use crossd_graphics::color::{Color, MinContrast};
+    /// #
+    /// let brand = Color::new_opaque(255, 140, 0);
+    /// let candidates = [brand, Color::BLACK, Color::WHITE];
+    ///
+    /// let on_white = Color::pick_readable(Color::WHITE, candidates, MinContrast::AA);
+    /// let on_navy =
+    ///     Color::pick_readable(Color::new_opaque(0, 0, 128), candidates, MinContrast::AA);
+    ///
+    /// assert_eq!(on_white, Some(Color::BLACK));
+    /// assert_eq!(on_navy, Some(brand));
+    /// ```
+    #[must_use]
+    pub fn pick_readable(
+        background: Self,
+        candidates: impl IntoIterator<Item = Self>,
+        min: MinContrast,
+    ) -> Option<Self> {
+        candidates.into_iter().find(|&color| min.is_met(color, background))
+    }
+
+    /// The color made lighter or darker, by as little as possible, to have at
+    /// least `min` contrast as text on `background`.
+    ///
+    /// The lightness is changed in [`Oklch`], keeping the hue. If no
+    /// lightness is enough, this is black or white, whichever has more
+    /// contrast.
+    ///
+    /// ```
+    /// # use crossd_graphics::color::{Color, MinContrast};
+    /// #
+    /// let link = Color::new_opaque(80, 140, 255);
+    /// let readable = link.readable_on(Color::WHITE, MinContrast::AA);
+    ///
+    /// assert!(!MinContrast::AA.is_met(link, Color::WHITE));
+    /// assert!(MinContrast::AA.is_met(readable, Color::WHITE));
+    /// assert_eq!(readable, Color::new_opaque(56, 113, 226));
+    ///
+    /// // already readable
+    /// assert_eq!(readable.readable_on(Color::WHITE, MinContrast::AA), readable);
+    /// ```
+    #[must_use]
+    pub fn readable_on(self, background: Self, min: MinContrast) -> Self {
+        if min.is_met(self, background) {
+            return self;
+        }
+
+        let color = Oklch::from(self);
+        let with_lightness = |l: f32| Self::from(Oklch { l, ..color });
+
+        // the closest lightness towards black and towards white that is enough
+        let closest = [0.0, 1.0].map(|extreme| {
+            if !min.is_met(with_lightness(extreme), background) {
+                return None;
+            }
+
+            let (mut not_met, mut met) = (color.l, extreme);
+
+            for _ in 0..24 {
+                let l = (not_met + met) / 2.0;
+
+                if min.is_met(with_lightness(l), background) {
+                    met = l;
+                } else {
+                    not_met = l;
+                }
+            }
+
+            Some(met)
+        });
+
+        let l = match closest {
+            [Some(darker), Some(lighter)] => {
+                if color.l - darker <= lighter - color.l {
+                    darker
+                } else {
+                    lighter
+                }
+            },
+            [Some(l), None] | [None, Some(l)] => l,
+            [None, None] => {
+                let measure = |text| min.measure(text, background);
+
+                return if measure(Self::BLACK) >= measure(Self::WHITE) {
+                    Self::BLACK
+                } else {
+                    Self::WHITE
+                };
+            },
+        };
+
+        with_lightness(l)
+    }
+}
+
+impl MinContrast {
+    /// WCAG level AA for text.
+    pub const AA: Self = Self::Wcag(4.5);
+    /// WCAG level AAA for text.
+    pub const AAA: Self = Self::Wcag(7.0);
+    /// WCAG level AAA for large text.
+    pub const AAA_LARGE: Self = Self::Wcag(4.5);
+    /// WCAG level AA for large text, and for user interface components.
+    pub const AA_LARGE: Self = Self::Wcag(3.0);
+
+    /// Whether `text` on `background` has at least this contrast.
+    #[must_use]
+    pub fn is_met(self, text: Color, background: Color) -> bool {
+        let min = match self {
+            Self::Wcag(min) | Self::Apca(min) => min,
+        };
+
+        self.measure(text, background) >= min
+    }
+
+    /// The contrast of `text` on `background`, in the measure of the minimum.
+    fn measure(self, text: Color, background: Color) -> f32 {
+        match self {
+            Self::Wcag(_) => text.contrast_ratio(background),
+            Self::Apca(_) => text.apca_contrast(background).abs(),
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::super::{Color, MinContrast};
+
+    #[test]
+    fn wcag() {
+        // from the WCAG 2.x understanding documents and common tools
+        let ratio = |a: Color, b: Color| (a.contrast_ratio(b) * 100.0).round() / 100.0;
+
+        assert_eq!(Color::WHITE.relative_luminance(), 1.0);
+        assert_eq!(Color::BLACK.relative_luminance(), 0.0);
+        assert_eq!(ratio(Color::splat_opaque(0x76), Color::WHITE), 4.54);
+        assert_eq!(ratio(Color::splat_opaque(0x77), Color::WHITE), 4.48);
+        assert_eq!(ratio(Color::RED, Color::WHITE), 4.0);
+        assert_eq!(ratio(Color::BLUE, Color::WHITE), 8.59);
+    }
+
+    #[test]
+    fn apca() {
+        // from the APCA reference implementation
+        let lc = |text: &str, background: &str| {
+            let (text, background) =
+                (text.parse::<Color>().unwrap(), background.parse::<Color>().unwrap());
+
+            (text.apca_contrast(background) * 10.0).round() / 10.0
+        };
+
+        assert_eq!(lc("#888", "#fff"), 63.1);
+        assert_eq!(lc("#fff", "#888"), -68.5);
+        assert_eq!(lc("#000", "#aaa"), 58.1);
+        assert_eq!(lc("#aaa", "#000"), -56.2);
+        assert_eq!(lc("#123", "#def"), 91.7);
+        assert_eq!(lc("#def", "#123"), -93.1);
+        assert_eq!(lc("#777", "#777"), 0.0);
+    }
+
+    #[test]
+    fn readable() {
+        let backgrounds = [
+            Color::WHITE,
+            Color::BLACK,
+            Color::splat_opaque(200),
+            Color::new_opaque(30, 60, 90),
+            Color::new_opaque(250, 240, 200),
+        ];
+        let colors =
+            [Color::RED, Color::new_opaque(0, 160, 80), Color::new_opaque(120, 90, 200)];
+        let mins = [
+            MinContrast::AA_LARGE,
+            MinContrast::AA,
+            MinContrast::AAA,
+            MinContrast::Apca(60.0),
+        ];
+
+        for background in backgrounds {
+            for color in colors {
+                for min in mins {
+                    let readable = color.readable_on(background, min);
+
+                    assert!(min.is_met(readable, background), "{color:?} {background:?}");
+                }
+            }
+        }
+
+        // no color has AAA contrast with mid gray
+        let gray = Color::splat_opaque(128);
+        assert_eq!(Color::RED.readable_on(gray, MinContrast::AAA), Color::BLACK);
+    }
+}
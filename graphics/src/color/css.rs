@@ -0,0 +1,535 @@
+use std::fmt;
+use std::str::FromStr;
+
+use super::{to_u8, Color, Hsl, ParseColorError};
+
+impl Color {
+    /// Parse a CSS color.
+    ///
+    /// Supported are hex colors (`#rgb`, `#rgba`, `#rrggbb` and `#rrggbbaa`),
+    /// the `rgb()`, `rgba()`, `hsl()` and `hsla()` functions in both their
+    /// comma and space separated forms, `transparent` and all named colors.
+    /// Like in CSS, names and functions are case-insensitive and components
+    /// outside of their range are clamped.
+    ///
+    /// ```
+    /// # use crossd_graphics::color::{Color, ParseColorError};
+    /// #
+    /// let orange = Color::new_opaque(255, 165, 0);
+    ///
+    /// assert_eq!(Color::from_css("#ffa500"), Ok(orange));
+    /// assert_eq!(Color::from_css("Orange"), Ok(orange));
+    /// assert_eq!(Color::from_css("rgb(255, 165, 0)"), Ok(orange));
+    /// assert_eq!(
+    ///     Color::from_css("rgb(100% 64.7% 0% / 50%)"),
+    ///     Ok(Color { a: 128, ..orange })
+    /// );
+    /// assert_eq!(Color::from_css("hsl(38.8deg 100% 50%)"), Ok(orange));
+    ///
+    /// assert_eq!(Color::from_css("rgb(255, 165)"), Err(ParseColorError::Expected(12, ',')));
+    /// ```
+    pub fn from_css(css: &str) -> Result<Self, ParseColorError> {
+        let mut parser = Parser { data: css, pos: 0 };
+        parser.skip_whitespace();
+
+        let color = if parser.eat(b'#') {
+            parser.hex()?
+        } else {
+            let start = parser.pos;
+            let name = parser.ident();
+
+            if name.is_empty() {
+                return Err(match parser.peek() {
+                    Some(_) => ParseColorError::Unexpected(start),
+                    None => ParseColorError::Empty,
+                });
+            }
+
+            let name = name.to_ascii_lowercase();
+
+            if parser.eat(b'(') {
+                match name.as_str() {
+                    "rgb" | "rgba" => {
+                        let kinds = [Kind::Channel, Kind::Channel, Kind::Channel];
+                        let [r, g, b, a] = parser.arguments(kinds)?;
+
+                        Self::new(to_u8(r), to_u8(g), to_u8(b), to_u8(a))
+                    },
+                    "hsl" | "hsla" => {
+                        let kinds = [Kind::Hue, Kind::Percentage, Kind::Percentage];
+                        let [h, s, l, a] = parser.arguments(kinds)?;
+
+                        Hsl::new(h, s, l, a).into()
+                    },
+                    _ => return Err(ParseColorError::UnknownFunction(name)),
+                }
+            } else if name == "transparent" {
+                Self::TRANSPARENT
+            } else {
+                NAMED_COLORS
+                    .binary_search_by_key(&name.as_str(), |(name, _)| name)
+                    .map(|i| NAMED_COLORS[i].1)
+                    .map_err(|_| ParseColorError::UnknownName(name))?
+            }
+        };
+
+        parser.skip_whitespace();
+
+        match parser.peek() {
+            Some(_) => Err(ParseColorError::Unexpected(parser.pos)),
+            None => Ok(color),
+        }
+    }
+}
+
+impl FromStr for Color {
+    type Err = ParseColorError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Self::from_css(s)
+    }
+}
+
+impl fmt::Display for Color {
+    /// Format as a CSS hex color, `#rrggbb` or `#rrggbbaa` if the color isn't
+    /// opaque.
+    ///
+    /// ```
+    /// # use crossd_graphics::color::Color;
+    /// #
+    /// assert_eq!(Color::new_opaque(255, 165, 0).to_string(), "#ffa500");
+    /// assert_eq!(Color::new(255, 165, 0, 128).to_string(), "#ffa50080");
+    /// ```
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let Self { r, g, b, a } = *self;
+
+        write!(f, "#{r:02x}{g:02x}{b:02x}")?;
+
+        if a != 255 {
+            write!(f, "{a:02x}")?;
+        }
+
+        Ok(())
+    }
+}
+
+/// What a component of a color function means, which decides the units it
+/// can have.
+#[derive(Debug, Clone, Copy)]
+enum Kind {
+    /// An RGB channel, a number up to `255` or a percentage.
+    Channel,
+    /// A hue, a number of degrees or an angle.
+    Hue,
+    /// A percentage, or a number of percents.
+    Percentage,
+    /// An alpha, a number up to `1` or a percentage.
+    Alpha,
+}
+
+/// Reads a CSS color, keeping track of the position for errors.
+struct Parser<'a> {
+    data: &'a str,
+    pos: usize,
+}
+
+impl<'a> Parser<'a> {
+    fn peek(&self) -> Option<u8> {
+        self.data.as_bytes().get(self.pos).copied()
+    }
+
+    /// Skip the byte if it's next.
+    fn eat(&mut self, byte: u8) -> bool {
+        let found = self.peek() == Some(byte);
+        self.pos += usize::from(found);
+
+        found
+    }
+
+    /// Skip the byte, after any whitespace, or fail.
+    fn expect(&mut self, byte: u8) -> Result<(), ParseColorError> {
+        self.skip_whitespace();
+
+        if self.eat(byte) {
+            Ok(())
+        } else {
+            Err(ParseColorError::Expected(self.pos, char::from(byte)))
+        }
+    }
+
+    fn skip_whitespace(&mut self) {
+        while self.peek().is_some_and(|byte| byte.is_ascii_whitespace()) {
+            self.pos += 1;
+        }
+    }
+
+    /// Take bytes while they match.
+    fn take_while(&mut self, f: impl Fn(u8) -> bool) -> &'a str {
+        let start = self.pos;
+
+        while self.peek().is_some_and(&f) {
+            self.pos += 1;
+        }
+
+        &self.data[start..self.pos]
+    }
+
+    /// A name of a color, function or unit.
+    fn ident(&mut self) -> &'a str {
+        self.take_while(|byte| byte.is_ascii_alphanumeric() || byte == b'-')
+    }
+
+    /// The digits of a hex color, after the `#`.
+    fn hex(&mut self) -> Result<Color, ParseColorError> {
+        let digits = self.take_while(|byte| byte.is_ascii_hexdigit());
+
+        if self.peek().is_some_and(|byte| byte.is_ascii_alphanumeric()) {
+            return Err(ParseColorError::InvalidHex(self.pos));
+        }
+
+        let digit = |i: usize| u8::from_str_radix(&digits[i..=i], 16).unwrap_or_default();
+        let byte = |i: usize| digit(i) << 4 | digit(i + 1);
+
+        Ok(match digits.len() {
+            3 => Color::new_opaque(digit(0) * 17, digit(1) * 17, digit(2) * 17),
+            4 => Color::new(digit(0) * 17, digit(1) * 17, digit(2) * 17, digit(3) * 17),
+            6 => Color::new_opaque(byte(0), byte(2), byte(4)),
+            8 => Color::new(byte(0), byte(2), byte(4), byte(6)),
+            len => return Err(ParseColorError::HexLength(len)),
+        })
+    }
+
+    /// The arguments of a color function, after the `(`, with an alpha of
+    /// `1.0` if it's left out.
+    ///
+    /// Arguments are either separated by commas, or by whitespace with a `/`
+    /// before the alpha.
+    fn arguments(&mut self, kinds: [Kind; 3]) -> Result<[f32; 4], ParseColorError> {
+        self.skip_whitespace();
+        let first = self.component(kinds[0])?;
+
+        self.skip_whitespace();
+        let commas = self.eat(b',');
+
+        let second = self.component(kinds[1])?;
+
+        if commas {
+            self.expect(b',')?;
+        }
+
+        let third = self.component(kinds[2])?;
+
+        self.skip_whitespace();
+        let alpha = if self.eat(if commas { b',' } else { b'/' }) {
+            self.component(Kind::Alpha)?
+        } else {
+            1.0
+        };
+
+        self.expect(b')')?;
+
+        Ok([first, second, third, alpha])
+    }
+
+    /// A number with an optional unit, or `none`, converted to the range of
+    /// the component.
+    fn component(&mut self, kind: Kind) -> Result<f32, ParseColorError> {
+        self.skip_whitespace();
+        let start = self.pos;
+
+        if self.ident().eq_ignore_ascii_case("none") {
+            return Ok(0.0);
+        }
+
+        self.pos = start;
+        let number = self.number()?;
+
+        let unit_start = self.pos;
+        let unit = if self.eat(b'%') { "%" } else { self.ident() };
+
+        let value = match (kind, unit.to_ascii_lowercase().as_str()) {
+            (Kind::Channel, "") => number / 255.0,
+            (Kind::Alpha, "") => number,
+            (Kind::Channel | Kind::Alpha | Kind::Percentage, "%")
+            | (Kind::Percentage, "") => number / 100.0,
+            (Kind::Hue, "" | "deg") => number,
+            (Kind::Hue, "rad") => number.to_degrees(),
+            (Kind::Hue, "grad") => number * 0.9,
+            (Kind::Hue, "turn") => number * 360.0,
+            _ => return Err(ParseColorError::InvalidUnit(unit_start)),
+        };
+
+        Ok(value)
+    }
+
+    /// A CSS number, like `-1`, `.5` or `1e3`.
+    fn number(&mut self) -> Result<f32, ParseColorError> {
+        let start = self.pos;
+        let digits =
+            |parser: &mut Self| !parser.take_while(|b| b.is_ascii_digit()).is_empty();
+
+        let _ = self.eat(b'+') || self.eat(b'-');
+        let mut valid = digits(self);
+
+        if self.eat(b'.') {
+            valid |= digits(self);
+        }
+
+        // an exponent, if there are digits after it
+        let before_exponent = self.pos;
+
+        if valid && (self.eat(b'e') || self.eat(b'E')) {
+            let _ = self.eat(b'+') || self.eat(b'-');
+
+            if !digits(self) {
+                self.pos = before_exponent;
+            }
+        }
+
+        match self.data[start..self.pos].parse() {
+            Ok(number) if valid => Ok(number),
+            _ => Err(ParseColorError::ExpectedNumber(start)),
+        }
+    }
+}
+
+/// All CSS named colors, sorted by name.
+const NAMED_COLORS: [(&str, Color); 148] = [
+    ("aliceblue", Color::new_opaque(0xf0, 0xf8, 0xff)),
+    ("antiquewhite", Color::new_opaque(0xfa, 0xeb, 0xd7)),
+    ("aqua", Color::new_opaque(0x00, 0xff, 0xff)),
+    ("aquamarine", Color::new_opaque(0x7f, 0xff, 0xd4)),
+    ("azure", Color::new_opaque(0xf0, 0xff, 0xff)),
+    ("beige", Color::new_opaque(0xf5, 0xf5, 0xdc)),
+    ("bisque", Color::new_opaque(0xff, 0xe4, 0xc4)),
+    ("black", Color::new_opaque(0x00, 0x00, 0x00)),
+    ("blanchedalmond", Color::new_opaque(0xff, 0xeb, 0xcd)),
+    ("blue", Color::new_opaque(0x00, 0x00, 0xff)),
+    ("blueviolet", Color::new_opaque(0x8a, 0x2b, 0xe2)),
+    ("brown", Color::new_opaque(0xa5, 0x2a, 0x2a)),
+    ("burlywood", Color::new_opaque(0xde, 0xb8, 0x87)),
+    ("cadetblue", Color::new_opaque(0x5f, 0x9e, 0xa0)),
+    ("chartreuse", Color::new_opaque(0x7f, 0xff, 0x00)),
+    ("chocolate", Color::new_opaque(0xd2, 0x69, 0x1e)),
+    ("coral", Color::new_opaque(0xff, 0x7f, 0x50)),
+    ("cornflowerblue", Color::new_opaque(0x64, 0x95, 0xed)),
+    ("cornsilk", Color::new_opaque(0xff, 0xf8, 0xdc)),
+    ("crimson", Color::new_opaque(0xdc, 0x14, 0x3c)),
+    ("cyan", Color::new_opaque(0x00, 0xff, 0xff)),
+    ("darkblue", Color::new_opaque(0x00, 0x00, 0x8b)),
+    ("darkcyan", Color::new_opaque(0x00, 0x8b, 0x8b)),
+    ("darkgoldenrod", Color::new_opaque(0xb8, 0x86, 0x0b)),
+    ("darkgray", Color::new_opaque(0xa9, 0xa9, 0xa9)),
+    ("darkgreen", Color::new_opaque(0x00, 0x64, 0x00)),
+    ("darkgrey", Color::new_opaque(0xa9, 0xa9, 0xa9)),
+    ("darkkhaki", Color::new_opaque(0xbd, 0xb7, 0x6b)),
+    ("darkmagenta", Color::new_opaque(0x8b, 0x00, 0x8b)),
+    ("darkolivegreen", Color::new_opaque(0x55, 0x6b, 0x2f)),
+    ("darkorange", Color::new_opaque(0xff, 0x8c, 0x00)),
+    ("darkorchid", Color::new_opaque(0x99, 0x32, 0xcc)),
+    ("darkred", Color::new_opaque(0x8b, 0x00, 0x00)),
+    ("darksalmon", Color::new_opaque(0xe9, 0x96, 0x7a)),
+    ("darkseagreen", Color::new_opaque(0x8f, 0xbc, 0x8f)),
+    ("darkslateblue", Color::new_opaque(0x48, 0x3d, 0x8b)),
+    ("darkslategray", Color::new_opaque(0x2f, 0x4f, 0x4f)),
+    ("darkslategrey", Color::new_opaque(0x2f, 0x4f, 0x4f)),
+    ("darkturquoise", Color::new_opaque(0x00, 0xce, 0xd1)),
+    ("darkviolet", Color::new_opaque(0x94, 0x00, 0xd3)),
+    ("deeppink", Color::new_opaque(0xff, 0x14, 0x93)),
+    ("deepskyblue", Color::new_opaque(0x00, 0xbf, 0xff)),
+    ("dimgray", Color::new_opaque(0x69, 0x69, 0x69)),
+    ("dimgrey", Color::new_opaque(0x69, 0x69, 0x69)),
+    ("dodgerblue", Color::new_opaque(0x1e, 0x90, 0xff)),
+    ("firebrick", Color::new_opaque(0xb2, 0x22, 0x22)),
+    ("floralwhite", Color::new_opaque(0xff, 0xfa, 0xf0)),
+    ("forestgreen", Color::new_opaque(0x22, 0x8b, 0x22)),
+    ("fuchsia", Color::new_opaque(0xff, 0x00, 0xff)),
+    ("gainsboro", Color::new_opaque(0xdc, 0xdc, 0xdc)),
+    ("ghostwhite", Color::new_opaque(0xf8, 0xf8, 0xff)),
+    ("gold", Color::new_opaque(0xff, 0xd7, 0x00)),
+    ("goldenrod", Color::new_opaque(0xda, 0xa5, 0x20)),
+    ("gray", Color::new_opaque(0x80, 0x80, 0x80)),
+    ("green", Color::new_opaque(0x00, 0x80, 0x00)),
+    ("greenyellow", Color::new_opaque(0xad, 0xff, 0x2f)),
+    ("grey", Color::new_opaque(0x80, 0x80, 0x80)),
+    ("honeydew", Color::new_opaque(0xf0, 0xff, 0xf0)),
+    ("hotpink", Color::new_opaque(0xff, 0x69, 0xb4)),
+    ("indianred", Color::new_opaque(0xcd, 0x5c, 0x5c)),
+    ("indigo", Color::new_opaque(0x4b, 0x00, 0x82)),
+    ("ivory", Color::new_opaque(0xff, 0xff, 0xf0)),
+    ("khaki", Color::new_opaque(0xf0, 0xe6, 0x8c)),
+    ("lavender", Color::new_opaque(0xe6, 0xe6, 0xfa)),
+    ("lavenderblush", Color::new_opaque(0xff, 0xf0, 0xf5)),
+    ("lawngreen", Color::new_opaque(0x7c, 0xfc, 0x00)),
+    ("lemonchiffon", Color::new_opaque(0xff, 0xfa, 0xcd)),
+    ("lightblue", Color::new_opaque(0xad, 0xd8, 0xe6)),
+    ("lightcoral", Color::new_opaque(0xf0, 0x80, 0x80)),
+    ("lightcyan", Color::new_opaque(0xe0, 0xff, 0xff)),
+    ("lightgoldenrodyellow", Color::new_opaque(0xfa, 0xfa, 0xd2)),
+    ("lightgray", Color::new_opaque(0xd3, 0xd3, 0xd3)),
+    ("lightgreen", Color::new_opaque(0x90, 0xee, 0x90)),
+    ("lightgrey", Color::new_opaque(0xd3, 0xd3, 0xd3)),
+    ("lightpink", Color::new_opaque(0xff, 0xb6, 0xc1)),
+    ("lightsalmon", Color::new_opaque(0xff, 0xa0, 0x7a)),
+    ("lightseagreen", Color::new_opaque(0x20, 0xb2, 0xaa)),
+    ("lightskyblue", Color::new_opaque(0x87, 0xce, 0xfa)),
+    ("lightslategray", Color::new_opaque(0x77, 0x88, 0x99)),
+    ("lightslategrey", Color::new_opaque(0x77, 0x88, 0x99)),
+    ("lightsteelblue", Color::new_opaque(0xb0, 0xc4, 0xde)),
+    ("lightyellow", Color::new_opaque(0xff, 0xff, 0xe0)),
+    ("lime", Color::new_opaque(0x00, 0xff, 0x00)),
+    ("limegreen", Color::new_opaque(0x32, 0xcd, 0x32)),
+    ("linen", Color::new_opaque(0xfa, 0xf0, 0xe6)),
+    ("magenta", Color::new_opaque(0xff, 0x00, 0xff)),
+    ("maroon", Color::new_opaque(0x80, 0x00, 0x00)),
+    ("mediumaquamarine", Color::new_opaque(0x66, 0xcd, 0xaa)),
+    ("mediumblue", Color::new_opaque(0x00, 0x00, 0xcd)),
+    ("mediumorchid", Color::new_opaque(0xba, 0x55, 0xd3)),
+    ("mediumpurple", Color::new_opaque(0x93, 0x70, 0xdb)),
+    ("mediumseagreen", Color::new_opaque(0x3c, 0xb3, 0x71)),
+    ("mediumslateblue", Color::new_opaque(0x7b, 0x68, 0xee)),
+    ("mediumspringgreen", Color::new_opaque(0x00, 0xfa, 0x9a)),
+    ("mediumturquoise", Color::new_opaque(0x48, 0xd1, 0xcc)),
+    ("mediumvioletred", Color::new_opaque(0xc7, 0x15, 0x85)),
+    ("midnightblue", Color::new_opaque(0x19, 0x19, 0x70)),
+    ("mintcream", Color::new_opaque(0xf5, 0xff, 0xfa)),
+    ("mistyrose", Color::new_opaque(0xff, 0xe4, 0xe1)),
+    ("moccasin", Color::new_opaque(0xff, 0xe4, 0xb5)),
+    ("navajowhite", Color::new_opaque(0xff, 0xde, 0xad)),
+    ("navy", Color::new_opaque(0x00, 0x00, 0x80)),
+    ("oldlace", Color::new_opaque(0xfd, 0xf5, 0xe6)),
+    ("olive", Color::new_opaque(0x80, 0x80, 0x00)),
+    ("olivedrab", Color::new_opaque(0x6b, 0x8e, 0x23)),
+    ("orange", Color::new_opaque(0xff, 0xa5, 0x00)),
+    ("orangered", Color::new_opaque(0xff, 0x45, 0x00)),
+    ("orchid", Color::new_opaque(0xda, 0x70, 0xd6)),
+    ("palegoldenrod", Color::new_opaque(0xee, 0xe8, 0xaa)),
+    ("palegreen", Color::new_opaque(0x98, 0xfb, 0x98)),
+    ("paleturquoise", Color::new_opaque(0xaf, 0xee, 0xee)),
+    ("palevioletred", Color::new_opaque(0xdb, 0x70, 0x93)),
+    ("papayawhip", Color::new_opaque(0xff, 0xef, 0xd5)),
+    ("peachpuff", Color::new_opaque(0xff, 0xda, 0xb9)),
+    ("peru", Color::new_opaque(0xcd, 0x85, 0x3f)),
+    ("pink", Color::new_opaque(0xff, 0xc0, 0xcb)),
+    ("plum", Color::new_opaque(0xdd, 0xa0, 0xdd)),
+    ("powderblue", Color::new_opaque(0xb0, 0xe0, 0xe6)),
+    ("purple", Color::new_opaque(0x80, 0x00, 0x80)),
+    ("rebeccapurple", Color::new_opaque(0x66, 0x33, 0x99)),
+    ("red", Color::new_opaque(0xff, 0x00, 0x00)),
+    ("rosybrown", Color::new_opaque(0xbc, 0x8f, 0x8f)),
+    ("royalblue", Color::new_opaque(0x41, 0x69, 0xe1)),
+    ("saddlebrown", Color::new_opaque(0x8b, 0x45, 0x13)),
+    ("salmon", Color::new_opaque(0xfa, 0x80, 0x72)),
+    ("sandybrown", Color::new_opaque(0xf4, 0xa4, 0x60)),
+    ("seagreen", Color::new_opaque(0x2e, 0x8b, 0x57)),
+    ("seashell", Color::new_opaque(0xff, 0xf5, 0xee)),
+    ("sienna", Color::new_opaque(0xa0, 0x52, 0x2d)),
+    ("silver", Color::new_opaque(0xc0, 0xc0, 0xc0)),
+    ("skyblue", Color::new_opaque(0x87, 0xce, 0xeb)),
+    ("slateblue", Color::new_opaque(0x6a, 0x5a, 0xcd)),
+    ("slategray", Color::new_opaque(0x70, 0x80, 0x90)),
+    ("slategrey", Color::new_opaque(0x70, 0x80, 0x90)),
+    ("snow", Color::new_opaque(0xff, 0xfa, 0xfa)),
+    ("springgreen", Color::new_opaque(0x00, 0xff, 0x7f)),
+    ("steelblue", Color::new_opaque(0x46, 0x82, 0xb4)),
+    ("tan", Color::new_opaque(0xd2, 0xb4, 0x8c)),
+    ("teal", Color::new_opaque(0x00, 0x80, 0x80)),
+    ("thistle", Color::new_opaque(0xd8, 0xbf, 0xd8)),
+    ("tomato", Color::new_opaque(0xff, 0x63, 0x47)),
+    ("turquoise", Color::new_opaque(0x40, 0xe0, 0xd0)),
+    ("violet", Color::new_opaque(0xee, 0x82, 0xee)),
+    ("wheat", Color::new_opaque(0xf5, 0xde, 0xb3)),
+    ("white", Color::new_opaque(0xff, 0xff, 0xff)),
+    ("whitesmoke", Color::new_opaque(0xf5, 0xf5, 0xf5)),
+    ("yellow", Color::new_opaque(0xff, 0xff, 0x00)),
+    ("yellowgreen", Color::new_opaque(0x9a, 0xcd, 0x32)),
+];
+
+#[cfg(test)]
+mod test {
+    use super::super::{Color, ParseColorError};
+    use super::NAMED_COLORS;
+
+    #[test]
+    fn named_colors() {
+        assert!(NAMED_COLORS.windows(2).all(|pair| pair[0].0 < pair[1].0));
+
+        assert_eq!("rebeccapurple".parse(), Ok(Color::new_opaque(0x66, 0x33, 0x99)));
+        assert_eq!("  WhiteSmoke ".parse(), Ok(Color::splat_opaque(0xf5)));
+        assert_eq!("transparent".parse(), Ok(Color::new(0, 0, 0, 0)));
+        assert_eq!(
+            "reddish".parse::<Color>(),
+            Err(ParseColorError::UnknownName("reddish".to_owned()))
+        );
+    }
+
+    #[test]
+    fn hex() {
+        assert_eq!("#f80".parse(), Ok(Color::new_opaque(0xff, 0x88, 0x00)));
+        assert_eq!("#f808".parse(), Ok(Color::new(0xff, 0x88, 0x00, 0x88)));
+        assert_eq!("#1A2b3C".parse(), Ok(Color::new_opaque(0x1a, 0x2b, 0x3c)));
+        assert_eq!("#1a2b3c4d".parse(), Ok(Color::new(0x1a, 0x2b, 0x3c, 0x4d)));
+
+        assert_eq!("#12345".parse::<Color>(), Err(ParseColorError::HexLength(5)));
+        assert_eq!("#12g".parse::<Color>(), Err(ParseColorError::InvalidHex(3)));
+        assert_eq!("#123 x".parse::<Color>(), Err(ParseColorError::Unexpected(5)));
+    }
+
+    #[test]
+    fn functions() {
+        let color = Color::new(255, 128, 0, 128);
+
+        assert_eq!("rgba(255, 128, 0, 0.5)".parse(), Ok(color));
+        assert_eq!("RGB(100%,50.2%,0%,50%)".parse(), Ok(color));
+        assert_eq!("rgb(255 128 none / .5)".parse(), Ok(color));
+        assert_eq!("rgb( 255 128 0 )".parse(), Ok(Color { a: 255, ..color }));
+        assert_eq!("rgb(300, -5, 0)".parse(), Ok(Color::new_opaque(255, 0, 0)));
+
+        assert_eq!("hsl(120, 100%, 25%)".parse(), Ok(Color::new_opaque(0, 128, 0)));
+        assert_eq!(
+            "hsla(0.5turn 100 50 / 1e0)".parse(),
+            Ok(Color::new_opaque(0, 255, 255))
+        );
+        assert_eq!(
+            "hsl(3.14159rad 100% 50%)".parse(),
+            Ok(Color::new_opaque(0, 255, 255))
+        );
+    }
+
+    #[test]
+    fn errors() {
+        assert_eq!("".parse::<Color>(), Err(ParseColorError::Empty));
+        assert_eq!("  ".parse::<Color>(), Err(ParseColorError::Empty));
+        assert_eq!("@".parse::<Color>(), Err(ParseColorError::Unexpected(0)));
+        assert_eq!(
+            "lab(50 0 0)".parse::<Color>(),
+            Err(ParseColorError::UnknownFunction("lab".to_owned()))
+        );
+        assert_eq!(
+            "rgb(1, x, 3)".parse::<Color>(),
+            Err(ParseColorError::ExpectedNumber(7))
+        );
+        assert_eq!(
+            "rgb(1, 2 3)".parse::<Color>(),
+            Err(ParseColorError::Expected(9, ','))
+        );
+        assert_eq!("rgb(1 2 3".parse::<Color>(), Err(ParseColorError::Expected(9, ')')));
+        assert_eq!(
+            "rgb(1deg 2 3)".parse::<Color>(),
+            Err(ParseColorError::InvalidUnit(5))
+        );
+        assert_eq!(
+            "hsl(1% 2% 3%)".parse::<Color>(),
+            Err(ParseColorError::InvalidUnit(5))
+        );
+    }
+
+    #[test]
+    fn display_round_trip() {
+        for v in (0..=255).step_by(5) {
+            for color in [Color::new(v, 255 - v, v / 2, 255), Color::new(v, 0, 1, v)] {
+                assert_eq!(color.to_string().parse(), Ok(color));
+            }
+        }
+    }
+}
@@ -0,0 +1,334 @@
+use super::{linear_to_srgb, srgb_to_linear, to_u8, Color, Hsl, Hsv, Hwb, LinearRgba};
+
+impl Hsl {
+    /// Color from hue, saturation, lightness and alpha.
+    #[inline]
+    #[must_use]
+    pub const fn new(h: f32, s: f32, l: f32, a: f32) -> Self {
+        Self { h, s, l, a }
+    }
+
+    /// Color from hue, saturation and lightness with an alpha of `1.0`.
+    #[inline]
+    #[must_use]
+    pub const fn new_opaque(h: f32, s: f32, l: f32) -> Self {
+        Self::new(h, s, l, 1.0)
+    }
+
+    /// Add `amount` to the lightness, keeping it between `0.0` and `1.0`.
+    ///
+    /// ```
+    /// # use crossd_graphics::color::{Color, Hsl};
+    /// #
+    /// let color = Hsl::from(Color::new_opaque(0, 0, 255));
+    ///
+    /// assert_eq!(Color::from(color.lighten(0.25)), Color::new_opaque(128, 128, 255));
+    /// assert_eq!(Color::from(color.lighten(1.0)), Color::WHITE);
+    /// ```
+    #[must_use]
+    pub fn lighten(self, amount: f32) -> Self {
+        Self { l: (self.l + amount).clamp(0.0, 1.0), ..self }
+    }
+
+    /// Subtract `amount` from the lightness, keeping it between `0.0` and
+    /// `1.0`.
+    #[must_use]
+    pub fn darken(self, amount: f32) -> Self {
+        self.lighten(-amount)
+    }
+
+    /// Add `amount` to the saturation, keeping it between `0.0` and `1.0`.
+    #[must_use]
+    pub fn saturate(self, amount: f32) -> Self {
+        Self { s: (self.s + amount).clamp(0.0, 1.0), ..self }
+    }
+
+    /// Subtract `amount` from the saturation, keeping it between `0.0` and
+    /// `1.0`.
+    #[must_use]
+    pub fn desaturate(self, amount: f32) -> Self {
+        self.saturate(-amount)
+    }
+
+    /// Rotate the hue by `degrees` around the color wheel.
+    #[must_use]
+    pub fn rotate_hue(self, degrees: f32) -> Self {
+        Self { h: (self.h + degrees).rem_euclid(360.0), ..self }
+    }
+
+    /// Color from sRGB encoded components and alpha.
+    fn from_srgb([r, g, b, a]: [f32; 4]) -> Self {
+        let (max, min) = (r.max(g).max(b), r.min(g).min(b));
+        let l = (max + min) / 2.0;
+        let s = match 1.0 - (2.0 * l - 1.0).abs() {
+            d if d > 0.0 => (max - min) / d,
+            _ => 0.0,
+        };
+
+        Self::new(hue(r, g, b), s, l, a)
+    }
+
+    /// Convert to sRGB encoded components and alpha.
+    fn to_srgb(self) -> [f32; 4] {
+        let Self { h, s, l, a } = self;
+        let (s, l) = (s.clamp(0.0, 1.0), l.clamp(0.0, 1.0));
+        let chroma = s * l.min(1.0 - l);
+
+        let f = |n: f32| {
+            let k = (n + h / 30.0).rem_euclid(12.0);
+
+            l - chroma * (k - 3.0).min(9.0 - k).clamp(-1.0, 1.0)
+        };
+
+        [f(0.0), f(8.0), f(4.0), a]
+    }
+}
+
+impl Hsv {
+    /// Color from hue, saturation, value and alpha.
+    #[inline]
+    #[must_use]
+    pub const fn new(h: f32, s: f32, v: f32, a: f32) -> Self {
+        Self { h, s, v, a }
+    }
+
+    /// Color from hue, saturation and value with an alpha of `1.0`.
+    #[inline]
+    #[must_use]
+    pub const fn new_opaque(h: f32, s: f32, v: f32) -> Self {
+        Self::new(h, s, v, 1.0)
+    }
+
+    /// Rotate the hue by `degrees` around the color wheel.
+    #[must_use]
+    pub fn rotate_hue(self, degrees: f32) -> Self {
+        Self { h: (self.h + degrees).rem_euclid(360.0), ..self }
+    }
+
+    /// Color from sRGB encoded components and alpha.
+    fn from_srgb([r, g, b, a]: [f32; 4]) -> Self {
+        let (max, min) = (r.max(g).max(b), r.min(g).min(b));
+        let s = if max > 0.0 { (max - min) / max } else { 0.0 };
+
+        Self::new(hue(r, g, b), s, max, a)
+    }
+
+    /// Convert to sRGB encoded components and alpha.
+    fn to_srgb(self) -> [f32; 4] {
+        let Self { h, s, v, a } = self;
+        let (s, v) = (s.clamp(0.0, 1.0), v.clamp(0.0, 1.0));
+
+        let f = |n: f32| {
+            let k = (n + h / 60.0).rem_euclid(6.0);
+
+            v - v * s * k.min(4.0 - k).clamp(0.0, 1.0)
+        };
+
+        [f(5.0), f(3.0), f(1.0), a]
+    }
+}
+
+impl Hwb {
+    /// Color from hue, whiteness, blackness and alpha.
+    #[inline]
+    #[must_use]
+    pub const fn new(h: f32, w: f32, b: f32, a: f32) -> Self {
+        Self { h, w, b, a }
+    }
+
+    /// Color from hue, whiteness and blackness with an alpha of `1.0`.
+    #[inline]
+    #[must_use]
+    pub const fn new_opaque(h: f32, w: f32, b: f32) -> Self {
+        Self::new(h, w, b, 1.0)
+    }
+
+    /// Rotate the hue by `degrees` around the color wheel.
+    #[must_use]
+    pub fn rotate_hue(self, degrees: f32) -> Self {
+        Self { h: (self.h + degrees).rem_euclid(360.0), ..self }
+    }
+
+    /// Color from sRGB encoded components and alpha.
+    fn from_srgb([r, g, b, a]: [f32; 4]) -> Self {
+        let (max, min) = (r.max(g).max(b), r.min(g).min(b));
+
+        Self::new(hue(r, g, b), min, 1.0 - max, a)
+    }
+
+    /// Convert to sRGB encoded components and alpha.
+    fn to_srgb(self) -> [f32; 4] {
+        let Self { h, w, b, a } = self;
+        let (w, b) = (w.clamp(0.0, 1.0), b.clamp(0.0, 1.0));
+
+        if w + b >= 1.0 {
+            let gray = w / (w + b);
+
+            return [gray, gray, gray, a];
+        }
+
+        // the pure hue, mixed with white and black
+        let [red, green, blue, _] = Hsv::new(h, 1.0, 1.0, a).to_srgb();
+        let f = |v: f32| v * (1.0 - w - b) + w;
+
+        [f(red), f(green), f(blue), a]
+    }
+}
+
+impl Color {
+    /// Make the color lighter, see [`Hsl::lighten`].
+    ///
+    /// ```
+    /// # use crossd_graphics::color::Color;
+    /// #
+    /// let base = Color::new_opaque(51, 102, 204);
+    /// let hover = base.lighten(0.1);
+    ///
+    /// assert_eq!(hover, Color::new_opaque(92, 133, 214));
+    /// assert_eq!(hover.darken(0.1), base);
+    /// ```
+    #[must_use]
+    pub fn lighten(self, amount: f32) -> Self {
+        Hsl::from(self).lighten(amount).into()
+    }
+
+    /// Make the color darker, see [`Hsl::darken`].
+    #[must_use]
+    pub fn darken(self, amount: f32) -> Self {
+        Hsl::from(self).darken(amount).into()
+    }
+
+    /// Make the color more saturated, see [`Hsl::saturate`].
+    #[must_use]
+    pub fn saturate(self, amount: f32) -> Self {
+        Hsl::from(self).saturate(amount).into()
+    }
+
+    /// Make the color less saturated, see [`Hsl::desaturate`].
+    #[must_use]
+    pub fn desaturate(self, amount: f32) -> Self {
+        Hsl::from(self).desaturate(amount).into()
+    }
+
+    /// Rotate the hue of the color by `degrees` around the color wheel.
+    ///
+    /// ```
+    /// # use crossd_graphics::color::Color;
+    /// #
+    /// assert_eq!(Color::RED.rotate_hue(120.0), Color::GREEN);
+    /// assert_eq!(Color::RED.rotate_hue(-120.0), Color::BLUE);
+    /// ```
+    #[must_use]
+    pub fn rotate_hue(self, degrees: f32) -> Self {
+        Hsl::from(self).rotate_hue(degrees).into()
+    }
+}
+
+/// Conversions between a color model and the RGB types, through it's
+/// `from_srgb` and `to_srgb`.
+macro_rules! impl_rgb_conversions {
+    ($($model:ty),*) => {
+        $(
+            impl From<Color> for $model {
+                fn from(color: Color) -> Self {
+                    Self::from_srgb(color.to_array().map(|v| f32::from(v) / 255.0))
+                }
+            }
+
+            impl From<$model> for Color {
+                fn from(color: $model) -> Self {
+                    Self::from_array(color.to_srgb().map(to_u8))
+                }
+            }
+
+            impl From<LinearRgba> for $model {
+                fn from(color: LinearRgba) -> Self {
+                    let LinearRgba { r, g, b, a } = color;
+                    let [r, g, b] = [r, g, b].map(linear_to_srgb);
+
+                    Self::from_srgb([r, g, b, a])
+                }
+            }
+
+            impl From<$model> for LinearRgba {
+                fn from(color: $model) -> Self {
+                    let [r, g, b, a] = color.to_srgb();
+                    let [r, g, b] = [r, g, b].map(srgb_to_linear);
+
+                    Self::new(r, g, b, a)
+                }
+            }
+        )*
+    };
+}
+
+impl_rgb_conversions!(Hsl, Hsv, Hwb);
+
+/// The hue in degrees of sRGB encoded components, or `0.0` for grays.
+fn hue(r: f32, g: f32, b: f32) -> f32 {
+    let (max, min) = (r.max(g).max(b), r.min(g).min(b));
+    let delta = max - min;
+
+    if delta <= 0.0 {
+        return 0.0;
+    }
+
+    let sector = if max == r {
+        (g - b) / delta
+    } else if max == g {
+        (b - r) / delta + 2.0
+    } else {
+        (r - g) / delta + 4.0
+    };
+
+    (sector * 60.0).rem_euclid(360.0)
+}
+
+#[cfg(test)]
+mod test {
+    use super::super::{Color, Hsl, Hsv, Hwb, LinearRgba};
+
+    /// Colors on a grid through the RGB cube.
+    fn colors() -> impl Iterator<Item = Color> {
+        let steps = (0..=255).step_by(15);
+
+        steps.clone().flat_map(move |r| {
+            let steps = steps.clone();
+
+            steps
+                .clone()
+                .flat_map(move |g| steps.clone().map(move |b| Color::new(r, g, b, 128)))
+        })
+    }
+
+    #[test]
+    fn round_trip() {
+        for color in colors() {
+            assert_eq!(Color::from(Hsl::from(color)), color);
+            assert_eq!(Color::from(Hsv::from(color)), color);
+            assert_eq!(Color::from(Hwb::from(color)), color);
+
+            let linear = LinearRgba::from(color);
+            assert_eq!(Color::from(LinearRgba::from(Hsl::from(linear))), color);
+        }
+    }
+
+    #[test]
+    fn known_values() {
+        let yellow = Color::new_opaque(255, 255, 0);
+
+        assert_eq!(Hsl::from(yellow), Hsl::new_opaque(60.0, 1.0, 0.5));
+        assert_eq!(Hsv::from(yellow), Hsv::new_opaque(60.0, 1.0, 1.0));
+        assert_eq!(Hwb::from(yellow), Hwb::new_opaque(60.0, 0.0, 0.0));
+
+        assert_eq!(
+            Color::from(Hsl::new_opaque(240.0, 1.0, 0.25)),
+            Color::new_opaque(0, 0, 128)
+        );
+        assert_eq!(
+            Color::from(Hsv::new_opaque(-60.0, 0.5, 1.0)),
+            Color::new_opaque(255, 128, 255)
+        );
+        assert_eq!(Color::from(Hwb::new_opaque(0.0, 0.6, 0.6)), Color::splat_opaque(128));
+    }
+}
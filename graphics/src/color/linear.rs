@@ -0,0 +1,164 @@
+use std::sync::OnceLock;
+
+use super::{linear_to_srgb, srgb_to_linear, to_u8, Color, LinearRgba};
+
+impl LinearRgba {
+    /// Color black.
+    ///
+    /// Fully opaque.
+    pub const BLACK: Self = Self::new_opaque(0.0, 0.0, 0.0);
+    /// Fully transparent black.
+    pub const TRANSPARENT: Self = Self::new(0.0, 0.0, 0.0, 0.0);
+    /// Color white.
+    ///
+    /// Fully opaque.
+    pub const WHITE: Self = Self::new_opaque(1.0, 1.0, 1.0);
+
+    /// Color from linear RGB components and alpha.
+    #[inline]
+    #[must_use]
+    pub const fn new(r: f32, g: f32, b: f32, a: f32) -> Self {
+        Self { r, g, b, a }
+    }
+
+    /// Color from linear RGB components with an alpha of `1.0`.
+    #[inline]
+    #[must_use]
+    pub const fn new_opaque(r: f32, g: f32, b: f32) -> Self {
+        Self::new(r, g, b, 1.0)
+    }
+
+    /// Convert to an array `[r, g, b, a]`.
+    #[inline]
+    #[must_use]
+    pub const fn to_array(self) -> [f32; 4] {
+        [self.r, self.g, self.b, self.a]
+    }
+
+    /// Color from array `[r, g, b, a]`.
+    #[inline]
+    #[must_use]
+    pub const fn from_array([r, g, b, a]: [f32; 4]) -> Self {
+        Self::new(r, g, b, a)
+    }
+
+    /// Convert to sRGB using a lookup table, which is faster than the exact
+    /// conversion of `Color::from` and gives the same result, other than for
+    /// components within rounding error of half way between two bytes.
+    ///
+    /// Components are clamped between `0.0` and `1.0`.
+    ///
+    /// ```
+    /// # use crossd_graphics::color::{Color, LinearRgba};
+    /// #
+    /// let color = LinearRgba::new(0.5, 0.0, 2.0, 0.5);
+    ///
+    /// assert_eq!(color.to_color_fast(), Color::from(color));
+    /// assert_eq!(color.to_color_fast(), Color::new(188, 0, 255, 128));
+    /// ```
+    #[must_use]
+    pub fn to_color_fast(self) -> Color {
+        let thresholds = encode_table();
+        let encode = |v: f32| thresholds.partition_point(|t| *t <= v) as u8;
+
+        Color::new(encode(self.r), encode(self.g), encode(self.b), to_u8(self.a))
+    }
+}
+
+impl From<Color> for LinearRgba {
+    /// Decode an sRGB color, using a lookup table.
+    fn from(color: Color) -> Self {
+        let table = decode_table();
+
+        Self::new(
+            table[usize::from(color.r)],
+            table[usize::from(color.g)],
+            table[usize::from(color.b)],
+            f32::from(color.a) / 255.0,
+        )
+    }
+}
+
+impl From<LinearRgba> for Color {
+    /// Encode a linear color to sRGB, clamping components between `0.0` and
+    /// `1.0`.
+    fn from(color: LinearRgba) -> Self {
+        let encode = |v: f32| to_u8(linear_to_srgb(v.clamp(0.0, 1.0)));
+
+        Self::new(encode(color.r), encode(color.g), encode(color.b), to_u8(color.a))
+    }
+}
+
+impl From<[f32; 4]> for LinearRgba {
+    fn from(rgba: [f32; 4]) -> Self {
+        Self::from_array(rgba)
+    }
+}
+
+impl From<LinearRgba> for [f32; 4] {
+    fn from(color: LinearRgba) -> Self {
+        color.to_array()
+    }
+}
+
+/// The linear value of each sRGB byte.
+fn decode_table() -> &'static [f32; 256] {
+    static TABLE: OnceLock<[f32; 256]> = OnceLock::new();
+
+    TABLE.get_or_init(|| std::array::from_fn(|i| srgb_to_linear(i as f32 / 255.0)))
+}
+
+/// The linear values half way between each two consecutive sRGB bytes, so
+/// that the amount of them below a value is it's byte.
+fn encode_table() -> &'static [f32; 255] {
+    static TABLE: OnceLock<[f32; 255]> = OnceLock::new();
+
+    TABLE
+        .get_or_init(|| std::array::from_fn(|i| srgb_to_linear((i as f32 + 0.5) / 255.0)))
+}
+
+#[cfg(test)]
+mod test {
+    use super::super::{linear_to_srgb, srgb_to_linear, Color, LinearRgba};
+
+    #[test]
+    fn round_trip() {
+        for v in 0..=255 {
+            let color = Color::new(v, v, v, v);
+            let linear = LinearRgba::from(color);
+
+            assert_eq!(Color::from(linear), color);
+            assert_eq!(linear.to_color_fast(), color);
+        }
+    }
+
+    #[test]
+    fn transfer_functions() {
+        assert_eq!(srgb_to_linear(0.0), 0.0);
+        assert_eq!(srgb_to_linear(1.0), 1.0);
+        assert!((srgb_to_linear(0.5) - 0.214_041).abs() < 1e-6);
+        assert!((linear_to_srgb(0.214_041) - 0.5).abs() < 1e-6);
+
+        for i in 0..=1000 {
+            let v = i as f32 / 1000.0;
+
+            assert!((linear_to_srgb(srgb_to_linear(v)) - v).abs() < 1e-5);
+        }
+    }
+
+    #[test]
+    fn fast_encode() {
+        for i in 0..=100_000 {
+            let v = i as f32 / 100_000.0;
+            let color = LinearRgba::new_opaque(v, v, v);
+
+            // values half way between two bytes may round either way
+            let (fast, exact) = (color.to_color_fast(), Color::from(color));
+            assert!(fast.r.abs_diff(exact.r) <= 1, "{color:?}");
+        }
+
+        let out_of_range = LinearRgba::new(-1.0, 2.0, f32::NAN, 1.5);
+        assert_eq!(out_of_range.to_color_fast(), Color::new(0, 255, 0, 255));
+        assert_eq!(Color::from(out_of_range), Color::new(0, 255, 0, 255));
+    }
+}
@@ -0,0 +1,316 @@
+use super::{Color, LinearRgba, Oklab, Oklch};
+
+impl Oklab {
+    /// Color from lightness, `a`, `b` and alpha.
+    #[inline]
+    #[must_use]
+    pub const fn new(l: f32, a: f32, b: f32, alpha: f32) -> Self {
+        Self { l, a, b, alpha }
+    }
+
+    /// Color from lightness, `a` and `b` with an alpha of `1.0`.
+    #[inline]
+    #[must_use]
+    pub const fn new_opaque(l: f32, a: f32, b: f32) -> Self {
+        Self::new(l, a, b, 1.0)
+    }
+
+    /// Whether the color is inside the sRGB gamut, and so can be converted to
+    /// an RGB type without changing it.
+    #[must_use]
+    pub fn in_gamut(self) -> bool {
+        let LinearRgba { r, g, b, .. } = self.to_linear_unmapped();
+
+        [r, g, b].into_iter().all(|v| (-GAMUT_EPSILON..=1.0 + GAMUT_EPSILON).contains(&v))
+    }
+
+    /// The distance between two colors, where about `0.02` is just noticeable.
+    #[must_use]
+    pub fn distance(self, other: Self) -> f32 {
+        let (l, a, b) = (self.l - other.l, self.a - other.a, self.b - other.b);
+
+        (l * l + a * a + b * b).sqrt()
+    }
+
+    /// Convert to linear sRGB, with components outside of `0.0..=1.0` if the
+    /// color is out of gamut.
+    fn to_linear_unmapped(self) -> LinearRgba {
+        let Self { l, a, b, alpha } = self;
+
+        let l_ = l + 0.396_337_78 * a + 0.215_803_76 * b;
+        let m_ = l - 0.105_561_346 * a - 0.063_854_17 * b;
+        let s_ = l - 0.089_484_18 * a - 1.291_485_5 * b;
+
+        let (l, m, s) = (l_ * l_ * l_, m_ * m_ * m_, s_ * s_ * s_);
+
+        LinearRgba::new(
+            4.076_741_7 * l - 3.307_711_6 * m + 0.230_969_94 * s,
+            -1.268_438 * l + 2.609_757_4 * m - 0.341_319_38 * s,
+            -0.004_196_086_3 * l - 0.703_418_6 * m + 1.707_614_7 * s,
+            alpha,
+        )
+    }
+}
+
+impl Oklch {
+    /// Color from lightness, chroma, hue and alpha.
+    #[inline]
+    #[must_use]
+    pub const fn new(l: f32, c: f32, h: f32, alpha: f32) -> Self {
+        Self { l, c, h, alpha }
+    }
+
+    /// Color from lightness, chroma and hue with an alpha of `1.0`.
+    #[inline]
+    #[must_use]
+    pub const fn new_opaque(l: f32, c: f32, h: f32) -> Self {
+        Self::new(l, c, h, 1.0)
+    }
+
+    /// Whether the color is inside the sRGB gamut, and so can be converted to
+    /// an RGB type without changing it.
+    #[must_use]
+    pub fn in_gamut(self) -> bool {
+        Oklab::from(self).in_gamut()
+    }
+
+    /// Rotate the hue by `degrees` around the color wheel.
+    #[must_use]
+    pub fn rotate_hue(self, degrees: f32) -> Self {
+        Self { h: (self.h + degrees).rem_euclid(360.0), ..self }
+    }
+
+    /// Map the color into the sRGB gamut by reducing it's chroma, using the
+    /// [CSS Color 4 algorithm](https://www.w3.org/TR/css-color-4/#binsearch).
+    ///
+    /// The result is the clipped color at the largest chroma where clipping
+    /// makes a just noticeable difference, so it may have a bit more chroma
+    /// than fits exactly.
+    fn to_linear_mapped(self) -> LinearRgba {
+        /// The just noticeable difference, in [`Oklab::distance`].
+        const JND: f32 = 0.02;
+        /// How close the chroma gets to the best one.
+        const EPSILON: f32 = 0.0001;
+
+        if self.l >= 1.0 {
+            return LinearRgba { a: self.alpha, ..LinearRgba::WHITE };
+        }
+
+        if self.l <= 0.0 {
+            return LinearRgba { a: self.alpha, ..LinearRgba::BLACK };
+        }
+
+        let origin = Oklab::from(self);
+
+        if origin.in_gamut() {
+            return clip(origin.to_linear_unmapped());
+        }
+
+        let difference = |current: Self| {
+            let clipped = clip(Oklab::from(current).to_linear_unmapped());
+
+            (clipped, Oklab::from(clipped).distance(Oklab::from(current)))
+        };
+
+        let (mut clipped, distance) = difference(self);
+
+        if distance < JND {
+            return clipped;
+        }
+
+        let (mut min, mut max) = (0.0, self.c);
+        let mut min_in_gamut = true;
+
+        while max - min > EPSILON {
+            let current = Self { c: (min + max) / 2.0, ..self };
+
+            if min_in_gamut && current.in_gamut() {
+                min = current.c;
+                continue;
+            }
+
+            let distance;
+            (clipped, distance) = difference(current);
+
+            if distance < JND {
+                if JND - distance < EPSILON {
+                    break;
+                }
+
+                min_in_gamut = false;
+                min = current.c;
+            } else {
+                max = current.c;
+            }
+        }
+
+        clipped
+    }
+}
+
+impl From<LinearRgba> for Oklab {
+    fn from(color: LinearRgba) -> Self {
+        let LinearRgba { r, g, b, a } = color;
+
+        let l = 0.412_221_46 * r + 0.536_332_55 * g + 0.051_445_995 * b;
+        let m = 0.211_903_5 * r + 0.680_699_5 * g + 0.107_396_96 * b;
+        let s = 0.088_302_46 * r + 0.281_718_85 * g + 0.629_978_7 * b;
+
+        let (l, m, s) = (l.cbrt(), m.cbrt(), s.cbrt());
+
+        Self::new(
+            0.210_454_26 * l + 0.793_617_8 * m - 0.004_072_047 * s,
+            1.977_998_5 * l - 2.428_592_2 * m + 0.450_593_7 * s,
+            0.025_904_037 * l + 0.782_771_77 * m - 0.808_675_77 * s,
+            a,
+        )
+    }
+}
+
+impl From<Oklab> for LinearRgba {
+    /// Convert to linear sRGB, mapping colors outside of it's gamut into it as
+    /// [`Oklch`] does.
+    fn from(color: Oklab) -> Self {
+        Oklch::from(color).into()
+    }
+}
+
+impl From<Color> for Oklab {
+    fn from(color: Color) -> Self {
+        LinearRgba::from(color).into()
+    }
+}
+
+impl From<Oklab> for Color {
+    /// Convert to sRGB, mapping colors outside of it's gamut into it as
+    /// [`Oklch`] does.
+    fn from(color: Oklab) -> Self {
+        LinearRgba::from(color).into()
+    }
+}
+
+impl From<Oklch> for Oklab {
+    fn from(color: Oklch) -> Self {
+        let (sin, cos) = color.h.to_radians().sin_cos();
+
+        Self::new(color.l, color.c * cos, color.c * sin, color.alpha)
+    }
+}
+
+impl From<Oklab> for Oklch {
+    /// Convert to polar coordinates, with a hue of `0.0` for grays.
+    fn from(color: Oklab) -> Self {
+        let c = (color.a * color.a + color.b * color.b).sqrt();
+        let h = if c > 0.0 {
+            color.b.atan2(color.a).to_degrees().rem_euclid(360.0)
+        } else {
+            0.0
+        };
+
+        Self::new(color.l, c, h, color.alpha)
+    }
+}
+
+impl From<LinearRgba> for Oklch {
+    fn from(color: LinearRgba) -> Self {
+        Oklab::from(color).into()
+    }
+}
+
+impl From<Oklch> for LinearRgba {
+    /// Convert to linear sRGB, mapping colors outside of it's gamut into it.
+    fn from(color: Oklch) -> Self {
+        color.to_linear_mapped()
+    }
+}
+
+impl From<Color> for Oklch {
+    fn from(color: Color) -> Self {
+        LinearRgba::from(color).into()
+    }
+}
+
+impl From<Oklch> for Color {
+    /// Convert to sRGB, mapping colors outside of it's gamut into it.
+    fn from(color: Oklch) -> Self {
+        LinearRgba::from(color).into()
+    }
+}
+
+/// How far out of `0.0..=1.0` linear components can be because of rounding
+/// errors and still count as in gamut.
+const GAMUT_EPSILON: f32 = 1e-5;
+
+/// Clamp the RGB components between `0.0` and `1.0`.
+fn clip(color: LinearRgba) -> LinearRgba {
+    let clamp = |v: f32| v.clamp(0.0, 1.0);
+
+    LinearRgba { r: clamp(color.r), g: clamp(color.g), b: clamp(color.b), a: color.a }
+}
+
+#[cfg(test)]
+mod test {
+    use super::super::{Color, LinearRgba, Oklab, Oklch};
+
+    fn assert_close(a: [f32; 3], b: [f32; 3], epsilon: f32) {
+        let close = a.iter().zip(b).all(|(a, b)| (a - b).abs() <= epsilon);
+
+        assert!(close, "{a:?} is not close to {b:?}");
+    }
+
+    #[test]
+    fn reference_values() {
+        // from the CSS Color 4 specification and Björn Ottosson's post
+        let lab = |color: Color| {
+            let Oklab { l, a, b, .. } = color.into();
+            [l, a, b]
+        };
+        let lch = |color: Color| {
+            let Oklch { l, c, h, .. } = color.into();
+            [l, c, h]
+        };
+
+        assert_close(lab(Color::WHITE), [1.0, 0.0, 0.0], 1e-4);
+        assert_close(lab(Color::BLACK), [0.0, 0.0, 0.0], 1e-6);
+        assert_close(lab(Color::RED), [0.627_955, 0.224_863, 0.125_846], 1e-4);
+        assert_close(lab(Color::GREEN), [0.866_440, -0.233_888, 0.179_498], 1e-4);
+        assert_close(lab(Color::BLUE), [0.452_014, -0.032_457, -0.311_528], 1e-4);
+        assert_close(lch(Color::RED), [0.627_955, 0.257_683, 29.233_9], 1e-3);
+        assert_close(lch(Color::BLUE), [0.452_014, 0.313_214, 264.052], 1e-3);
+    }
+
+    #[test]
+    fn round_trip() {
+        for v in (0..=255).step_by(17) {
+            for color in [Color::new(v, 0, 255 - v, v), Color::new(255, v, v / 2, 255)] {
+                assert_eq!(Color::from(Oklab::from(color)), color);
+                assert_eq!(Color::from(Oklch::from(color)), color);
+            }
+        }
+    }
+
+    #[test]
+    fn gamut_mapping() {
+        // far too much chroma for any of these
+        for h in (0..360).step_by(15) {
+            for l in [0.2, 0.5, 0.8] {
+                let color = Oklch::new_opaque(l, 0.5, h as f32);
+                let mapped = LinearRgba::from(color);
+                let back = Oklch::from(mapped);
+
+                assert!(!color.in_gamut());
+                assert!(Oklab::from(mapped).in_gamut());
+                assert!(back.c < color.c);
+                assert!((back.l - l).abs() < 0.02, "{color:?} {back:?}");
+                assert!(
+                    Oklab::from(back).distance(Oklab::from(Oklch { c: back.c, ..color }))
+                        < 0.03
+                );
+            }
+        }
+
+        assert_eq!(Color::from(Oklch::new_opaque(1.2, 0.3, 50.0)), Color::WHITE);
+        assert_eq!(Color::from(Oklch::new_opaque(-0.1, 0.3, 50.0)), Color::BLACK);
+        assert_eq!(Color::from(Oklch::new_opaque(0.627_955, 0.3, 29.233_9)), Color::RED);
+    }
+}
@@ -0,0 +1,154 @@
+use super::Color;
+
+impl Color {
+    /// Convert to a `u32` with the components in the order `0xRRGGBBAA`.
+    ///
+    /// ```
+    /// # use crossd_graphics::color::Color;
+    /// #
+    /// let color = Color::new(0x12, 0x34, 0x56, 0x78);
+    ///
+    /// assert_eq!(color.to_rgba_u32(), 0x1234_5678);
+    /// ```
+    #[inline]
+    #[must_use]
+    pub const fn to_rgba_u32(self) -> u32 {
+        pack([self.r, self.g, self.b, self.a])
+    }
+
+    /// Convert to a `u32` with the components in the order `0xAARRGGBB`.
+    ///
+    /// ```
+    /// # use crossd_graphics::color::Color;
+    /// #
+    /// let color = Color::new(0x12, 0x34, 0x56, 0x78);
+    ///
+    /// assert_eq!(color.to_argb_u32(), 0x7812_3456);
+    /// ```
+    #[inline]
+    #[must_use]
+    pub const fn to_argb_u32(self) -> u32 {
+        pack([self.a, self.r, self.g, self.b])
+    }
+
+    /// Convert to a `u32` with the components in the order `0xBBGGRRAA`.
+    ///
+    /// ```
+    /// # use crossd_graphics::color::Color;
+    /// #
+    /// let color = Color::new(0x12, 0x34, 0x56, 0x78);
+    ///
+    /// assert_eq!(color.to_bgra_u32(), 0x5634_1278);
+    /// ```
+    #[inline]
+    #[must_use]
+    pub const fn to_bgra_u32(self) -> u32 {
+        pack([self.b, self.g, self.r, self.a])
+    }
+
+    /// Convert to a `u32` with the components in the order `0xAABBGGRR`.
+    ///
+    /// ```
+    /// # use crossd_graphics::color::Color;
+    /// #
+    /// let color = Color::new(0x12, 0x34, 0x56, 0x78);
+    ///
+    /// assert_eq!(color.to_abgr_u32(), 0x7856_3412);
+    /// ```
+    #[inline]
+    #[must_use]
+    pub const fn to_abgr_u32(self) -> u32 {
+        pack([self.a, self.b, self.g, self.r])
+    }
+
+    /// Color from a `u32` with the components in the order `0xRRGGBBAA`.
+    #[inline]
+    #[must_use]
+    pub const fn from_rgba_u32(v: u32) -> Self {
+        let [r, g, b, a] = unpack(v);
+
+        Self::new(r, g, b, a)
+    }
+
+    /// Color from a `u32` with the components in the order `0xAARRGGBB`.
+    #[inline]
+    #[must_use]
+    pub const fn from_argb_u32(v: u32) -> Self {
+        let [a, r, g, b] = unpack(v);
+
+        Self::new(r, g, b, a)
+    }
+
+    /// Color from a `u32` with the components in the order `0xBBGGRRAA`.
+    #[inline]
+    #[must_use]
+    pub const fn from_bgra_u32(v: u32) -> Self {
+        let [b, g, r, a] = unpack(v);
+
+        Self::new(r, g, b, a)
+    }
+
+    /// Color from a `u32` with the components in the order `0xAABBGGRR`.
+    #[inline]
+    #[must_use]
+    pub const fn from_abgr_u32(v: u32) -> Self {
+        let [a, b, g, r] = unpack(v);
+
+        Self::new(r, g, b, a)
+    }
+}
+
+/// Pack bytes into a `u32`, the first being the most significant.
+///
+/// Shifting rather than transmuting keeps the order the same regardless of
+/// the endianness of the machine.
+const fn pack([a, b, c, d]: [u8; 4]) -> u32 {
+    (a as u32) << 24 | (b as u32) << 16 | (c as u32) << 8 | d as u32
+}
+
+/// Unpack a `u32` into bytes, the most significant first.
+const fn unpack(v: u32) -> [u8; 4] {
+    [(v >> 24) as u8, (v >> 16) as u8, (v >> 8) as u8, v as u8]
+}
+
+#[cfg(test)]
+mod test {
+    use super::super::Color;
+
+    #[test]
+    fn packing() {
+        let color = Color::new(0x11, 0x22, 0x33, 0x44);
+
+        assert_eq!(color.to_rgba_u32(), 0x1122_3344);
+        assert_eq!(color.to_argb_u32(), 0x4411_2233);
+        assert_eq!(color.to_bgra_u32(), 0x3322_1144);
+        assert_eq!(color.to_abgr_u32(), 0x4433_2211);
+
+        assert_eq!(Color::from_rgba_u32(0x1122_3344), color);
+        assert_eq!(Color::from_argb_u32(0x4411_2233), color);
+        assert_eq!(Color::from_bgra_u32(0x3322_1144), color);
+        assert_eq!(Color::from_abgr_u32(0x4433_2211), color);
+    }
+
+    #[test]
+    fn hex() {
+        assert_eq!(Color::BLACK.to_hex(), 0xFF00_0000);
+        assert_eq!(Color::RED.to_hex(), 0xFFFF_0000);
+        assert_eq!(Color::BLUE.to_hex(), 0xFF00_00FF);
+        assert_eq!(Color::new(0, 0, 0, 0x80).to_hex(), 0x8000_0000);
+
+        assert_eq!(Color::from_hex(0xFF00_FF00), Color::GREEN);
+        assert_eq!(Color::from_hex(0x8012_3456), Color::new(0x12, 0x34, 0x56, 0x80));
+    }
+
+    #[test]
+    fn round_trip() {
+        for v in [0, 1, 0x1234_5678, 0x8000_0001, 0xDEAD_BEEF, u32::MAX] {
+            assert_eq!(Color::from_rgba_u32(v).to_rgba_u32(), v);
+            assert_eq!(Color::from_argb_u32(v).to_argb_u32(), v);
+            assert_eq!(Color::from_bgra_u32(v).to_bgra_u32(), v);
+            assert_eq!(Color::from_abgr_u32(v).to_abgr_u32(), v);
+            assert_eq!(Color::from_hex(v).to_hex(), v);
+        }
+    }
+}
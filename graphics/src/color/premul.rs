@@ -0,0 +1,258 @@
+use wgpu::{BlendComponent, BlendFactor, BlendOperation, BlendState};
+
+use super::{Color, LinearRgba, PorterDuff, PremulColor};
+
+impl PremulColor {
+    /// Fully transparent.
+    pub const TRANSPARENT: Self = Self::new(0.0, 0.0, 0.0, 0.0);
+
+    /// Color from premultiplied linear RGB components and alpha.
+    #[inline]
+    #[must_use]
+    pub const fn new(r: f32, g: f32, b: f32, a: f32) -> Self {
+        Self { r, g, b, a }
+    }
+
+    /// Convert to an array `[r, g, b, a]`.
+    #[inline]
+    #[must_use]
+    pub const fn to_array(self) -> [f32; 4] {
+        [self.r, self.g, self.b, self.a]
+    }
+
+    /// Color from array `[r, g, b, a]`.
+    #[inline]
+    #[must_use]
+    pub const fn from_array([r, g, b, a]: [f32; 4]) -> Self {
+        Self::new(r, g, b, a)
+    }
+
+    /// Divide the RGB components by alpha.
+    ///
+    /// A fully transparent color has no RGB components left, so it becomes
+    /// transparent black.
+    #[must_use]
+    pub fn unpremultiply(self) -> LinearRgba {
+        let Self { r, g, b, a } = self;
+
+        if a <= 0.0 {
+            return LinearRgba::TRANSPARENT;
+        }
+
+        LinearRgba::new(r / a, g / a, b / a, a)
+    }
+
+    /// Draw the color on top of `dst`, see [`PorterDuff::SrcOver`].
+    ///
+    /// ```
+    /// # use crossd_graphics::color::{LinearRgba, PremulColor};
+    /// #
+    /// let red = LinearRgba::new(1.0, 0.0, 0.0, 0.5).premultiply();
+    /// let blue = LinearRgba::new(0.0, 0.0, 1.0, 1.0).premultiply();
+    ///
+    /// assert_eq!(red.over(blue), PremulColor::new(0.5, 0.0, 0.5, 1.0));
+    /// assert_eq!(red.over(PremulColor::TRANSPARENT), red);
+    /// ```
+    #[inline]
+    #[must_use]
+    pub fn over(self, dst: Self) -> Self {
+        PorterDuff::SrcOver.composite(self, dst)
+    }
+
+    /// Multiply each component by `v`.
+    fn scale(self, v: f32) -> Self {
+        Self::from_array(self.to_array().map(|c| c * v))
+    }
+}
+
+impl LinearRgba {
+    /// Multiply the RGB components by alpha.
+    #[inline]
+    #[must_use]
+    pub fn premultiply(self) -> PremulColor {
+        let Self { r, g, b, a } = self;
+
+        PremulColor::new(r * a, g * a, b * a, a)
+    }
+}
+
+impl PorterDuff {
+    /// All operators.
+    pub const ALL: [Self; 12] = [
+        Self::Clear,
+        Self::Src,
+        Self::Dst,
+        Self::SrcOver,
+        Self::DstOver,
+        Self::SrcIn,
+        Self::DstIn,
+        Self::SrcOut,
+        Self::DstOut,
+        Self::SrcAtop,
+        Self::DstAtop,
+        Self::Xor,
+    ];
+
+    /// Composite `src` onto `dst` on the CPU.
+    ///
+    /// Gives the same result as drawing with [`PorterDuff::blend_state`],
+    /// other than for rounding.
+    ///
+    /// ```
+    /// # use crossd_graphics::color::{PorterDuff, PremulColor};
+    /// #
+    /// let src = PremulColor::new(0.5, 0.0, 0.0, 0.5);
+    /// let dst = PremulColor::new(0.0, 0.0, 0.25, 0.25);
+    ///
+    /// assert_eq!(
+    ///     PorterDuff::SrcIn.composite(src, dst),
+    ///     PremulColor::new(0.125, 0.0, 0.0, 0.125)
+    /// );
+    /// assert_eq!(
+    ///     PorterDuff::Xor.composite(src, dst),
+    ///     PremulColor::new(0.375, 0.0, 0.125, 0.5)
+    /// );
+    /// ```
+    #[must_use]
+    pub fn composite(self, src: PremulColor, dst: PremulColor) -> PremulColor {
+        let (src_alpha, dst_alpha) = (src.a, dst.a);
+
+        // how much of the source and destination are kept
+        let (src_part, dst_part) = match self {
+            Self::Clear => (0.0, 0.0),
+            Self::Src => (1.0, 0.0),
+            Self::Dst => (0.0, 1.0),
+            Self::SrcOver => (1.0, 1.0 - src_alpha),
+            Self::DstOver => (1.0 - dst_alpha, 1.0),
+            Self::SrcIn => (dst_alpha, 0.0),
+            Self::DstIn => (0.0, src_alpha),
+            Self::SrcOut => (1.0 - dst_alpha, 0.0),
+            Self::DstOut => (0.0, 1.0 - src_alpha),
+            Self::SrcAtop => (dst_alpha, 1.0 - src_alpha),
+            Self::DstAtop => (1.0 - dst_alpha, src_alpha),
+            Self::Xor => (1.0 - dst_alpha, 1.0 - src_alpha),
+        };
+
+        let src = src.scale(src_part).to_array();
+        let dst = dst.scale(dst_part).to_array();
+
+        PremulColor::from_array([0, 1, 2, 3].map(|i| src[i] + dst[i]))
+    }
+
+    /// The blend state for drawing premultiplied colors with the operator on
+    /// the GPU.
+    #[must_use]
+    pub fn blend_state(self) -> BlendState {
+        use BlendFactor::{
+            DstAlpha,
+            One,
+            OneMinusDstAlpha,
+            OneMinusSrcAlpha,
+            SrcAlpha,
+            Zero,
+        };
+
+        let (src_factor, dst_factor) = match self {
+            Self::Clear => (Zero, Zero),
+            Self::Src => (One, Zero),
+            Self::Dst => (Zero, One),
+            Self::SrcOver => (One, OneMinusSrcAlpha),
+            Self::DstOver => (OneMinusDstAlpha, One),
+            Self::SrcIn => (DstAlpha, Zero),
+            Self::DstIn => (Zero, SrcAlpha),
+            Self::SrcOut => (OneMinusDstAlpha, Zero),
+            Self::DstOut => (Zero, OneMinusSrcAlpha),
+            Self::SrcAtop => (DstAlpha, OneMinusSrcAlpha),
+            Self::DstAtop => (OneMinusDstAlpha, SrcAlpha),
+            Self::Xor => (OneMinusDstAlpha, OneMinusSrcAlpha),
+        };
+
+        let component =
+            BlendComponent { src_factor, dst_factor, operation: BlendOperation::Add };
+
+        BlendState { color: component, alpha: component }
+    }
+}
+
+impl From<LinearRgba> for PremulColor {
+    fn from(color: LinearRgba) -> Self {
+        color.premultiply()
+    }
+}
+
+impl From<PremulColor> for LinearRgba {
+    fn from(color: PremulColor) -> Self {
+        color.unpremultiply()
+    }
+}
+
+impl From<Color> for PremulColor {
+    fn from(color: Color) -> Self {
+        LinearRgba::from(color).premultiply()
+    }
+}
+
+impl From<PremulColor> for Color {
+    fn from(color: PremulColor) -> Self {
+        color.unpremultiply().into()
+    }
+}
+
+impl From<[f32; 4]> for PremulColor {
+    fn from(rgba: [f32; 4]) -> Self {
+        Self::from_array(rgba)
+    }
+}
+
+impl From<PremulColor> for [f32; 4] {
+    fn from(color: PremulColor) -> Self {
+        color.to_array()
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::super::{LinearRgba, PorterDuff, PremulColor};
+
+    #[test]
+    fn premultiply() {
+        let color = LinearRgba::new(0.5, 0.25, 1.0, 0.5);
+
+        assert_eq!(color.premultiply(), PremulColor::new(0.25, 0.125, 0.5, 0.5));
+        assert_eq!(color.premultiply().unpremultiply(), color);
+        assert_eq!(
+            LinearRgba::new(1.0, 1.0, 1.0, 0.0).premultiply().unpremultiply(),
+            LinearRgba::TRANSPARENT
+        );
+    }
+
+    #[test]
+    fn operators() {
+        let src = PremulColor::new(0.4, 0.0, 0.0, 0.4);
+        let dst = PremulColor::new(0.0, 0.5, 0.0, 0.5);
+
+        let expected = [
+            (PorterDuff::Clear, [0.0, 0.0, 0.0, 0.0]),
+            (PorterDuff::Src, [0.4, 0.0, 0.0, 0.4]),
+            (PorterDuff::Dst, [0.0, 0.5, 0.0, 0.5]),
+            (PorterDuff::SrcOver, [0.4, 0.3, 0.0, 0.7]),
+            (PorterDuff::DstOver, [0.2, 0.5, 0.0, 0.7]),
+            (PorterDuff::SrcIn, [0.2, 0.0, 0.0, 0.2]),
+            (PorterDuff::DstIn, [0.0, 0.2, 0.0, 0.2]),
+            (PorterDuff::SrcOut, [0.2, 0.0, 0.0, 0.2]),
+            (PorterDuff::DstOut, [0.0, 0.3, 0.0, 0.3]),
+            (PorterDuff::SrcAtop, [0.2, 0.3, 0.0, 0.5]),
+            (PorterDuff::DstAtop, [0.2, 0.2, 0.0, 0.4]),
+            (PorterDuff::Xor, [0.2, 0.3, 0.0, 0.5]),
+        ];
+
+        for (op, rgba) in expected {
+            let result = op.composite(src, dst).to_array();
+            let close = result.iter().zip(rgba).all(|(a, b)| (a - b).abs() < 1e-6);
+
+            assert!(close, "{op:?} gave {result:?} instead of {rgba:?}");
+        }
+
+        assert_eq!(expected.map(|(op, _)| op), PorterDuff::ALL);
+    }
+}
@@ -0,0 +1,213 @@
+use super::{
+    linear_to_srgb,
+    srgb_to_linear,
+    Color,
+    ColorSpace,
+    HueInterpolation,
+    LinearRgba,
+    Oklab,
+    Oklch,
+};
+
+impl Color {
+    /// Interpolate from the color at `0.0` to `other` at `1.0` in `space`,
+    /// like CSS `color-mix()`.
+    ///
+    /// Mixing sRGB encoded components gives darker and duller colors half way
+    /// than most expect, [`ColorSpace::Oklab`] looks the most even.
+    ///
+    /// ```
+    /// # use crossd_graphics::color::{Color, ColorSpace, HueInterpolation};
+    /// #
+    /// let (red, blue) = (Color::RED, Color::BLUE);
+    ///
+    /// assert_eq!(red.mix(blue, 0.25, ColorSpace::Srgb), Color::new_opaque(191, 0, 64));
+    /// assert_eq!(red.mix(blue, 0.5, ColorSpace::Oklab), Color::new_opaque(140, 83, 162));
+    ///
+    /// // through purple, or the long way through yellow and green
+    /// let shorter = ColorSpace::Oklch(HueInterpolation::Shorter);
+    /// let longer = ColorSpace::Oklch(HueInterpolation::Longer);
+    ///
+    /// assert_eq!(red.mix(blue, 0.5, shorter), Color::new_opaque(183, 0, 190));
+    /// assert_eq!(red.mix(blue, 0.5, longer), Color::new_opaque(0, 138, 14));
+    /// ```
+    #[must_use]
+    pub fn mix(self, other: Self, t: f32, space: ColorSpace) -> Self {
+        space.mix(self.into(), other.into(), t).into()
+    }
+}
+
+impl ColorSpace {
+    /// Interpolate from `from` at `0.0` to `to` at `1.0` in the space.
+    pub(crate) fn mix(self, from: LinearRgba, to: LinearRgba, t: f32) -> LinearRgba {
+        let [mut from, mut to] = [from, to].map(|color| self.components(color));
+
+        if let Self::Oklch(hue) = self {
+            hue.fix_hues(&mut from, &mut to);
+        }
+
+        let alpha = from[3] + (to[3] - from[3]) * t;
+        let component = |i: usize| {
+            // hues aren't premultiplied
+            if matches!(self, Self::Oklch(_)) && i == 2 {
+                return from[i] + (to[i] - from[i]) * t;
+            }
+
+            let (from, to) = (from[i] * from[3], to[i] * to[3]);
+            let premul = from + (to - from) * t;
+
+            if alpha > 0.0 {
+                premul / alpha
+            } else {
+                0.0
+            }
+        };
+
+        self.to_linear([component(0), component(1), component(2), alpha])
+    }
+
+    /// The components of a color in the space, with alpha last.
+    fn components(self, color: LinearRgba) -> [f32; 4] {
+        let LinearRgba { r, g, b, a } = color;
+
+        match self {
+            Self::Srgb => {
+                let [r, g, b] = [r, g, b].map(linear_to_srgb);
+
+                [r, g, b, a]
+            },
+            Self::LinearSrgb => [r, g, b, a],
+            Self::Oklab => {
+                let Oklab { l, a, b, alpha } = color.into();
+
+                [l, a, b, alpha]
+            },
+            Self::Oklch(_) => {
+                let Oklch { l, c, h, alpha } = color.into();
+
+                [l, c, h, alpha]
+            },
+        }
+    }
+
+    /// Convert components in the space back to linear sRGB.
+    fn to_linear(self, [x, y, z, alpha]: [f32; 4]) -> LinearRgba {
+        match self {
+            Self::Srgb => {
+                let [r, g, b] = [x, y, z].map(srgb_to_linear);
+
+                LinearRgba::new(r, g, b, alpha)
+            },
+            Self::LinearSrgb => LinearRgba::new(x, y, z, alpha),
+            Self::Oklab => Oklab::new(x, y, z, alpha).into(),
+            Self::Oklch(_) => Oklch::new(x, y, z, alpha).into(),
+        }
+    }
+}
+
+impl HueInterpolation {
+    /// Adjust the hues of two OKLCH colors `[l, c, h, alpha]`, so that going
+    /// from one to the other goes around the right way.
+    fn fix_hues(self, from: &mut [f32; 4], to: &mut [f32; 4]) {
+        /// Chroma below which a color has no hue to speak of.
+        const POWERLESS: f32 = 1e-4;
+
+        match (from[1] < POWERLESS, to[1] < POWERLESS) {
+            (true, false) => from[2] = to[2],
+            (false, true) => to[2] = from[2],
+            _ => {},
+        }
+
+        let delta = to[2] - from[2];
+        let (from_first, to_first) = match self {
+            Self::Shorter => (delta > 180.0, delta < -180.0),
+            Self::Longer => {
+                (0.0 < delta && delta < 180.0, -180.0 < delta && delta <= 0.0)
+            },
+            Self::Increasing => (false, delta < 0.0),
+            Self::Decreasing => (delta > 0.0, false),
+        };
+
+        if from_first {
+            from[2] += 360.0;
+        } else if to_first {
+            to[2] += 360.0;
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::super::{Color, ColorSpace, HueInterpolation, LinearRgba, Oklch};
+
+    #[test]
+    fn spaces() {
+        let (red, blue) = (Color::RED, Color::BLUE);
+
+        assert_eq!(red.mix(blue, 0.25, ColorSpace::Srgb), Color::new_opaque(191, 0, 64));
+        assert_eq!(
+            red.mix(blue, 0.5, ColorSpace::LinearSrgb),
+            Color::new_opaque(188, 0, 188)
+        );
+
+        for space in [
+            ColorSpace::Srgb,
+            ColorSpace::LinearSrgb,
+            ColorSpace::Oklab,
+            ColorSpace::Oklch(HueInterpolation::Shorter),
+            ColorSpace::Oklch(HueInterpolation::Longer),
+        ] {
+            assert_eq!(red.mix(blue, 0.0, space), red);
+            assert_eq!(red.mix(blue, 1.0, space), blue);
+            assert_eq!(Color::WHITE.mix(Color::WHITE, 0.3, space), Color::WHITE);
+        }
+    }
+
+    #[test]
+    fn hue() {
+        let hue = |from: f32, to: f32, method| {
+            let [from, to] =
+                [from, to].map(|h| LinearRgba::from(Oklch::new_opaque(0.7, 0.05, h)));
+
+            Oklch::from(ColorSpace::Oklch(method).mix(from, to, 0.5)).h
+        };
+        let expected = [
+            (HueInterpolation::Shorter, 60.0, 60.0, 0.0),
+            (HueInterpolation::Longer, 240.0, 240.0, 180.0),
+            (HueInterpolation::Increasing, 60.0, 240.0, 0.0),
+            (HueInterpolation::Decreasing, 240.0, 60.0, 180.0),
+        ];
+
+        for (method, up, down, across) in expected {
+            assert!((hue(30.0, 90.0, method) - up).abs() < 0.1, "{method:?}");
+            assert!((hue(90.0, 30.0, method) - down).abs() < 0.1, "{method:?}");
+
+            // 350 to 10 degrees, through 0 or 180
+            let h = hue(350.0, 10.0, method);
+            assert!(
+                (h - across).abs() < 0.1 || (h - across - 360.0).abs() < 0.1,
+                "{method:?}"
+            );
+        }
+
+        // white has no hue, so the hue of red is kept, other than for a little
+        // clipping into sRGB
+        let space = ColorSpace::Oklch(HueInterpolation::Shorter);
+        let pink = Oklch::from(Color::RED.mix(Color::WHITE, 0.5, space));
+        let red = Oklch::from(Color::RED);
+
+        assert!((pink.h - red.h).abs() < 2.0, "{pink:?}");
+    }
+
+    #[test]
+    fn premultiplied() {
+        // the RGB of transparent black doesn't darken the mix
+        let half = Color::RED.mix(Color::TRANSPARENT, 0.5, ColorSpace::Srgb);
+
+        assert_eq!(half, Color::new(255, 0, 0, 128));
+        assert_eq!(
+            Color::TRANSPARENT.mix(Color::TRANSPARENT, 0.5, ColorSpace::Oklab),
+            Color::TRANSPARENT
+        );
+    }
+}
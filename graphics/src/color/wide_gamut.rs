@@ -0,0 +1,238 @@
+use super::{
+    linear_to_srgb,
+    srgb_to_linear,
+    Color,
+    DisplayP3,
+    LinearRgba,
+    Oklch,
+    Rec2020,
+};
+
+impl DisplayP3 {
+    /// Color from RGB components and alpha.
+    #[inline]
+    #[must_use]
+    pub const fn new(r: f32, g: f32, b: f32, a: f32) -> Self {
+        Self { r, g, b, a }
+    }
+
+    /// Color from RGB components with an alpha of `1.0`.
+    #[inline]
+    #[must_use]
+    pub const fn new_opaque(r: f32, g: f32, b: f32) -> Self {
+        Self::new(r, g, b, 1.0)
+    }
+
+    /// Convert to an array `[r, g, b, a]`.
+    #[inline]
+    #[must_use]
+    pub const fn to_array(self) -> [f32; 4] {
+        [self.r, self.g, self.b, self.a]
+    }
+
+    /// Color from array `[r, g, b, a]`.
+    #[inline]
+    #[must_use]
+    pub const fn from_array([r, g, b, a]: [f32; 4]) -> Self {
+        Self::new(r, g, b, a)
+    }
+}
+
+impl Rec2020 {
+    /// Color from RGB components and alpha.
+    #[inline]
+    #[must_use]
+    pub const fn new(r: f32, g: f32, b: f32, a: f32) -> Self {
+        Self { r, g, b, a }
+    }
+
+    /// Color from RGB components with an alpha of `1.0`.
+    #[inline]
+    #[must_use]
+    pub const fn new_opaque(r: f32, g: f32, b: f32) -> Self {
+        Self::new(r, g, b, 1.0)
+    }
+
+    /// Convert to an array `[r, g, b, a]`.
+    #[inline]
+    #[must_use]
+    pub const fn to_array(self) -> [f32; 4] {
+        [self.r, self.g, self.b, self.a]
+    }
+
+    /// Color from array `[r, g, b, a]`.
+    #[inline]
+    #[must_use]
+    pub const fn from_array([r, g, b, a]: [f32; 4]) -> Self {
+        Self::new(r, g, b, a)
+    }
+}
+
+/// Linear Display P3 to linear sRGB.
+const P3_TO_SRGB: [[f32; 3]; 3] = [
+    [1.224_940_2, -0.224_940_18, 0.0],
+    [-0.042_056_955, 1.042_057, 0.0],
+    [-0.019_637_555, -0.078_636_05, 1.098_273_6],
+];
+/// Linear sRGB to linear Display P3.
+const SRGB_TO_P3: [[f32; 3]; 3] = [
+    [0.822_461_97, 0.177_538_03, 0.0],
+    [0.033_194_2, 0.966_805_8, 0.0],
+    [0.017_082_631, 0.072_397_44, 0.910_519_9],
+];
+/// Linear Rec. 2020 to linear sRGB.
+const REC2020_TO_SRGB: [[f32; 3]; 3] = [
+    [1.660_491, -0.587_641_1, -0.072_849_86],
+    [-0.124_550_48, 1.132_899_9, -0.008_349_423],
+    [-0.018_150_763, -0.100_578_9, 1.118_729_7],
+];
+/// Linear sRGB to linear Rec. 2020.
+const SRGB_TO_REC2020: [[f32; 3]; 3] = [
+    [0.627_403_9, 0.329_283_04, 0.043_313_067],
+    [0.069_097_29, 0.919_540_4, 0.011_362_316],
+    [0.016_391_44, 0.088_013_31, 0.895_595_3],
+];
+
+/// Conversions between a wide gamut color and the RGB types, through linear
+/// sRGB with the given matrices and transfer functions.
+///
+/// Conversions to [`LinearRgba`] keep colors outside of sRGB as components
+/// outside of `0.0..=1.0`, conversions to [`Color`] map them into it as
+/// [`Oklch`] does.
+macro_rules! impl_wide_gamut_conversions {
+    ($(
+        $ty:ty: $to_srgb:expr, $from_srgb:expr, $to_linear:expr, $from_linear:expr;
+    )*) => {
+        $(
+            impl From<LinearRgba> for $ty {
+                fn from(color: LinearRgba) -> Self {
+                    let LinearRgba { r, g, b, a } = color;
+                    let [r, g, b] = transform($from_srgb, [r, g, b])
+                        .map(|v| signed(v, $from_linear));
+
+                    Self::new(r, g, b, a)
+                }
+            }
+
+            impl From<$ty> for LinearRgba {
+                fn from(color: $ty) -> Self {
+                    let [r, g, b, a] = color.to_array();
+                    let [r, g, b] =
+                        transform($to_srgb, [r, g, b].map(|v| signed(v, $to_linear)));
+
+                    Self::new(r, g, b, a)
+                }
+            }
+
+            impl From<Color> for $ty {
+                fn from(color: Color) -> Self {
+                    LinearRgba::from(color).into()
+                }
+            }
+
+            impl From<$ty> for Color {
+                fn from(color: $ty) -> Self {
+                    Oklch::from(LinearRgba::from(color)).into()
+                }
+            }
+        )*
+    };
+}
+
+impl_wide_gamut_conversions! {
+    DisplayP3: P3_TO_SRGB, SRGB_TO_P3, srgb_to_linear, linear_to_srgb;
+    Rec2020: REC2020_TO_SRGB, SRGB_TO_REC2020, rec2020_to_linear, linear_to_rec2020;
+}
+
+/// Multiply `v` by the matrix `m`.
+fn transform(m: [[f32; 3]; 3], v: [f32; 3]) -> [f32; 3] {
+    m.map(|row| row[0] * v[0] + row[1] * v[1] + row[2] * v[2])
+}
+
+/// Apply a transfer function to the magnitude of `v`, keeping it's sign.
+fn signed(v: f32, f: fn(f32) -> f32) -> f32 {
+    f(v.abs()).copysign(v)
+}
+
+/// The `α` of the BT.2020 transfer function, as CSS Color 4 has it.
+const ALPHA: f32 = 1.099_296_8;
+/// The `β` of the BT.2020 transfer function, where it's linear below.
+const BETA: f32 = 0.018_053_97;
+
+/// Convert a Rec. 2020 encoded component between `0.0` and `1.0` to linear.
+fn rec2020_to_linear(v: f32) -> f32 {
+    if v < BETA * 4.5 {
+        v / 4.5
+    } else {
+        ((v + ALPHA - 1.0) / ALPHA).powf(1.0 / 0.45)
+    }
+}
+
+/// Convert a linear component between `0.0` and `1.0` to Rec. 2020 encoded.
+fn linear_to_rec2020(v: f32) -> f32 {
+    if v < BETA {
+        v * 4.5
+    } else {
+        ALPHA * v.powf(0.45) - (ALPHA - 1.0)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::super::{Color, DisplayP3, LinearRgba, Rec2020};
+
+    fn assert_close(a: [f32; 4], b: [f32; 4]) {
+        let close = a.iter().zip(b).all(|(a, b)| (a - b).abs() < 1e-4);
+
+        assert!(close, "{a:?} is not close to {b:?}");
+    }
+
+    #[test]
+    fn reference_values() {
+        // from the CSS Color 4 conversion code
+        assert_close(
+            DisplayP3::from(Color::RED).to_array(),
+            [0.917_488, 0.200_287, 0.138_561, 1.0],
+        );
+        assert_close(
+            Rec2020::from(Color::RED).to_array(),
+            [0.791_977, 0.230_976, 0.073_761, 1.0],
+        );
+        assert_close(
+            LinearRgba::from(DisplayP3::new_opaque(0.0, 1.0, 0.0)).to_array(),
+            [-0.224_940, 1.042_057, -0.078_636, 1.0],
+        );
+        assert_close(
+            LinearRgba::from(Rec2020::new_opaque(0.0, 0.0, 1.0)).to_array(),
+            [-0.072_850, -0.008_349, 1.118_73, 1.0],
+        );
+    }
+
+    #[test]
+    fn round_trip() {
+        for v in (0..=255).step_by(15) {
+            for color in [Color::new(v, 0, 255 - v, v), Color::new(255, v, v / 2, 255)] {
+                assert_eq!(Color::from(DisplayP3::from(color)), color);
+                assert_eq!(Color::from(Rec2020::from(color)), color);
+            }
+        }
+
+        // colors outside of sRGB survive linear sRGB
+        let p3 = DisplayP3::new(0.1, 1.0, 0.2, 0.5);
+        assert_close(DisplayP3::from(LinearRgba::from(p3)).to_array(), p3.to_array());
+
+        let rec2020 = Rec2020::new(1.0, 0.0, 0.05, 1.0);
+        assert_close(
+            Rec2020::from(LinearRgba::from(rec2020)).to_array(),
+            rec2020.to_array(),
+        );
+    }
+
+    #[test]
+    fn gamut_mapping() {
+        let green = Color::from(Rec2020::new_opaque(0.0, 1.0, 0.0));
+
+        assert_eq!(green.r, 0);
+        assert!(green.g > 200 && green.b < 128, "{green:?}");
+    }
+}
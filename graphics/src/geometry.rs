@@ -1,32 +1,444 @@
 //! Simple geometry types usable on the GPU.
+//!
+//! ## Units
+//!
+//! [`Point2`], [`Size2`] and [`Rect`] carry a unit marker, either
+//! [`Logical`] (the default) or [`Physical`]. Mixing units is a type error;
+//! use a [`ScaleFactor`] to convert between them.
+//!
+//! ## Shapes
+//!
+//! [`Rect`], [`RoundedRect`], [`Circle`], [`Ellipse`], [`Line`] and
+//! [`Triangle`] implement [`Shape`] for hit testing and measurement. They can
+//! be moved around with a [`Transform`], which turns rectangles into
+//! [`Polygon`]s.
+//!
+//! ## Paths
+//!
+//! Arbitrary outlines made of lines and Bézier curves are described by a
+//! [`Path`], created using a [`PathBuilder`] or parsed from SVG path data.
+//! Paths can be flattened into [`Polygon`]s, whose inside is decided by a
+//! [`FillRule`], and combined with a [`BooleanOp`].
 
+use std::marker::PhantomData;
+use std::slice;
+
+use bytemuck::{Pod, Zeroable};
+
+use crate::math::Vec2;
+
+/// Implementations for [`Anchor`].
+mod anchor;
+/// Implementations for [`QuadBezier`] and [`CubicBezier`].
+mod bezier;
+/// Boolean operations on [`Path`]s.
+pub(crate) mod boolean;
+/// Implementations for [`Circle`].
+mod circle;
+/// Implementations for [`Ellipse`].
+mod ellipse;
+/// Implementations for [`FillRule`].
+mod fill_rule;
+/// Implementations for [`Insets`].
+mod insets;
+/// Implementations for [`Line`].
+mod line;
+/// Implementations for [`Path`], [`PathBuilder`], [`Segment`] and
+/// [`Segments`].
+mod path;
 /// Implementations for [`Point2`].
 mod point2;
+/// Implementations for [`Polygon`].
+mod polygon;
 /// Implementations for [`Rect`].
 mod rect;
+/// Implementations for [`RoundedRect`] and [`CornerRadii`].
+mod rounded_rect;
+/// Implementations for [`ScaleFactor`].
+mod scale_factor;
 /// Implementations for [`Size2`].
 mod size2;
+/// Parsing and serialization of [`Path`]s as SVG path data.
+mod svg;
+/// Decomposition of polygons into trapezoids.
+pub(crate) mod sweep;
+/// Implementations for [`Transform`].
+mod transform;
+/// Implementations for [`Triangle`].
+mod triangle;
+
+/// Unit marker for logical (device-independent) pixels.
+///
+/// This is the unit of layout and drawing. Multiply by the [`ScaleFactor`]
+/// of a target to get [`Physical`] pixels.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct Logical;
+
+/// Unit marker for physical (device) pixels.
+///
+/// This is the unit of windows, textures and scissor rectangles.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct Physical;
 
 /// A point in 2 dimensions.
+///
+/// ## Ops
+///
+/// Points are locations, not offsets: subtracting two points gives the
+/// [`Vec2`](crate::math::Vec2) between them, and a point can be moved by
+/// adding or subtracting a `Vec2`. This requires `T: Num`.
+///
+/// ```
+/// # use crossd_graphics::geometry::Point2;
+/// # use crossd_graphics::math::Vec2;
+/// #
+/// let a: Point2 = Point2::new(1.0, 2.0);
+/// let b: Point2 = Point2::new(4.0, 6.0);
+///
+/// assert_eq!(b - a, Vec2::new(3.0, 4.0));
+/// assert_eq!(a + (b - a), b);
+/// ```
+///
+/// ## Repr
+///
+/// The unit marker is zero-sized, so this type is equivalent to `[T; 2]`.
 #[repr(C)]
-#[derive(Debug, Default, Clone, Copy, PartialEq, PartialOrd)]
-pub struct Point2<T = f32> {
+pub struct Point2<T = f32, U = Logical> {
     pub x: T,
     pub y: T,
+    _unit: PhantomData<U>,
+}
+
+/// A point relative to the size of a rectangle, used to align one rectangle
+/// within another.
+///
+/// `(0, 0)` is the upper-left corner and `(1, 1)` is the lower-right corner,
+/// like percentages in CSS `object-position`.
+#[derive(Debug, Default, Clone, Copy, PartialEq, PartialOrd)]
+pub struct Anchor {
+    pub x: f32,
+    pub y: f32,
+}
+
+/// How a [`Size2`] is fit into a container by [`Size2::fit`].
+///
+/// These match the values of CSS `object-fit`.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum FitMode {
+    /// Scale to fit within the container, preserving aspect ratio.
+    #[default]
+    Contain,
+    /// Scale to cover the container, preserving aspect ratio.
+    Cover,
+    /// Stretch to the size of the container.
+    Fill,
+    /// Like [`FitMode::Contain`], but never scale up.
+    ScaleDown,
+    /// Keep the original size.
+    None,
+}
+
+/// Distances from each edge of a rectangle, as used for padding, margins and
+/// borders.
+///
+/// ```
+/// # use crossd_graphics::geometry::{Insets, Point2, Rect, Size2};
+/// #
+/// let rect: Rect = Rect::new(Point2::new(0.0, 0.0), Size2::new(100.0, 50.0));
+/// let padding = Insets::symmetric(5.0, 10.0);
+///
+/// assert_eq!(
+///     rect.inset_by(padding),
+///     Rect::new(Point2::new(10.0, 5.0), Size2::new(80.0, 40.0)),
+/// );
+/// assert_eq!(rect.inset_by(padding).outset_by(padding), rect);
+/// ```
+#[repr(C)]
+pub struct Insets<T = f32, U = Logical> {
+    pub top: T,
+    pub right: T,
+    pub bottom: T,
+    pub left: T,
+    _unit: PhantomData<U>,
 }
 
 /// A rectangle defined by it's location and size.
 #[repr(C)]
-#[derive(Debug, Default, Clone, Copy, PartialEq, PartialOrd)]
-pub struct Rect<T = f32> {
-    pub loc: Point2<T>,
-    pub size: Size2<T>,
+pub struct Rect<T = f32, U = Logical> {
+    pub loc: Point2<T, U>,
+    pub size: Size2<T, U>,
 }
 
 /// A size in 2 dimensions.
+///
+/// ## Repr
+///
+/// The unit marker is zero-sized, so this type is equivalent to `[T; 2]`.
 #[repr(C)]
-#[derive(Debug, Default, Clone, Copy, PartialEq, PartialOrd)]
-pub struct Size2<T = u32> {
+pub struct Size2<T = u32, U = Logical> {
     pub w: T,
     pub h: T,
+    _unit: PhantomData<U>,
+}
+
+/// The amount of [`Physical`] pixels per [`Logical`] pixel.
+///
+/// ```
+/// # use crossd_graphics::geometry::{ScaleFactor, Size2};
+/// #
+/// let scale = ScaleFactor::new(2.0);
+/// let size: Size2<f32> = Size2::new(400.0, 300.0);
+///
+/// assert_eq!(size.to_physical(scale).to_logical(scale), size);
+/// ```
+#[repr(transparent)]
+#[derive(Debug, Clone, Copy, PartialEq, PartialOrd)]
+pub struct ScaleFactor<T = f32>(pub T);
+
+/// A 2D affine transformation.
+///
+/// A point `p` is transformed to `p.x * x + p.y * y + translation`, which is
+/// equivalent to multiplying by the column-major matrix:
+///
+/// ```text
+/// [ x.x y.x translation.x
+///   x.y y.y translation.y
+///   0   0   1             ]
+/// ```
+#[repr(C)]
+#[derive(Debug, Clone, Copy, PartialEq, PartialOrd, Pod, Zeroable)]
+pub struct Transform {
+    /// Where the `x` axis is mapped to.
+    pub x: Vec2,
+    /// Where the `y` axis is mapped to.
+    pub y: Vec2,
+    /// The offset applied after the linear part of the transformation.
+    pub translation: Vec2,
+}
+
+/// Trait for closed 2D shapes.
+pub trait Shape {
+    /// Whether the point is inside the shape or on it's edge.
+    fn contains(&self, point: Point2) -> bool;
+
+    /// The smallest axis-aligned rectangle containing the shape.
+    fn bounds(&self) -> Rect;
+
+    /// The area of the shape.
+    fn area(&self) -> f32;
+
+    /// The length of the outline of the shape.
+    fn perimeter(&self) -> f32;
+}
+
+/// A circle defined by it's center and radius.
+#[derive(Debug, Default, Clone, Copy, PartialEq, PartialOrd)]
+pub struct Circle {
+    pub center: Point2,
+    pub radius: f32,
+}
+
+/// An ellipse defined by it's center, radii and rotation.
+#[derive(Debug, Default, Clone, Copy, PartialEq, PartialOrd)]
+pub struct Ellipse {
+    pub center: Point2,
+    /// The radius along the (rotated) `x` and `y` axes.
+    pub radii: Vec2,
+    /// The rotation of the `x` axis of the ellipse in radians.
+    pub rotation: f32,
+}
+
+/// A rectangle with rounded corners.
+#[derive(Debug, Default, Clone, Copy, PartialEq, PartialOrd)]
+pub struct RoundedRect {
+    pub rect: Rect,
+    pub radii: CornerRadii,
+}
+
+/// The radius of each corner of a [`RoundedRect`].
+#[derive(Debug, Default, Clone, Copy, PartialEq, PartialOrd)]
+pub struct CornerRadii {
+    pub top_left: f32,
+    pub top_right: f32,
+    pub bottom_right: f32,
+    pub bottom_left: f32,
+}
+
+/// A line segment between two points.
+#[derive(Debug, Default, Clone, Copy, PartialEq, PartialOrd)]
+pub struct Line {
+    pub start: Point2,
+    pub end: Point2,
+}
+
+/// A triangle defined by it's corners.
+#[derive(Debug, Default, Clone, Copy, PartialEq, PartialOrd)]
+pub struct Triangle {
+    pub a: Point2,
+    pub b: Point2,
+    pub c: Point2,
+}
+
+/// A quadratic Bézier curve.
+#[derive(Debug, Default, Clone, Copy, PartialEq, PartialOrd)]
+pub struct QuadBezier {
+    /// The start point.
+    pub p0: Point2,
+    /// The control point.
+    pub p1: Point2,
+    /// The end point.
+    pub p2: Point2,
+}
+
+/// A cubic Bézier curve.
+#[derive(Debug, Default, Clone, Copy, PartialEq, PartialOrd)]
+pub struct CubicBezier {
+    /// The start point.
+    pub p0: Point2,
+    /// The first control point.
+    pub p1: Point2,
+    /// The second control point.
+    pub p2: Point2,
+    /// The end point.
+    pub p3: Point2,
+}
+
+/// A vector path made of lines and curves.
+///
+/// A path is a list of subpaths, each started with [`PathEl::MoveTo`] and
+/// optionally ended with [`PathEl::Close`].
+///
+/// ```
+/// # use crossd_graphics::geometry::{Path, Point2, Rect, Size2};
+/// #
+/// let path = Path::builder()
+///     .move_to(Point2::new(0.0, 0.0))
+///     .line_to(Point2::new(10.0, 0.0))
+///     .quad_to(Point2::new(10.0, 10.0), Point2::new(0.0, 10.0))
+///     .close()
+///     .build();
+///
+/// assert_eq!(path.segments().count(), 3);
+/// assert_eq!(
+///     path.bounds(),
+///     Some(Rect::new(Point2::new(0.0, 0.0), Size2::new(10.0, 10.0))),
+/// );
+/// ```
+#[derive(Debug, Default, Clone, PartialEq)]
+pub struct Path {
+    elements: Vec<PathEl>,
+}
+
+/// An element of a [`Path`].
+#[derive(Debug, Clone, Copy, PartialEq, PartialOrd)]
+pub enum PathEl {
+    /// Start a new subpath at the point.
+    MoveTo(Point2),
+    /// A line to the point.
+    LineTo(Point2),
+    /// A quadratic Bézier curve using a control point to the end point.
+    QuadTo(Point2, Point2),
+    /// A cubic Bézier curve using two control points to the end point.
+    CubicTo(Point2, Point2, Point2),
+    /// Close the subpath with a line to it's start.
+    Close,
+}
+
+/// A drawn segment of a [`Path`].
+///
+/// See [`Path::segments`].
+#[derive(Debug, Clone, Copy, PartialEq, PartialOrd)]
+pub enum Segment {
+    Line(Line),
+    Quad(QuadBezier),
+    Cubic(CubicBezier),
+}
+
+/// An iterator over the [`Segment`]s of a [`Path`].
+///
+/// See [`Path::segments`].
+#[derive(Debug, Clone)]
+pub struct Segments<'a> {
+    elements: slice::Iter<'a, PathEl>,
+    /// The start of the current subpath.
+    start: Point2,
+    /// The end of the last element.
+    current: Point2,
+}
+
+/// Errors that can arise while parsing SVG path data into a [`Path`].
+///
+/// Each error carries the byte position in the input where it occurred.
+///
+/// ```
+/// # use crossd_graphics::geometry::{ParsePathError, Path};
+/// #
+/// assert_eq!("M 0 0 L 1".parse::<Path>(), Err(ParsePathError::ExpectedNumber(9)));
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, thiserror::Error)]
+pub enum ParsePathError {
+    #[error("expected a command at byte {0}")]
+    ExpectedCommand(usize),
+    #[error("unknown command {1:?} at byte {0}")]
+    UnknownCommand(usize, char),
+    #[error("path data must start with a move at byte {0}")]
+    ExpectedMove(usize),
+    #[error("expected a number at byte {0}")]
+    ExpectedNumber(usize),
+    #[error("expected an arc flag (`0` or `1`) at byte {0}")]
+    ExpectedFlag(usize),
+}
+
+/// A builder interface for creating a [`Path`].
+#[derive(Debug, Default, Clone)]
+pub struct PathBuilder {
+    elements: Vec<PathEl>,
+    /// The start of the current subpath.
+    start: Point2,
+    /// The end of the last element.
+    current: Point2,
+    /// Whether a subpath has been started and not closed.
+    open: bool,
+}
+
+/// How the inside of a shape is decided from the winding number of a point,
+/// the amount of times the outline goes around it.
+///
+/// These match the values of the SVG `fill-rule` property.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum FillRule {
+    /// Points with a non-zero winding number are inside.
+    #[default]
+    NonZero,
+    /// Points with an odd winding number are inside.
+    EvenOdd,
+}
+
+/// The direction an outline goes around it's inside, as seen on screen where
+/// `y` points down.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Winding {
+    Clockwise,
+    CounterClockwise,
+}
+
+/// How the insides of two shapes are combined.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum BooleanOp {
+    /// Inside either shape.
+    Union,
+    /// Inside both shapes.
+    Intersection,
+    /// Inside the first shape but not the second.
+    Difference,
+    /// Inside exactly one of the shapes.
+    Xor,
+}
+
+/// A closed polygon.
+///
+/// The last point is connected back to the first.
+#[derive(Debug, Default, Clone, PartialEq)]
+pub struct Polygon {
+    pub points: Vec<Point2>,
 }
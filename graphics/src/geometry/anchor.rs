@@ -0,0 +1,29 @@
+use super::Anchor;
+
+impl Anchor {
+    /// The lower edge, centered horizontally.
+    pub const BOTTOM: Self = Self::new(0.5, 1.0);
+    /// The lower-left corner.
+    pub const BOTTOM_LEFT: Self = Self::new(0.0, 1.0);
+    /// The lower-right corner.
+    pub const BOTTOM_RIGHT: Self = Self::new(1.0, 1.0);
+    /// The center.
+    pub const CENTER: Self = Self::new(0.5, 0.5);
+    /// The left edge, centered vertically.
+    pub const LEFT: Self = Self::new(0.0, 0.5);
+    /// The right edge, centered vertically.
+    pub const RIGHT: Self = Self::new(1.0, 0.5);
+    /// The upper edge, centered horizontally.
+    pub const TOP: Self = Self::new(0.5, 0.0);
+    /// The upper-left corner.
+    pub const TOP_LEFT: Self = Self::new(0.0, 0.0);
+    /// The upper-right corner.
+    pub const TOP_RIGHT: Self = Self::new(1.0, 0.0);
+
+    /// A new anchor from fractions of the width and height.
+    #[inline]
+    #[must_use]
+    pub const fn new(x: f32, y: f32) -> Self {
+        Self { x, y }
+    }
+}
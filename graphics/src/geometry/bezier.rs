@@ -0,0 +1,269 @@
+use super::{CubicBezier, Point2, QuadBezier, Rect, Transform};
+
+impl QuadBezier {
+    /// A new curve from it's start, control and end points.
+    #[inline]
+    #[must_use]
+    pub const fn new(p0: Point2, p1: Point2, p2: Point2) -> Self {
+        Self { p0, p1, p2 }
+    }
+
+    /// The point at `t` along the curve, where `0` is the start and `1` is the
+    /// end.
+    #[inline]
+    #[must_use]
+    pub fn eval(self, t: f32) -> Point2 {
+        let mt = 1.0 - t;
+        let Self { p0, p1, p2 } = self;
+
+        Point2::from_vec2(
+            p0.to_vec2().scale(mt * mt)
+                + p1.to_vec2().scale(2.0 * mt * t)
+                + p2.to_vec2().scale(t * t),
+        )
+    }
+
+    /// The exact cubic equivalent of this curve.
+    #[inline]
+    #[must_use]
+    pub fn to_cubic(self) -> CubicBezier {
+        let Self { p0, p1, p2 } = self;
+
+        CubicBezier::new(
+            p0,
+            p0 + (p1 - p0).scale(2.0 / 3.0),
+            p2 + (p1 - p2).scale(2.0 / 3.0),
+            p2,
+        )
+    }
+
+    /// The smallest axis-aligned rectangle containing the curve.
+    #[must_use]
+    pub fn bounds(self) -> Rect {
+        let Self { p0, p1, p2 } = self;
+        // the derivative is linear, so there is at most one extremum per axis
+        let num = p0 - p1;
+        let denom = num + (p2 - p1);
+
+        [(num.x, denom.x), (num.y, denom.y)]
+            .into_iter()
+            .filter(|(_, denom)| *denom != 0.0)
+            .map(|(num, denom)| num / denom)
+            .filter(|t| *t > 0.0 && *t < 1.0)
+            .fold(Rect::from_points(p0, p2), |rect, t| {
+                rect.union(point_rect(self.eval(t)))
+            })
+    }
+
+    /// Approximate the curve with lines, returning the points after the start.
+    ///
+    /// No point on the lines is further than `tolerance` from the curve.
+    #[must_use]
+    pub fn flatten(self, tolerance: f32) -> Vec<Point2> {
+        let Self { p0, p1, p2 } = self;
+        // the second derivative is constant, `2 * dd`, and a line over a step
+        // of `h` deviates by at most `|2 * dd| * h^2 / 8`
+        let dd = (p0 - p1) + (p2 - p1);
+        let count = subdivisions(dd.x.hypot(dd.y) / (4.0 * tolerance));
+
+        (1..=count).map(|i| self.eval(i as f32 / count as f32)).collect()
+    }
+
+    /// Apply a transformation to the curve.
+    #[inline]
+    #[must_use]
+    pub fn transform(self, trans: Transform) -> Self {
+        let Self { p0, p1, p2 } = self;
+
+        Self::new(
+            trans.transform_point(p0),
+            trans.transform_point(p1),
+            trans.transform_point(p2),
+        )
+    }
+}
+
+impl CubicBezier {
+    /// A new curve from it's start, control and end points.
+    #[inline]
+    #[must_use]
+    pub const fn new(p0: Point2, p1: Point2, p2: Point2, p3: Point2) -> Self {
+        Self { p0, p1, p2, p3 }
+    }
+
+    /// The point at `t` along the curve, where `0` is the start and `1` is the
+    /// end.
+    #[inline]
+    #[must_use]
+    pub fn eval(self, t: f32) -> Point2 {
+        let mt = 1.0 - t;
+        let Self { p0, p1, p2, p3 } = self;
+
+        Point2::from_vec2(
+            p0.to_vec2().scale(mt * mt * mt)
+                + p1.to_vec2().scale(3.0 * mt * mt * t)
+                + p2.to_vec2().scale(3.0 * mt * t * t)
+                + p3.to_vec2().scale(t * t * t),
+        )
+    }
+
+    /// The smallest axis-aligned rectangle containing the curve.
+    #[must_use]
+    pub fn bounds(self) -> Rect {
+        let Self { p0, p1, p2, p3 } = self;
+        // coefficients of the derivative (divided by 3) as `a t^2 + 2 b t + c`
+        let a = (p3 - p0) + (p1 - p2).scale(3.0);
+        let b = (p0 - p1) + (p2 - p1);
+        let c = p1 - p0;
+
+        [(a.x, b.x, c.x), (a.y, b.y, c.y)]
+            .into_iter()
+            .flat_map(|(a, b, c)| quadratic_roots(a, b, c))
+            .flatten()
+            .filter(|t| *t > 0.0 && *t < 1.0)
+            .fold(Rect::from_points(p0, p3), |rect, t| {
+                rect.union(point_rect(self.eval(t)))
+            })
+    }
+
+    /// Approximate the curve with lines, returning the points after the start.
+    ///
+    /// No point on the lines is further than `tolerance` from the curve.
+    #[must_use]
+    pub fn flatten(self, tolerance: f32) -> Vec<Point2> {
+        let Self { p0, p1, p2, p3 } = self;
+        // the second derivative is at most `6 * max(|dd1|, |dd2|)`, and a line
+        // over a step of `h` deviates by at most that times `h^2 / 8`
+        let dd1 = (p0 - p1) + (p2 - p1);
+        let dd2 = (p1 - p2) + (p3 - p2);
+        let dd = dd1.x.hypot(dd1.y).max(dd2.x.hypot(dd2.y));
+        let count = subdivisions(3.0 * dd / (4.0 * tolerance));
+
+        (1..=count).map(|i| self.eval(i as f32 / count as f32)).collect()
+    }
+
+    /// Apply a transformation to the curve.
+    #[inline]
+    #[must_use]
+    pub fn transform(self, trans: Transform) -> Self {
+        let Self { p0, p1, p2, p3 } = self;
+
+        Self::new(
+            trans.transform_point(p0),
+            trans.transform_point(p1),
+            trans.transform_point(p2),
+            trans.transform_point(p3),
+        )
+    }
+}
+
+/// The most lines a curve is flattened into.
+const MAX_SUBDIVISIONS: usize = 1024;
+
+/// The amount of lines needed to flatten a curve, where `squared` is the
+/// square of it.
+fn subdivisions(squared: f32) -> usize {
+    // `NaN` (from a tolerance of 0) becomes 0
+    (squared.sqrt().ceil() as usize).clamp(1, MAX_SUBDIVISIONS)
+}
+
+/// A rectangle containing only `point`.
+fn point_rect(point: Point2) -> Rect {
+    Rect::from_points(point, point)
+}
+
+/// The real roots of `a t^2 + 2 b t + c`.
+fn quadratic_roots(a: f32, b: f32, c: f32) -> [Option<f32>; 2] {
+    if a.abs() <= f32::EPSILON {
+        // the equation is linear
+        return [(b != 0.0).then(|| -c / (2.0 * b)), None];
+    }
+
+    let disc = b * b - a * c;
+
+    if disc < 0.0 {
+        return [None, None];
+    }
+
+    let sqrt = disc.sqrt();
+
+    [Some((-b + sqrt) / a), Some((-b - sqrt) / a)]
+}
+
+#[cfg(test)]
+mod test {
+    use super::super::{CubicBezier, Line, Point2, QuadBezier, Rect, Size2};
+
+    fn p(x: f32, y: f32) -> Point2 {
+        Point2::new(x, y)
+    }
+
+    /// Assert that the curve at `eval` stays within `tolerance` of the lines
+    /// through `points`, flattened with equal steps of `t`.
+    #[track_caller]
+    fn assert_within(eval: impl Fn(f32) -> Point2, points: &[Point2], tolerance: f32) {
+        let count = points.len() as f32;
+        let mut start = eval(0.0);
+
+        for (i, &end) in points.iter().enumerate() {
+            let line = Line::new(start, end);
+
+            for j in 0..=16 {
+                let t = (i as f32 + j as f32 / 16.0) / count;
+                let distance = line.distance_to(eval(t));
+
+                assert!(distance <= tolerance, "{distance} at {t}");
+            }
+
+            start = end;
+        }
+
+        assert_eq!(points.last(), Some(&eval(1.0)));
+    }
+
+    #[test]
+    fn bounds() {
+        // the control points are further out than the curve reaches
+        let quad = QuadBezier::new(p(0.0, 0.0), p(5.0, 10.0), p(10.0, 0.0));
+
+        assert_eq!(quad.bounds(), Rect::new(p(0.0, 0.0), Size2::new(10.0, 5.0)));
+
+        let cubic =
+            CubicBezier::new(p(0.0, 0.0), p(0.0, 10.0), p(10.0, 10.0), p(10.0, 0.0));
+
+        assert_eq!(cubic.bounds(), Rect::new(p(0.0, 0.0), Size2::new(10.0, 7.5)));
+
+        // an s-curve reaching past both ends
+        let cubic =
+            CubicBezier::new(p(0.0, 0.0), p(10.0, 0.0), p(-10.0, 10.0), p(0.0, 10.0));
+        let bounds = cubic.bounds();
+
+        assert!(bounds.min().x < 0.0 && bounds.max().x > 0.0);
+        assert!(bounds.min().x > -10.0 && bounds.max().x < 10.0);
+        assert_eq!((bounds.min().y, bounds.max().y), (0.0, 10.0));
+
+        for i in 0..=100 {
+            let Point2 { x, y, .. } = cubic.eval(i as f32 / 100.0);
+
+            assert!(x >= bounds.min().x - 1e-4 && x <= bounds.max().x + 1e-4);
+            assert!(y >= bounds.min().y - 1e-4 && y <= bounds.max().y + 1e-4);
+        }
+    }
+
+    #[test]
+    fn flatten() {
+        let quad = QuadBezier::new(p(0.0, 0.0), p(50.0, 100.0), p(100.0, 0.0));
+        let cubic =
+            CubicBezier::new(p(0.0, 0.0), p(0.0, 100.0), p(100.0, -100.0), p(100.0, 0.0));
+
+        for tolerance in [1.0, 0.1, 0.01] {
+            assert_within(|t| quad.eval(t), &quad.flatten(tolerance), tolerance);
+            assert_within(|t| cubic.eval(t), &cubic.flatten(tolerance), tolerance);
+        }
+
+        // a straight curve needs a single line
+        let line = QuadBezier::new(p(0.0, 0.0), p(5.0, 5.0), p(10.0, 10.0));
+
+        assert_eq!(line.flatten(0.1), [p(10.0, 10.0)]);
+    }
+}
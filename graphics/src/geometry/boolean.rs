@@ -0,0 +1,429 @@
+use std::collections::HashMap;
+
+use super::sweep::{Sweep, Trapezoid};
+use super::{BooleanOp, FillRule, Path, PathBuilder, Point2, Polygon};
+
+impl BooleanOp {
+    /// Whether a point inside the first shape (`a`) and/or the second shape
+    /// (`b`) is inside the result.
+    #[inline]
+    #[must_use]
+    pub const fn is_inside(self, a: bool, b: bool) -> bool {
+        match self {
+            Self::Union => a || b,
+            Self::Intersection => a && b,
+            Self::Difference => a && !b,
+            Self::Xor => a != b,
+        }
+    }
+}
+
+impl Path {
+    /// Combine the insides of two paths, each decided by `fill_rule`.
+    ///
+    /// Curves are flattened within `tolerance` and open subpaths are closed,
+    /// so the result only has straight lines. It's outlines go clockwise on
+    /// screen around the inside and counter-clockwise around holes, so it
+    /// can be filled with either fill rule.
+    ///
+    /// ```
+    /// # use crossd_graphics::geometry::{BooleanOp, FillRule, Path};
+    /// #
+    /// let a = Path::from_svg("M0 0H20V20H0Z").unwrap();
+    /// let b = Path::from_svg("M10 10H30V30H10Z").unwrap();
+    ///
+    /// let union = a.boolean(&b, BooleanOp::Union, FillRule::NonZero, 0.1);
+    /// assert_eq!(union.to_svg(), "M0 0 20 0 20 10 30 10 30 30 10 30 10 20 0 20Z");
+    ///
+    /// let intersection = a.boolean(&b, BooleanOp::Intersection, FillRule::NonZero, 0.1);
+    /// assert_eq!(intersection.to_svg(), "M10 10 20 10 20 20 10 20Z");
+    /// ```
+    #[must_use]
+    pub fn boolean(
+        &self,
+        other: &Self,
+        op: BooleanOp,
+        fill_rule: FillRule,
+        tolerance: f32,
+    ) -> Self {
+        combine(&self.flatten(tolerance), &other.flatten(tolerance), op, fill_rule)
+    }
+}
+
+/// Combine the insides of two sets of polygons, each decided by `fill_rule`.
+pub(crate) fn combine(
+    a: &[Polygon],
+    b: &[Polygon],
+    op: BooleanOp,
+    fill_rule: FillRule,
+) -> Path {
+    let mut sweep = Sweep::default();
+
+    for (operand, polygons) in [a, b].into_iter().enumerate() {
+        for polygon in polygons {
+            sweep.add_polygon(&polygon.points, operand);
+        }
+    }
+
+    let mut outline = Outline::default();
+
+    sweep.trapezoids(
+        |winding| {
+            op.is_inside(fill_rule.is_inside(winding[0]), fill_rule.is_inside(winding[1]))
+        },
+        |trapezoid| outline.add(trapezoid),
+    );
+
+    outline.build()
+}
+
+/// A point by the bits of it's coordinates, so that it can be hashed.
+type Key = (u32, u32);
+
+/// The outline of a set of clockwise trapezoids, where the sides they share
+/// cancel out.
+#[derive(Default)]
+struct Outline {
+    /// The amount of each directed non-horizontal edge.
+    sides: HashMap<(Key, Key), usize>,
+    /// Changes in the amount of rightwards edges along each horizontal line,
+    /// by the bits of it's `y`.
+    horizontals: HashMap<u32, Vec<(f32, i32)>>,
+}
+
+impl Outline {
+    /// Add the edges of a trapezoid.
+    fn add(&mut self, trapezoid: Trapezoid) {
+        let Trapezoid { top, bottom, left, right } = trapezoid;
+
+        // the top goes right and the bottom left, which works out even if
+        // rounding swapped the ends of one
+        self.horizontal(top, left[0], right[0]);
+        self.horizontal(bottom, right[1], left[1]);
+        self.side(Point2::new(right[0], top), Point2::new(right[1], bottom));
+        self.side(Point2::new(left[1], bottom), Point2::new(left[0], top));
+    }
+
+    /// Add a horizontal edge.
+    fn horizontal(&mut self, y: f32, from: f32, to: f32) {
+        let changes = self.horizontals.entry(key_of(y)).or_default();
+
+        changes.extend([(from, 1), (to, -1)]);
+    }
+
+    /// Add a non-horizontal edge, cancelling it out with an opposite one.
+    fn side(&mut self, from: Point2, to: Point2) {
+        let (from, to) = (key(from), key(to));
+
+        match self.sides.get_mut(&(to, from)) {
+            Some(count) if *count > 0 => *count -= 1,
+            _ => *self.sides.entry((from, to)).or_default() += 1,
+        }
+    }
+
+    /// Connect the remaining edges into closed outlines.
+    fn build(mut self) -> Path {
+        let mut next: HashMap<Key, Vec<Key>> = HashMap::new();
+
+        for ((from, to), count) in self.sides.drain() {
+            next.entry(from).or_default().extend((0..count).map(|_| to));
+        }
+
+        for (y, mut changes) in self.horizontals.drain() {
+            changes.sort_by(|a, b| a.0.total_cmp(&b.0));
+
+            let mut count = 0;
+
+            for pair in changes.windows(2) {
+                let ((x0, change), (x1, _)) = (pair[0], pair[1]);
+                count += change;
+
+                if x0 == x1 || count == 0 {
+                    continue;
+                }
+
+                let (left, right) = ((key_of(x0), y), (key_of(x1), y));
+                let (from, to) = if count > 0 { (left, right) } else { (right, left) };
+
+                next.entry(from).or_default().extend((0..count.abs()).map(|_| to));
+            }
+        }
+
+        // start from the top left, so that the result doesn't depend on the
+        // order of the hash map
+        let mut starts: Vec<Key> = next.keys().copied().collect();
+        starts.sort_by(|a, b| {
+            let (a, b) = (point(*a), point(*b));
+
+            a.y.total_cmp(&b.y).then(a.x.total_cmp(&b.x))
+        });
+
+        for to in next.values_mut() {
+            to.sort_unstable();
+        }
+
+        let mut builder = PathBuilder::new();
+        let mut walk = Vec::new();
+        // the position of each point in the walk
+        let mut visited = HashMap::new();
+
+        for start in starts {
+            while let Some(mut current) = next.get_mut(&start).and_then(Vec::pop) {
+                walk.clear();
+                walk.push(start);
+                visited.clear();
+                visited.insert(start, 0);
+
+                loop {
+                    // cut off a loop as soon as it closes, so that outlines
+                    // touching at a point are kept apart
+                    if let Some(&i) = visited.get(&current) {
+                        let outline: Vec<Key> = walk.drain(i..).collect();
+
+                        for key in &outline {
+                            visited.remove(key);
+                        }
+
+                        let points = outline.into_iter().map(point).collect();
+                        builder = add_outline(builder, simplify(points));
+
+                        if walk.is_empty() {
+                            break;
+                        }
+                    }
+
+                    visited.insert(current, walk.len());
+                    walk.push(current);
+
+                    match next.get_mut(&current).and_then(Vec::pop) {
+                        Some(to) => current = to,
+                        None => break,
+                    }
+                }
+            }
+        }
+
+        builder.build()
+    }
+}
+
+/// Add a closed outline to a path, unless it has no area.
+fn add_outline(mut builder: PathBuilder, points: Vec<Point2>) -> PathBuilder {
+    if points.len() >= 3 {
+        builder = builder.move_to(points[0]);
+
+        for p in &points[1..] {
+            builder = builder.line_to(*p);
+        }
+
+        builder = builder.close();
+    }
+
+    builder
+}
+
+/// Remove points that are in line with their neighbours from a closed
+/// outline, along with points that are only apart due to rounding.
+fn simplify(mut points: Vec<Point2>) -> Vec<Point2> {
+    // where edges cross, the crossing found on each edge can differ slightly
+    let same = |a: Point2, b: Point2| {
+        (b - a).length() <= 1e-5 * (1.0 + a.x.abs().max(a.y.abs()))
+    };
+    let in_line = |a: Point2, b: Point2, c: Point2| {
+        let (u, v) = (b - a, c - b);
+
+        u.cross(v).abs() <= 1e-5 * u.length() * v.length()
+    };
+
+    points.dedup_by(|b, a| same(*a, *b));
+
+    while points.len() > 1 && same(points[points.len() - 1], points[0]) {
+        points.pop();
+    }
+
+    let mut out: Vec<Point2> = Vec::with_capacity(points.len());
+
+    for p in points {
+        while out.len() >= 2 && in_line(out[out.len() - 2], out[out.len() - 1], p) {
+            out.pop();
+        }
+
+        out.push(p);
+    }
+
+    // the points around where the outline closes
+    while out.len() >= 3 {
+        let len = out.len();
+
+        if in_line(out[len - 2], out[len - 1], out[0]) {
+            out.pop();
+        } else if in_line(out[len - 1], out[0], out[1]) {
+            out.remove(0);
+        } else {
+            break;
+        }
+    }
+
+    out
+}
+
+/// The key of a point.
+fn key(p: Point2) -> Key {
+    (key_of(p.x), key_of(p.y))
+}
+
+/// The bits of a coordinate, the same for `-0.0` and `0.0`.
+fn key_of(v: f32) -> u32 {
+    (v + 0.0).to_bits()
+}
+
+/// The point of a key.
+fn point((x, y): Key) -> Point2 {
+    Point2::new(f32::from_bits(x), f32::from_bits(y))
+}
+
+#[cfg(test)]
+mod test {
+    use super::super::{BooleanOp, Circle, FillRule, Path, Point2};
+
+    /// The area inside a path, negative inside counter-clockwise outlines.
+    fn area(path: &Path) -> f32 {
+        path.flatten(0.01)
+            .iter()
+            .flat_map(|polygon| polygon.edges())
+            .map(|line| line.start.to_vec2().cross(line.end.to_vec2()) / 2.0)
+            .sum()
+    }
+
+    fn svg(data: &str) -> Path {
+        Path::from_svg(data).unwrap()
+    }
+
+    #[test]
+    fn overlapping() {
+        let a = svg("M0 0H20V20H0Z");
+        let b = svg("M10 10H30V30H10Z");
+        let op = |op| a.boolean(&b, op, FillRule::NonZero, 0.1);
+
+        assert_eq!(
+            op(BooleanOp::Union).to_svg(),
+            "M0 0 20 0 20 10 30 10 30 30 10 30 10 20 0 20Z"
+        );
+        assert_eq!(op(BooleanOp::Intersection).to_svg(), "M10 10 20 10 20 20 10 20Z");
+        assert_eq!(
+            op(BooleanOp::Difference).to_svg(),
+            "M0 0 20 0 20 10 10 10 10 20 0 20Z"
+        );
+        assert_eq!(area(&op(BooleanOp::Xor)), 600.0);
+    }
+
+    #[test]
+    fn coincident_edges() {
+        let a = svg("M0 0H20V20H0Z");
+        // the same square, wound the other way
+        let reversed = svg("M0 0V20H20V0Z");
+        // a square sharing the right edge of `a`
+        let beside = svg("M20 0H40V20H20Z");
+        // a square sharing part of the bottom edge of `a`
+        let below = svg("M10 20H30V40H10Z");
+
+        for other in [&a, &reversed] {
+            let op = |op| a.boolean(other, op, FillRule::NonZero, 0.1);
+
+            assert_eq!(op(BooleanOp::Union), a);
+            assert_eq!(op(BooleanOp::Intersection), a);
+            assert!(op(BooleanOp::Difference).is_empty());
+            assert!(op(BooleanOp::Xor).is_empty());
+        }
+
+        let union = a.boolean(&beside, BooleanOp::Union, FillRule::NonZero, 0.1);
+        assert_eq!(union.to_svg(), "M0 0 40 0 40 20 0 20Z");
+        assert!(a
+            .boolean(&beside, BooleanOp::Intersection, FillRule::NonZero, 0.1)
+            .is_empty());
+        assert_eq!(a.boolean(&beside, BooleanOp::Difference, FillRule::NonZero, 0.1), a);
+
+        let union = a.boolean(&below, BooleanOp::Union, FillRule::NonZero, 0.1);
+        assert_eq!(union.to_svg(), "M0 0 20 0 20 20 30 20 30 40 10 40 10 20 0 20Z");
+    }
+
+    #[test]
+    fn self_intersections() {
+        let empty = Path::new();
+        let bowtie = svg("M0 0L20 20H0L20 0Z");
+        let pentagram = svg("M50 0 79 90 2 35H98L21 90Z");
+
+        let union = bowtie.boolean(&empty, BooleanOp::Union, FillRule::NonZero, 0.1);
+        assert_eq!(area(&union), 200.0);
+        assert_eq!(union.flatten(0.1).len(), 2);
+
+        let non_zero =
+            pentagram.boolean(&empty, BooleanOp::Union, FillRule::NonZero, 0.1);
+        let even_odd =
+            pentagram.boolean(&empty, BooleanOp::Union, FillRule::EvenOdd, 0.1);
+        let center =
+            non_zero.boolean(&even_odd, BooleanOp::Difference, FillRule::NonZero, 0.1);
+
+        // the center of the pentagram is only inside with the non-zero rule
+        assert!((area(&non_zero) - area(&even_odd) - area(&center)).abs() < 0.1);
+        assert!(area(&center) > 0.0);
+        assert_eq!(center.flatten(0.1)[0].points.len(), 5);
+    }
+
+    #[test]
+    fn touching_corners() {
+        let a = svg("M0 0H10V10H0Z");
+        let b = svg("M10 10H20V20H10Z");
+        // both squares as one outline, passing through the corner twice
+        let figure_eight = svg("M0 0H10V10H20V20H10V10H0Z");
+
+        // outlines meeting at a point are separate outlines
+        let union = a.boolean(&b, BooleanOp::Union, FillRule::NonZero, 0.1);
+        assert_eq!(union.flatten(0.1).len(), 2);
+        assert_eq!(area(&union), 200.0);
+        assert!(union.flatten(0.1).iter().all(|polygon| polygon.points.len() == 4));
+
+        let xor =
+            a.boolean(&svg("M0 0H20V20H0Z"), BooleanOp::Xor, FillRule::NonZero, 0.1);
+        assert_eq!(area(&xor), 300.0);
+        assert!(xor.flatten(0.1).iter().all(|polygon| polygon.points.len() >= 4));
+
+        let union =
+            figure_eight.boolean(&Path::new(), BooleanOp::Union, FillRule::NonZero, 0.1);
+        assert_eq!(union.flatten(0.1).len(), 2);
+        assert_eq!(area(&union), 200.0);
+    }
+
+    #[test]
+    fn winding() {
+        let outer = svg("M0 0V30H30V0Z");
+        let inner = svg("M10 10H20V20H10Z");
+        let ring = outer.boolean(&inner, BooleanOp::Difference, FillRule::NonZero, 0.1);
+
+        // the outline goes clockwise and the hole counter-clockwise
+        assert_eq!(area(&ring), 800.0);
+        assert_eq!(
+            ring.boolean(&Path::new(), BooleanOp::Union, FillRule::NonZero, 0.1),
+            ring
+        );
+        assert_eq!(
+            ring.boolean(&Path::new(), BooleanOp::Union, FillRule::EvenOdd, 0.1),
+            ring
+        );
+    }
+
+    #[test]
+    fn curves() {
+        let circle =
+            |x| Path::builder().circle(Circle::new(Point2::new(x, 0.0), 10.0)).build();
+        let (a, b) = (circle(0.0), circle(10.0));
+        let lens = a.boolean(&b, BooleanOp::Intersection, FillRule::NonZero, 0.01);
+
+        // two circles with their centers a radius apart
+        let expected = (2.0 * std::f32::consts::PI / 3.0 - 3f32.sqrt() / 2.0) * 100.0;
+        assert!((area(&lens) - expected).abs() < 0.5);
+
+        let union = a.boolean(&b, BooleanOp::Union, FillRule::NonZero, 0.01);
+        assert!((area(&union) - area(&a) - area(&b) + area(&lens)).abs() < 0.5);
+    }
+}
@@ -0,0 +1,121 @@
+use std::f32::consts::PI;
+
+use super::{Circle, Ellipse, Point2, Rect, Shape, Size2, Transform};
+use crate::math::Vec2;
+
+impl Circle {
+    /// A new circle from it's center and radius.
+    #[inline]
+    #[must_use]
+    pub const fn new(center: Point2, radius: f32) -> Self {
+        Self { center, radius }
+    }
+
+    /// The diameter of the circle.
+    #[inline]
+    #[must_use]
+    pub fn diameter(self) -> f32 {
+        2.0 * self.radius
+    }
+
+    /// Move the circle by a vector.
+    #[inline]
+    #[must_use]
+    pub fn translate(self, by: Vec2) -> Self {
+        Self::new(self.center + by, self.radius)
+    }
+
+    /// Scale the circle around it's center.
+    #[inline]
+    #[must_use]
+    pub fn scale(self, by: f32) -> Self {
+        Self::new(self.center, self.radius * by.abs())
+    }
+
+    /// Apply a transformation to the circle.
+    ///
+    /// Circles can become ellipses when stretched, so this returns an
+    /// [`Ellipse`].
+    #[inline]
+    #[must_use]
+    pub fn transform(self, trans: Transform) -> Ellipse {
+        Ellipse::from(self).transform(trans)
+    }
+}
+
+impl Shape for Circle {
+    fn contains(&self, point: Point2) -> bool {
+        let d = point - self.center;
+
+        d.dot(d) <= self.radius * self.radius
+    }
+
+    fn bounds(&self) -> Rect {
+        let r = self.radius;
+
+        Rect::new(self.center - Vec2::splat(r), Size2::splat(2.0 * r))
+    }
+
+    fn area(&self) -> f32 {
+        PI * self.radius * self.radius
+    }
+
+    fn perimeter(&self) -> f32 {
+        2.0 * PI * self.radius
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use std::f32::consts::PI;
+
+    use super::super::{Circle, Point2, Rect, Shape, Size2, Transform};
+    use crate::math::Vec2;
+
+    #[test]
+    fn measure() {
+        let circle = Circle::new(Point2::new(3.0, 4.0), 2.0);
+
+        assert_eq!(circle.diameter(), 4.0);
+        assert_eq!(circle.area(), 4.0 * PI);
+        assert_eq!(circle.perimeter(), 4.0 * PI);
+        assert_eq!(
+            circle.bounds(),
+            Rect::new(Point2::new(1.0, 2.0), Size2::new(4.0, 4.0))
+        );
+    }
+
+    #[test]
+    fn contains() {
+        let circle = Circle::new(Point2::new(3.0, 4.0), 2.0);
+
+        assert!(circle.contains(Point2::new(3.0, 4.0)));
+        // on the edge
+        assert!(circle.contains(Point2::new(5.0, 4.0)));
+        assert!(circle.contains(Point2::new(3.0, 2.0)));
+        // inside the bounds, but not the circle
+        assert!(!circle.contains(Point2::new(4.5, 5.5)));
+        assert!(!circle.contains(Point2::new(5.01, 4.0)));
+    }
+
+    #[test]
+    fn transform() {
+        let circle = Circle::new(Point2::new(1.0, 1.0), 2.0);
+
+        assert_eq!(circle.translate(Vec2::new(1.0, -1.0)).center, Point2::new(2.0, 0.0));
+        assert_eq!(circle.scale(-2.0).radius, 4.0);
+
+        // uniform scales and rotations keep it a circle
+        let ellipse =
+            circle.transform(Transform::rotation(1.0).then(Transform::scale(3.0, 3.0)));
+        assert!((ellipse.radii - Vec2::splat(6.0)).length() < 1e-5);
+        assert!((ellipse.area() - circle.scale(3.0).area()).abs() < 1e-3);
+
+        let ellipse = circle.transform(Transform::scale(1.0, 0.5));
+        assert_eq!(ellipse.center, Point2::new(1.0, 0.5));
+        assert_eq!(
+            ellipse.bounds(),
+            Rect::new(Point2::new(-1.0, -0.5), Size2::new(4.0, 2.0))
+        );
+    }
+}
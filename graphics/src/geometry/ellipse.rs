@@ -0,0 +1,226 @@
+use std::f32::consts::PI;
+
+use super::{Circle, Ellipse, Point2, Rect, Shape, Size2, Transform};
+use crate::math::Vec2;
+
+impl Ellipse {
+    /// A new axis-aligned ellipse.
+    #[inline]
+    #[must_use]
+    pub const fn new(center: Point2, radii: Vec2) -> Self {
+        Self { center, radii, rotation: 0.0 }
+    }
+
+    /// A new ellipse rotated by `rotation` radians.
+    #[inline]
+    #[must_use]
+    pub const fn new_rotated(center: Point2, radii: Vec2, rotation: f32) -> Self {
+        Self { center, radii, rotation }
+    }
+
+    /// The largest axis-aligned ellipse that fits in the rectangle.
+    #[inline]
+    #[must_use]
+    pub fn from_rect(rect: Rect) -> Self {
+        Self::new(rect.center(), Vec2::new(rect.size.w, rect.size.h).scale(0.5))
+    }
+
+    /// Move the ellipse by a vector.
+    #[inline]
+    #[must_use]
+    pub fn translate(self, by: Vec2) -> Self {
+        Self { center: self.center + by, ..self }
+    }
+
+    /// Apply a transformation to the ellipse.
+    ///
+    /// ```
+    /// # use crossd_graphics::geometry::{Circle, Point2, Transform};
+    /// # use crossd_graphics::math::Vec2;
+    /// #
+    /// let circle = Circle::new(Point2::new(1.0, 0.0), 1.0);
+    /// let ellipse = circle.transform(Transform::scale(2.0, 1.0));
+    ///
+    /// assert_eq!(ellipse.center, Point2::new(2.0, 0.0));
+    /// assert_eq!(ellipse.radii, Vec2::new(2.0, 1.0));
+    /// ```
+    #[must_use]
+    pub fn transform(self, trans: Transform) -> Self {
+        // the ellipse is the unit circle transformed by `linear`, so it's radii and
+        // rotation are the singular values and left rotation of the SVD of `linear`
+        let linear = Transform::scale(self.radii.x, self.radii.y)
+            .then(Transform::rotation(self.rotation))
+            .then(trans);
+        let (m00, m01, m10, m11) = (linear.x.x, linear.y.x, linear.x.y, linear.y.y);
+
+        let e = (m00 + m11) / 2.0;
+        let f = (m00 - m11) / 2.0;
+        let g = (m10 + m01) / 2.0;
+        let h = (m10 - m01) / 2.0;
+
+        let q = e.hypot(h);
+        let r = f.hypot(g);
+
+        let a1 = g.atan2(f);
+        let a2 = h.atan2(e);
+
+        Self {
+            center: trans.transform_point(self.center),
+            radii: Vec2::new(q + r, (q - r).abs()),
+            rotation: (a2 + a1) / 2.0,
+        }
+    }
+
+    /// Convert a point to the space of the ellipse, where it is the unit
+    /// circle.
+    fn to_unit(self, point: Point2) -> Vec2 {
+        let (sin, cos) = self.rotation.sin_cos();
+        let d = point - self.center;
+
+        Vec2::new(
+            (d.x * cos + d.y * sin) / self.radii.x,
+            (d.y * cos - d.x * sin) / self.radii.y,
+        )
+    }
+}
+
+impl Shape for Ellipse {
+    fn contains(&self, point: Point2) -> bool {
+        let unit = self.to_unit(point);
+
+        unit.dot(unit) <= 1.0
+    }
+
+    fn bounds(&self) -> Rect {
+        let (sin, cos) = self.rotation.sin_cos();
+        let Vec2 { x: rx, y: ry } = self.radii;
+
+        let half = Vec2::new((rx * cos).hypot(ry * sin), (rx * sin).hypot(ry * cos));
+
+        Rect::new(self.center - half, Size2::new(2.0 * half.x, 2.0 * half.y))
+    }
+
+    fn area(&self) -> f32 {
+        PI * self.radii.x * self.radii.y
+    }
+
+    /// Ramanujan's second approximation of the perimeter.
+    fn perimeter(&self) -> f32 {
+        let Vec2 { x: a, y: b } = self.radii.map(f32::abs);
+
+        if a + b == 0.0 {
+            return 0.0;
+        }
+
+        let h = ((a - b) / (a + b)).powi(2);
+
+        PI * (a + b) * (1.0 + 3.0 * h / (10.0 + (4.0 - 3.0 * h).sqrt()))
+    }
+}
+
+impl From<Circle> for Ellipse {
+    fn from(circle: Circle) -> Self {
+        Self::new(circle.center, Vec2::splat(circle.radius))
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use std::f32::consts::{FRAC_PI_2, FRAC_PI_4, PI};
+
+    use super::super::{Ellipse, Point2, Rect, Shape, Size2, Transform};
+    use crate::math::Vec2;
+
+    fn assert_close(a: f32, b: f32) {
+        assert!((a - b).abs() < 1e-4, "{a} != {b}");
+    }
+
+    fn assert_rect_close(a: Rect, b: Rect) {
+        assert_close(a.loc.x, b.loc.x);
+        assert_close(a.loc.y, b.loc.y);
+        assert_close(a.size.w, b.size.w);
+        assert_close(a.size.h, b.size.h);
+    }
+
+    #[test]
+    fn measure() {
+        let ellipse = Ellipse::new(Point2::new(0.0, 0.0), Vec2::new(4.0, 2.0));
+
+        assert_eq!(ellipse.area(), 8.0 * PI);
+        // the exact perimeter is 19.376_896...
+        assert_close(ellipse.perimeter(), 19.376_896);
+
+        let circle = Ellipse::new(Point2::new(0.0, 0.0), Vec2::splat(3.0));
+        assert_close(circle.perimeter(), 6.0 * PI);
+
+        // the approximation is worst for flat ellipses, but still within 0.05%
+        let flat = Ellipse::new(Point2::new(0.0, 0.0), Vec2::new(5.0, 0.0));
+        assert!((flat.perimeter() - 20.0).abs() < 0.01, "{}", flat.perimeter());
+    }
+
+    #[test]
+    fn bounds() {
+        let ellipse = Ellipse::new(Point2::new(1.0, 1.0), Vec2::new(4.0, 2.0));
+        assert_eq!(
+            ellipse.bounds(),
+            Rect::new(Point2::new(-3.0, -1.0), Size2::new(8.0, 4.0))
+        );
+
+        // a quarter turn swaps the radii
+        let rotated =
+            Ellipse::new_rotated(Point2::new(1.0, 1.0), Vec2::new(4.0, 2.0), FRAC_PI_2);
+        assert_rect_close(
+            rotated.bounds(),
+            Rect::new(Point2::new(-1.0, -3.0), Size2::new(4.0, 8.0)),
+        );
+
+        // at 45 degrees each half-size is `sqrt((16 + 4) / 2)`
+        let half = 10.0_f32.sqrt();
+        let diagonal =
+            Ellipse::new_rotated(Point2::new(0.0, 0.0), Vec2::new(4.0, 2.0), FRAC_PI_4);
+        assert_rect_close(
+            diagonal.bounds(),
+            Rect::new(Point2::new(-half, -half), Size2::new(2.0 * half, 2.0 * half)),
+        );
+    }
+
+    #[test]
+    fn contains() {
+        let ellipse =
+            Ellipse::new_rotated(Point2::new(0.0, 0.0), Vec2::new(4.0, 1.0), FRAC_PI_2);
+
+        // the long axis is vertical
+        assert!(ellipse.contains(Point2::new(0.0, 3.9)));
+        assert!(ellipse.contains(Point2::new(0.9, 0.0)));
+        assert!(!ellipse.contains(Point2::new(3.9, 0.0)));
+        assert!(!ellipse.contains(Point2::new(0.0, 4.1)));
+    }
+
+    #[test]
+    fn transform() {
+        let ellipse = Ellipse::new(Point2::new(0.0, 0.0), Vec2::new(2.0, 1.0));
+
+        let rotated = ellipse.transform(Transform::rotation(FRAC_PI_4));
+        assert_close(rotated.radii.x, 2.0);
+        assert_close(rotated.radii.y, 1.0);
+        assert_close(rotated.rotation, FRAC_PI_4);
+
+        // skewing keeps the area, and the bounds are those of the skewed points
+        let skew = Transform::new(Vec2::new(1.0, 0.0), Vec2::new(1.0, 1.0), Vec2::ZERO);
+        let skewed = ellipse.transform(skew);
+        assert_close(skewed.area(), ellipse.area());
+
+        // the extremes of `(2 cos t + sin t, sin t)` are `sqrt(5)` and `1`
+        assert_rect_close(
+            skewed.bounds(),
+            Rect::new(
+                Point2::new(-5.0_f32.sqrt(), -1.0),
+                Size2::new(2.0 * 5.0_f32.sqrt(), 2.0),
+            ),
+        );
+
+        let on_edge: Point2 = skew.transform_point(Point2::new(2.0, 0.0));
+        assert!(skewed.contains(Point2::new(on_edge.x * 0.999, on_edge.y)));
+        assert!(!skewed.contains(Point2::new(on_edge.x * 1.001, on_edge.y)));
+    }
+}
@@ -0,0 +1,13 @@
+use super::FillRule;
+
+impl FillRule {
+    /// Whether a point with the winding number is inside.
+    #[inline]
+    #[must_use]
+    pub const fn is_inside(self, winding: i32) -> bool {
+        match self {
+            Self::NonZero => winding != 0,
+            Self::EvenOdd => winding % 2 != 0,
+        }
+    }
+}
@@ -0,0 +1,239 @@
+use std::fmt;
+use std::marker::PhantomData;
+use std::ops::{Add, AddAssign, Mul, Sub, SubAssign};
+
+use bytemuck::{Pod, Zeroable};
+
+use super::{Insets, Size2};
+use crate::math::{Num, Vec2, Zero};
+
+impl<T, U> Insets<T, U> {
+    /// New insets in CSS order (clockwise from the top).
+    #[inline]
+    #[must_use]
+    pub const fn new(top: T, right: T, bottom: T, left: T) -> Self {
+        Self { top, right, bottom, left, _unit: PhantomData }
+    }
+
+    /// Transform an `Insets<T>` -> `Insets<V>` with a callback called on each
+    /// edge.
+    #[inline]
+    #[must_use]
+    pub fn map<V>(self, f: impl FnMut(T) -> V) -> Insets<V, U> {
+        let mut f = f;
+
+        Insets::new(f(self.top), f(self.right), f(self.bottom), f(self.left))
+    }
+}
+
+impl<T: Copy, U> Insets<T, U> {
+    /// Insets with each edge set to `v`.
+    #[inline]
+    #[must_use]
+    pub const fn uniform(v: T) -> Self {
+        Self::new(v, v, v, v)
+    }
+
+    /// Insets with `vertical` for the top and bottom and `horizontal` for the
+    /// left and right.
+    #[inline]
+    #[must_use]
+    pub const fn symmetric(vertical: T, horizontal: T) -> Self {
+        Self::new(vertical, horizontal, vertical, horizontal)
+    }
+}
+
+impl<T: Zero, U> Insets<T, U> {
+    /// Insets with each edge set to `T::ZERO`.
+    pub const ZERO: Self = Self::uniform(T::ZERO);
+
+    /// Insets with `v` on the left and right and zero on the top and bottom.
+    #[inline]
+    #[must_use]
+    pub const fn horizontal(v: T) -> Self {
+        Self::symmetric(T::ZERO, v)
+    }
+
+    /// Insets with `v` on the top and bottom and zero on the left and right.
+    #[inline]
+    #[must_use]
+    pub const fn vertical(v: T) -> Self {
+        Self::symmetric(v, T::ZERO)
+    }
+}
+
+impl<T: Num, U> Insets<T, U> {
+    /// The sum of the left and right edges.
+    #[inline]
+    #[must_use]
+    pub fn horizontal_sum(self) -> T {
+        self.left + self.right
+    }
+
+    /// The sum of the top and bottom edges.
+    #[inline]
+    #[must_use]
+    pub fn vertical_sum(self) -> T {
+        self.top + self.bottom
+    }
+
+    /// The total space taken up by the insets along each axis.
+    #[inline]
+    #[must_use]
+    pub fn size(self) -> Size2<T, U> {
+        Size2::new(self.horizontal_sum(), self.vertical_sum())
+    }
+
+    /// The offset from the upper-left corner of a rectangle to the upper-left
+    /// corner of the inset rectangle.
+    #[inline]
+    #[must_use]
+    pub fn offset(self) -> Vec2<T> {
+        Vec2::new(self.left, self.top)
+    }
+}
+
+// ----------
+// operations
+// ----------
+
+impl<T: Num, U> Add for Insets<T, U> {
+    type Output = Self;
+
+    fn add(self, rhs: Self) -> Self::Output {
+        Self::new(
+            self.top + rhs.top,
+            self.right + rhs.right,
+            self.bottom + rhs.bottom,
+            self.left + rhs.left,
+        )
+    }
+}
+
+impl<T: Num, U> Sub for Insets<T, U> {
+    type Output = Self;
+
+    fn sub(self, rhs: Self) -> Self::Output {
+        Self::new(
+            self.top - rhs.top,
+            self.right - rhs.right,
+            self.bottom - rhs.bottom,
+            self.left - rhs.left,
+        )
+    }
+}
+
+/// Scale each edge.
+impl<T: Num, U> Mul<T> for Insets<T, U> {
+    type Output = Self;
+
+    fn mul(self, rhs: T) -> Self::Output {
+        self.map(|v| v * rhs)
+    }
+}
+
+impl<T: Num, U> AddAssign for Insets<T, U> {
+    fn add_assign(&mut self, rhs: Self) {
+        *self = *self + rhs
+    }
+}
+
+impl<T: Num, U> SubAssign for Insets<T, U> {
+    fn sub_assign(&mut self, rhs: Self) {
+        *self = *self - rhs
+    }
+}
+
+// ------
+// traits
+// ------
+
+// implemented by hand so that the unit marker doesn't need to implement them
+
+impl<T: fmt::Debug, U> fmt::Debug for Insets<T, U> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("Insets")
+            .field("top", &self.top)
+            .field("right", &self.right)
+            .field("bottom", &self.bottom)
+            .field("left", &self.left)
+            .finish()
+    }
+}
+
+impl<T: Default, U> Default for Insets<T, U> {
+    fn default() -> Self {
+        Self::new(T::default(), T::default(), T::default(), T::default())
+    }
+}
+
+impl<T: Clone, U> Clone for Insets<T, U> {
+    fn clone(&self) -> Self {
+        Self::new(
+            self.top.clone(),
+            self.right.clone(),
+            self.bottom.clone(),
+            self.left.clone(),
+        )
+    }
+}
+
+impl<T: Copy, U> Copy for Insets<T, U> {}
+
+impl<T: PartialEq, U> PartialEq for Insets<T, U> {
+    fn eq(&self, other: &Self) -> bool {
+        self.top == other.top
+            && self.right == other.right
+            && self.bottom == other.bottom
+            && self.left == other.left
+    }
+}
+
+unsafe impl<T: Pod, U: 'static> Pod for Insets<T, U> {}
+unsafe impl<T: Zeroable, U> Zeroable for Insets<T, U> {}
+
+#[cfg(test)]
+mod test {
+    use super::super::{Insets, Physical, Point2, Rect, Size2};
+
+    #[test]
+    fn asymmetric() {
+        let rect: Rect = Rect::new(Point2::new(10.0, 20.0), Size2::new(100.0, 50.0));
+        let insets = Insets::new(1.0, 2.0, 3.0, 4.0);
+
+        assert_eq!(insets.horizontal_sum(), 6.0);
+        assert_eq!(insets.vertical_sum(), 4.0);
+
+        let inset = rect.inset_by(insets);
+        assert_eq!(inset, Rect::new(Point2::new(14.0, 21.0), Size2::new(94.0, 46.0)));
+        assert_eq!(inset.max(), Point2::new(108.0, 67.0));
+        assert_eq!(inset.outset_by(insets), rect);
+
+        let outset = rect.outset_by(insets);
+        assert_eq!(outset, Rect::new(Point2::new(6.0, 19.0), Size2::new(106.0, 54.0)));
+        assert_eq!(outset.max(), Point2::new(112.0, 73.0));
+    }
+
+    #[test]
+    fn larger_than_rect() {
+        let rect: Rect = Rect::new(Point2::new(0.0, 0.0), Size2::new(10.0, 4.0));
+
+        let inset = rect.inset_by(Insets::new(2.0, 8.0, 3.0, 6.0));
+        assert_eq!(inset.size, Size2::new(0.0, 0.0));
+        assert!(inset.is_empty());
+
+        // only the axis that overflows is emptied
+        let inset = rect.inset_by(Insets::new(5.0, 3.0, 0.0, 3.0));
+        assert_eq!(inset.size, Size2::new(4.0, 0.0));
+
+        let rect: Rect<u32, Physical> = Rect::new(Point2::new(5, 5), Size2::new(10, 4));
+
+        let inset = rect.inset_by(Insets::uniform(6));
+        assert_eq!(inset.size, Size2::new(0, 0));
+        assert!(inset.is_empty());
+
+        let size: Size2<u32, Physical> = Size2::new(10, 4);
+        assert_eq!(size.shrink_by(Insets::new(0, 11, 0, 0)), Size2::new(0, 4));
+        assert_eq!(size.shrink_by(Insets::new(2, 0, 2, 0)), Size2::new(10, 0));
+    }
+}
@@ -0,0 +1,165 @@
+use super::{Line, Point2, Rect, Shape, Transform};
+use crate::math::Vec2;
+
+impl Line {
+    /// How far points may be from a line and still be on it for
+    /// [`Shape::contains`], in the units of the line.
+    ///
+    /// A thousandth of a pixel is too little to see, but enough for rounding
+    /// errors of points computed from the line's ends.
+    pub const TOLERANCE: f32 = 1e-3;
+
+    /// A new line segment between two points.
+    #[inline]
+    #[must_use]
+    pub const fn new(start: Point2, end: Point2) -> Self {
+        Self { start, end }
+    }
+
+    /// The vector from the start to the end of the line.
+    #[inline]
+    #[must_use]
+    pub fn delta(self) -> Vec2 {
+        self.end - self.start
+    }
+
+    /// The length of the line.
+    #[inline]
+    #[must_use]
+    pub fn length(self) -> f32 {
+        let Vec2 { x, y } = self.delta();
+
+        x.hypot(y)
+    }
+
+    /// The point at `t` along the line, where `0` is the start and `1` is the
+    /// end.
+    #[inline]
+    #[must_use]
+    pub fn at(self, t: f32) -> Point2 {
+        self.start + self.delta().scale(t)
+    }
+
+    /// The point on the line closest to `point`.
+    #[must_use]
+    pub fn nearest_point(self, point: Point2) -> Point2 {
+        let delta = self.delta();
+        let len2 = delta.dot(delta);
+
+        if len2 == 0.0 {
+            return self.start;
+        }
+
+        self.at(((point - self.start).dot(delta) / len2).clamp(0.0, 1.0))
+    }
+
+    /// The distance from `point` to the closest point on the line.
+    #[inline]
+    #[must_use]
+    pub fn distance_to(self, point: Point2) -> f32 {
+        let Vec2 { x, y } = point - self.nearest_point(point);
+
+        x.hypot(y)
+    }
+
+    /// Whether the point is no further than `tolerance` from the line.
+    ///
+    /// ```
+    /// # use crossd_graphics::geometry::{Line, Point2};
+    /// #
+    /// let line = Line::new(Point2::new(0.0, 0.0), Point2::new(10.0, 0.0));
+    ///
+    /// assert!(line.contains_within(Point2::new(5.0, 0.5), 1.0));
+    /// assert!(!line.contains_within(Point2::new(5.0, 1.5), 1.0));
+    /// ```
+    #[inline]
+    #[must_use]
+    pub fn contains_within(self, point: Point2, tolerance: f32) -> bool {
+        self.distance_to(point) <= tolerance
+    }
+
+    /// Move the line by a vector.
+    #[inline]
+    #[must_use]
+    pub fn translate(self, by: Vec2) -> Self {
+        Self::new(self.start + by, self.end + by)
+    }
+
+    /// Apply a transformation to the line.
+    #[inline]
+    #[must_use]
+    pub fn transform(self, trans: Transform) -> Self {
+        Self::new(trans.transform_point(self.start), trans.transform_point(self.end))
+    }
+}
+
+/// A line has no area, and it's perimeter is it's length.
+impl Shape for Line {
+    /// Whether the point is on the line, within [`Line::TOLERANCE`].
+    fn contains(&self, point: Point2) -> bool {
+        self.contains_within(point, Self::TOLERANCE)
+    }
+
+    fn bounds(&self) -> Rect {
+        Rect::from_points(self.start, self.end)
+    }
+
+    fn area(&self) -> f32 {
+        0.0
+    }
+
+    fn perimeter(&self) -> f32 {
+        self.length()
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::super::{Line, Point2, Rect, Shape, Size2, Transform};
+    use crate::math::Vec2;
+
+    #[test]
+    fn measure() {
+        let line = Line::new(Point2::new(1.0, 2.0), Point2::new(4.0, -2.0));
+
+        assert_eq!(line.length(), 5.0);
+        assert_eq!(line.perimeter(), 5.0);
+        assert_eq!(line.area(), 0.0);
+        assert_eq!(
+            line.bounds(),
+            Rect::new(Point2::new(1.0, -2.0), Size2::new(3.0, 4.0))
+        );
+    }
+
+    #[test]
+    fn contains() {
+        let line = Line::new(Point2::new(0.0, 0.0), Point2::new(3000.0, 1000.0));
+
+        // points computed along the line are on it, even far from the origin
+        for t in [0.0, 0.1, 0.25, 1.0 / 3.0, 0.5, 0.9, 1.0] {
+            assert!(line.contains(line.at(t)), "{t}");
+        }
+
+        assert!(!line.contains(Point2::new(1500.0, 501.0)));
+        // past the ends
+        assert!(!line.contains(Point2::new(-3.0, -1.0)));
+        assert!(!line.contains(Point2::new(3003.0, 1001.0)));
+
+        let short = Line::new(Point2::new(0.0, 0.0), Point2::new(0.0, 1.0));
+        assert!(short.contains_within(Point2::new(0.4, 0.5), 0.5));
+        assert!(!short.contains_within(Point2::new(0.4, 1.5), 0.5));
+        assert!(short.contains_within(Point2::new(0.3, 1.4), 0.5));
+    }
+
+    #[test]
+    fn transform() {
+        let line = Line::new(Point2::new(1.0, 0.0), Point2::new(2.0, 0.0));
+        let trans = Transform::rotation(std::f32::consts::FRAC_PI_2)
+            .then(Transform::translation(Vec2::new(0.0, 1.0)));
+        let moved = line.transform(trans);
+
+        assert!((moved.start - Point2::new(0.0, 2.0)).length() < 1e-6);
+        assert!((moved.end - Point2::new(0.0, 3.0)).length() < 1e-6);
+        assert!((moved.length() - line.length()).abs() < 1e-6);
+    }
+}
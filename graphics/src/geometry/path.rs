@@ -0,0 +1,878 @@
+use std::f32::consts::{FRAC_PI_2, PI, TAU};
+use std::{mem, slice};
+
+use super::{
+    Circle,
+    CornerRadii,
+    CubicBezier,
+    Ellipse,
+    FillRule,
+    Line,
+    Path,
+    PathBuilder,
+    PathEl,
+    Point2,
+    Polygon,
+    QuadBezier,
+    Rect,
+    RoundedRect,
+    Segment,
+    Segments,
+    Transform,
+};
+use crate::math::Vec2;
+
+impl Path {
+    /// A new empty path.
+    #[inline]
+    #[must_use]
+    pub const fn new() -> Self {
+        Self { elements: Vec::new() }
+    }
+
+    /// Return a builder for creating a path.
+    #[inline]
+    #[must_use]
+    pub fn builder() -> PathBuilder {
+        PathBuilder::new()
+    }
+
+    /// The elements of the path.
+    #[inline]
+    #[must_use]
+    pub fn elements(&self) -> &[PathEl] {
+        &self.elements
+    }
+
+    /// Whether the path has no elements.
+    #[inline]
+    #[must_use]
+    pub fn is_empty(&self) -> bool {
+        self.elements.is_empty()
+    }
+
+    /// An iterator over the drawn segments of the path.
+    ///
+    /// Closing a subpath whose end isn't at it's start yields a line back to
+    /// the start.
+    #[inline]
+    #[must_use]
+    pub fn segments(&self) -> Segments<'_> {
+        Segments {
+            elements: self.elements.iter(),
+            start: Point2::ORIGIN,
+            current: Point2::ORIGIN,
+        }
+    }
+
+    /// The smallest axis-aligned rectangle containing the path.
+    ///
+    /// Returns `None` if the path has no segments.
+    #[must_use]
+    pub fn bounds(&self) -> Option<Rect> {
+        self.segments().map(Segment::bounds).reduce(Rect::union)
+    }
+
+    /// Approximate each subpath with a polygon, within `tolerance` of the
+    /// curves.
+    ///
+    /// Open subpaths are closed. Subpaths with fewer than three points have
+    /// no area and are skipped.
+    ///
+    /// ```
+    /// # use crossd_graphics::geometry::{Circle, Path, Point2};
+    /// #
+    /// let circle = Circle::new(Point2::new(0.0, 0.0), 10.0);
+    /// let polygons = Path::builder().circle(circle).build().flatten(0.1);
+    ///
+    /// assert_eq!(polygons.len(), 1);
+    /// assert!(polygons[0].points.iter().all(|p| (p.x.hypot(p.y) - 10.0).abs() <= 0.1));
+    /// ```
+    #[must_use]
+    pub fn flatten(&self, tolerance: f32) -> Vec<Polygon> {
+        self.flatten_polylines(tolerance)
+            .into_iter()
+            .filter_map(|(mut points, _)| {
+                // the closing line is implied
+                if points.len() > 1 && points.first() == points.last() {
+                    points.pop();
+                }
+
+                (points.len() >= 3).then(|| Polygon::new(points))
+            })
+            .collect()
+    }
+
+    /// The amount of times the path goes around the point, positive where it
+    /// goes counter-clockwise on screen.
+    ///
+    /// Curves are flattened within `tolerance`, and open subpaths are closed.
+    #[must_use]
+    pub fn winding_number(&self, point: Point2, tolerance: f32) -> i32 {
+        self.flatten(tolerance).iter().map(|polygon| polygon.winding_number(point)).sum()
+    }
+
+    /// Whether the point is inside the path according to the fill rule.
+    ///
+    /// Curves are flattened within `tolerance`, and open subpaths are closed.
+    /// Points exactly on the outline may be either inside or outside.
+    ///
+    /// ```
+    /// # use crossd_graphics::geometry::{FillRule, Path, Point2};
+    /// #
+    /// // a square with a square hole, both going clockwise
+    /// let path = Path::from_svg("M0 0H30V30H0Z M10 10H20V20H10Z").unwrap();
+    /// let center = Point2::new(15.0, 15.0);
+    ///
+    /// assert!(path.contains(center, FillRule::NonZero, 0.1));
+    /// assert!(!path.contains(center, FillRule::EvenOdd, 0.1));
+    /// assert!(path.contains(Point2::new(5.0, 5.0), FillRule::EvenOdd, 0.1));
+    /// ```
+    #[must_use]
+    pub fn contains(&self, point: Point2, fill_rule: FillRule, tolerance: f32) -> bool {
+        let inside_bounds = self.bounds().is_some_and(|bounds| bounds.contains(point));
+
+        inside_bounds && fill_rule.is_inside(self.winding_number(point, tolerance))
+    }
+
+    /// The point on the path closest to `point`.
+    ///
+    /// Curves are flattened within `tolerance`. Open subpaths aren't closed,
+    /// so this is the closest point of a stroke along the path. Returns
+    /// `None` if the path is empty.
+    ///
+    /// ```
+    /// # use crossd_graphics::geometry::{Path, Point2};
+    /// #
+    /// let path = Path::from_svg("M0 0H10V10").unwrap();
+    ///
+    /// assert_eq!(
+    ///     path.nearest_point(Point2::new(3.0, 5.0), 0.1),
+    ///     Some(Point2::new(3.0, 0.0))
+    /// );
+    /// assert_eq!(
+    ///     path.nearest_point(Point2::new(12.0, 4.0), 0.1),
+    ///     Some(Point2::new(10.0, 4.0))
+    /// );
+    /// ```
+    #[must_use]
+    pub fn nearest_point(&self, point: Point2, tolerance: f32) -> Option<Point2> {
+        let polylines = self.flatten_polylines(tolerance);
+        let lines = polylines.iter().flat_map(|(points, closed)| {
+            let next = points.iter().cycle().skip(1);
+            // a single point is a line with no length
+            let len = if *closed || points.len() == 1 {
+                points.len()
+            } else {
+                points.len() - 1
+            };
+
+            points.iter().zip(next).take(len).map(|(a, b)| Line::new(*a, *b))
+        });
+
+        super::polygon::nearest_point(lines, point)
+    }
+
+    /// The distance from `point` to the closest point on the path.
+    ///
+    /// Curves are flattened within `tolerance`, and open subpaths aren't
+    /// closed. Returns infinity if the path is empty.
+    #[must_use]
+    pub fn distance_to(&self, point: Point2, tolerance: f32) -> f32 {
+        self.nearest_point(point, tolerance)
+            .map_or(f32::INFINITY, |p| (point - p).length())
+    }
+
+    /// Whether the point is on a stroke of `width` along the path, or at most
+    /// `hit_tolerance` away from it.
+    ///
+    /// Curves are flattened within `tolerance`. The ends of the stroke are
+    /// treated as round, whatever it's caps and joins.
+    ///
+    /// ```
+    /// # use crossd_graphics::geometry::{Path, Point2};
+    /// #
+    /// let path = Path::from_svg("M0 0H100").unwrap();
+    ///
+    /// assert!(path.hit_stroke(Point2::new(50.0, 2.0), 4.0, 0.0, 0.1));
+    /// assert!(!path.hit_stroke(Point2::new(50.0, 5.0), 4.0, 0.0, 0.1));
+    /// assert!(path.hit_stroke(Point2::new(50.0, 5.0), 4.0, 3.0, 0.1));
+    /// ```
+    #[must_use]
+    pub fn hit_stroke(
+        &self,
+        point: Point2,
+        width: f32,
+        hit_tolerance: f32,
+        tolerance: f32,
+    ) -> bool {
+        self.distance_to(point, tolerance) <= width / 2.0 + hit_tolerance
+    }
+
+    /// Flatten the path and remove points that are within `tolerance` of the
+    /// outline without them, using the Ramer-Douglas-Peucker algorithm.
+    ///
+    /// Curves are flattened within `tolerance` as well, so the result is up to
+    /// twice that away from the path. Open subpaths stay open, and keep their
+    /// ends.
+    ///
+    /// ```
+    /// # use crossd_graphics::geometry::Path;
+    /// #
+    /// let path = Path::from_svg("M0 0L5 0.1L10 0L20 10").unwrap();
+    ///
+    /// assert_eq!(path.simplify(0.5), Path::from_svg("M0 0L10 0L20 10").unwrap());
+    /// ```
+    #[must_use]
+    pub fn simplify(&self, tolerance: f32) -> Self {
+        let mut builder = Self::builder();
+
+        for (mut points, closed) in self.flatten_polylines(tolerance) {
+            let points = if closed {
+                if points.len() > 1 && points.first() == points.last() {
+                    points.pop();
+                }
+
+                Polygon::new(points).simplify(tolerance).points
+            } else {
+                super::polygon::simplify_polyline(&points, tolerance)
+            };
+
+            let Some((first, rest)) = points.split_first() else {
+                continue;
+            };
+
+            builder = rest.iter().fold(builder.move_to(*first), |b, p| b.line_to(*p));
+
+            if closed {
+                builder = builder.close();
+            }
+        }
+
+        builder.build()
+    }
+
+    /// Approximate each subpath with a polyline, within `tolerance` of the
+    /// curves, along with whether the subpath is closed.
+    pub(crate) fn flatten_polylines(&self, tolerance: f32) -> Vec<(Vec<Point2>, bool)> {
+        let mut polylines = Vec::new();
+        let mut points: Vec<Point2> = Vec::new();
+
+        for el in self {
+            let current = points.last().copied().unwrap_or_default();
+
+            match *el {
+                PathEl::MoveTo(p) => {
+                    if !points.is_empty() {
+                        polylines.push((mem::take(&mut points), false));
+                    }
+
+                    points.push(p);
+                },
+                PathEl::LineTo(p) => points.push(p),
+                PathEl::QuadTo(p1, p2) => {
+                    points.extend(QuadBezier::new(current, p1, p2).flatten(tolerance));
+                },
+                PathEl::CubicTo(p1, p2, p3) => {
+                    let cubic = CubicBezier::new(current, p1, p2, p3);
+
+                    points.extend(cubic.flatten(tolerance));
+                },
+                PathEl::Close => {
+                    if !points.is_empty() {
+                        polylines.push((mem::take(&mut points), true));
+                    }
+                },
+            }
+        }
+
+        if !points.is_empty() {
+            polylines.push((points, false));
+        }
+
+        polylines
+    }
+
+    /// Apply a transformation to each point of the path.
+    #[must_use]
+    pub fn transform(&self, trans: Transform) -> Self {
+        let point = |point| trans.transform_point(point);
+        let elements = self
+            .elements
+            .iter()
+            .map(|el| match *el {
+                PathEl::MoveTo(p) => PathEl::MoveTo(point(p)),
+                PathEl::LineTo(p) => PathEl::LineTo(point(p)),
+                PathEl::QuadTo(p1, p2) => PathEl::QuadTo(point(p1), point(p2)),
+                PathEl::CubicTo(p1, p2, p3) => {
+                    PathEl::CubicTo(point(p1), point(p2), point(p3))
+                },
+                PathEl::Close => PathEl::Close,
+            })
+            .collect();
+
+        Self { elements }
+    }
+
+    /// Move the path by a vector.
+    #[inline]
+    #[must_use]
+    pub fn translate(&self, by: Vec2) -> Self {
+        self.transform(Transform::translation(by))
+    }
+}
+
+impl<'a> IntoIterator for &'a Path {
+    type IntoIter = slice::Iter<'a, PathEl>;
+    type Item = &'a PathEl;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.elements.iter()
+    }
+}
+
+impl Segment {
+    /// The start point of the segment.
+    #[inline]
+    #[must_use]
+    pub fn start(self) -> Point2 {
+        match self {
+            Self::Line(line) => line.start,
+            Self::Quad(quad) => quad.p0,
+            Self::Cubic(cubic) => cubic.p0,
+        }
+    }
+
+    /// The end point of the segment.
+    #[inline]
+    #[must_use]
+    pub fn end(self) -> Point2 {
+        match self {
+            Self::Line(line) => line.end,
+            Self::Quad(quad) => quad.p2,
+            Self::Cubic(cubic) => cubic.p3,
+        }
+    }
+
+    /// The point at `t` along the segment, where `0` is the start and `1` is
+    /// the end.
+    #[inline]
+    #[must_use]
+    pub fn eval(self, t: f32) -> Point2 {
+        match self {
+            Self::Line(line) => line.at(t),
+            Self::Quad(quad) => quad.eval(t),
+            Self::Cubic(cubic) => cubic.eval(t),
+        }
+    }
+
+    /// Approximate the segment with lines, returning the points after the
+    /// start.
+    ///
+    /// No point on the lines is further than `tolerance` from the segment.
+    #[must_use]
+    pub fn flatten(self, tolerance: f32) -> Vec<Point2> {
+        match self {
+            Self::Line(line) => vec![line.end],
+            Self::Quad(quad) => quad.flatten(tolerance),
+            Self::Cubic(cubic) => cubic.flatten(tolerance),
+        }
+    }
+
+    /// The smallest axis-aligned rectangle containing the segment.
+    #[inline]
+    #[must_use]
+    pub fn bounds(self) -> Rect {
+        match self {
+            Self::Line(line) => Rect::from_points(line.start, line.end),
+            Self::Quad(quad) => quad.bounds(),
+            Self::Cubic(cubic) => cubic.bounds(),
+        }
+    }
+}
+
+impl Iterator for Segments<'_> {
+    type Item = Segment;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        loop {
+            let from = self.current;
+
+            let segment = match *self.elements.next()? {
+                PathEl::MoveTo(p) => {
+                    self.start = p;
+                    self.current = p;
+
+                    continue;
+                },
+                PathEl::LineTo(p) => Segment::Line(Line::new(from, p)),
+                PathEl::QuadTo(p1, p2) => Segment::Quad(QuadBezier::new(from, p1, p2)),
+                PathEl::CubicTo(p1, p2, p3) => {
+                    Segment::Cubic(CubicBezier::new(from, p1, p2, p3))
+                },
+                PathEl::Close => {
+                    self.current = self.start;
+
+                    if from == self.start {
+                        continue;
+                    }
+
+                    Segment::Line(Line::new(from, self.start))
+                },
+            };
+
+            self.current = segment.end();
+
+            return Some(segment);
+        }
+    }
+}
+
+impl PathBuilder {
+    /// A new builder for an empty path.
+    #[inline]
+    #[must_use]
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// The end of the last element, where the next segment will start.
+    #[inline]
+    #[must_use]
+    pub fn current(&self) -> Point2 {
+        self.current
+    }
+
+    /// Start a new subpath at the point.
+    #[must_use]
+    pub fn move_to(mut self, to: Point2) -> Self {
+        // consecutive moves only need the last
+        if let Some(PathEl::MoveTo(_)) = self.elements.last() {
+            self.elements.pop();
+        }
+
+        self.elements.push(PathEl::MoveTo(to));
+        self.start = to;
+        self.current = to;
+        self.open = true;
+
+        self
+    }
+
+    /// A line to the point.
+    #[must_use]
+    pub fn line_to(self, to: Point2) -> Self {
+        self.push(PathEl::LineTo(to), to)
+    }
+
+    /// A quadratic Bézier curve using a control point to the end point.
+    #[must_use]
+    pub fn quad_to(self, ctrl: Point2, to: Point2) -> Self {
+        self.push(PathEl::QuadTo(ctrl, to), to)
+    }
+
+    /// A cubic Bézier curve using two control points to the end point.
+    #[must_use]
+    pub fn cubic_to(self, ctrl1: Point2, ctrl2: Point2, to: Point2) -> Self {
+        self.push(PathEl::CubicTo(ctrl1, ctrl2, to), to)
+    }
+
+    /// An elliptical arc to the end point, like the SVG `A` command.
+    ///
+    /// The arc is part of an ellipse with the given radii whose `x` axis is
+    /// rotated by `rotation` radians. Of the (up to) four arcs of such an
+    /// ellipse between the current point and `to`, `large_arc` picks one that
+    /// spans more than 180 degrees and `sweep` picks one that goes from the `x`
+    /// axis towards the `y` axis (clockwise on screen).
+    ///
+    /// Arcs are stored as cubic Bézier curves.
+    #[must_use]
+    pub fn arc_to(
+        self,
+        radii: Vec2,
+        rotation: f32,
+        large_arc: bool,
+        sweep: bool,
+        to: Point2,
+    ) -> Self {
+        let from = self.current;
+
+        if from == to {
+            return self;
+        }
+
+        let radii = radii.map(f32::abs);
+
+        if radii.x == 0.0 || radii.y == 0.0 {
+            return self.line_to(to);
+        }
+
+        // https://www.w3.org/TR/SVG11/implnote.html#ArcConversionEndpointToCenter
+        let (sin, cos) = rotation.sin_cos();
+        let half = (from - to).scale(0.5);
+        let x1 = cos * half.x + sin * half.y;
+        let y1 = cos * half.y - sin * half.x;
+
+        // scale up the radii if there is no arc between the points
+        let lambda = (x1 * x1) / (radii.x * radii.x) + (y1 * y1) / (radii.y * radii.y);
+        let Vec2 { x: rx, y: ry } =
+            if lambda > 1.0 { radii.scale(lambda.sqrt()) } else { radii };
+
+        let num = rx * rx * ry * ry - rx * rx * y1 * y1 - ry * ry * x1 * x1;
+        let denom = rx * rx * y1 * y1 + ry * ry * x1 * x1;
+        let coef =
+            (num / denom).max(0.0).sqrt() * if large_arc == sweep { -1.0 } else { 1.0 };
+
+        let cx1 = coef * rx * y1 / ry;
+        let cy1 = -coef * ry * x1 / rx;
+
+        let mid = Point2::from_vec2((from.to_vec2() + to.to_vec2()).scale(0.5));
+        let center = mid + Vec2::new(cos * cx1 - sin * cy1, sin * cx1 + cos * cy1);
+
+        let start = angle(Vec2::new((x1 - cx1) / rx, (y1 - cy1) / ry));
+        let end = angle(Vec2::new((-x1 - cx1) / rx, (-y1 - cy1) / ry));
+        let mut delta = (end - start) % TAU;
+
+        if sweep && delta < 0.0 {
+            delta += TAU;
+        } else if !sweep && delta > 0.0 {
+            delta -= TAU;
+        }
+
+        let ellipse = Ellipse::new_rotated(center, Vec2::new(rx, ry), rotation);
+
+        self.arc(ellipse, start, delta, to)
+    }
+
+    /// Close the subpath with a line to it's start.
+    #[must_use]
+    pub fn close(mut self) -> Self {
+        if self.open {
+            self.elements.push(PathEl::Close);
+            self.current = self.start;
+            self.open = false;
+        }
+
+        self
+    }
+
+    /// Add a closed rectangle as a new subpath.
+    #[must_use]
+    pub fn rect(self, rect: Rect) -> Self {
+        let (min, max) = (rect.min(), rect.max());
+
+        self.move_to(min)
+            .line_to(Point2::new(max.x, min.y))
+            .line_to(max)
+            .line_to(Point2::new(min.x, max.y))
+            .close()
+    }
+
+    /// Add a closed rounded rectangle as a new subpath.
+    #[must_use]
+    pub fn rounded_rect(self, rect: RoundedRect) -> Self {
+        let CornerRadii { top_left, top_right, bottom_right, bottom_left } = rect.radii();
+        let (min, max) = (rect.rect.min(), rect.rect.max());
+
+        // each corner as it's radius, the center of it's circle, the angle it starts
+        // at and it's start and end points
+        let corners = [
+            (
+                top_right,
+                Point2::new(max.x - top_right, min.y + top_right),
+                -FRAC_PI_2,
+                Point2::new(max.x - top_right, min.y),
+                Point2::new(max.x, min.y + top_right),
+            ),
+            (
+                bottom_right,
+                Point2::new(max.x - bottom_right, max.y - bottom_right),
+                0.0,
+                Point2::new(max.x, max.y - bottom_right),
+                Point2::new(max.x - bottom_right, max.y),
+            ),
+            (
+                bottom_left,
+                Point2::new(min.x + bottom_left, max.y - bottom_left),
+                FRAC_PI_2,
+                Point2::new(min.x + bottom_left, max.y),
+                Point2::new(min.x, max.y - bottom_left),
+            ),
+            (
+                top_left,
+                Point2::new(min.x + top_left, min.y + top_left),
+                PI,
+                Point2::new(min.x, min.y + top_left),
+                Point2::new(min.x + top_left, min.y),
+            ),
+        ];
+
+        let mut builder = self.move_to(Point2::new(min.x + top_left, min.y));
+
+        for (radius, center, angle, from, to) in corners {
+            if builder.current != from {
+                builder = builder.line_to(from);
+            }
+
+            if radius > 0.0 {
+                builder =
+                    builder.arc(Circle::new(center, radius).into(), angle, FRAC_PI_2, to);
+            }
+        }
+
+        builder.close()
+    }
+
+    /// Add a closed ellipse as a new subpath.
+    #[must_use]
+    pub fn ellipse(self, ellipse: Ellipse) -> Self {
+        let (sin, cos) = ellipse.rotation.sin_cos();
+        let start = ellipse.center + Vec2::new(cos, sin).scale(ellipse.radii.x);
+
+        self.move_to(start).arc(ellipse, 0.0, TAU, start).close()
+    }
+
+    /// Add a closed circle as a new subpath.
+    #[inline]
+    #[must_use]
+    pub fn circle(self, circle: Circle) -> Self {
+        self.ellipse(circle.into())
+    }
+
+    /// Build the path.
+    #[inline]
+    #[must_use]
+    pub fn build(mut self) -> Path {
+        // a trailing move doesn't draw anything
+        if let Some(PathEl::MoveTo(_)) = self.elements.last() {
+            self.elements.pop();
+        }
+
+        Path { elements: self.elements }
+    }
+
+    /// Add an arc of an ellipse as cubic Bézier curves, ending exactly at `to`.
+    fn arc(mut self, ellipse: Ellipse, start: f32, sweep: f32, to: Point2) -> Self {
+        for cubic in arc_cubics(ellipse, start, sweep) {
+            self = self.cubic_to(cubic.p1, cubic.p2, cubic.p3);
+        }
+
+        // avoid leaving rounding errors at the end of the arc
+        if let Some(PathEl::CubicTo(_, _, end)) = self.elements.last_mut() {
+            *end = to;
+        }
+
+        self.current = to;
+
+        self
+    }
+
+    /// Push a drawing element, starting a subpath if needed.
+    fn push(mut self, el: PathEl, to: Point2) -> Self {
+        if !self.open {
+            let current = self.current;
+            self = self.move_to(current);
+        }
+
+        self.elements.push(el);
+        self.current = to;
+
+        self
+    }
+}
+
+/// The angle of a vector from the `x` axis.
+fn angle(vec2: Vec2) -> f32 {
+    vec2.y.atan2(vec2.x)
+}
+
+/// Approximate an arc of an ellipse with cubic Bézier curves, each spanning at
+/// most 90 degrees.
+///
+/// The angles are in radians in the space of the ellipse before rotation.
+fn arc_cubics(ellipse: Ellipse, start: f32, sweep: f32) -> Vec<CubicBezier> {
+    let count = (sweep.abs() / FRAC_PI_2).ceil().max(1.0) as usize;
+    let step = sweep / count as f32;
+    // distance of the control points along the tangent for a unit circle
+    let k = 4.0 / 3.0 * (step / 4.0).tan();
+
+    let (sin, cos) = ellipse.rotation.sin_cos();
+    let point = |v: Vec2| {
+        let v = v * ellipse.radii;
+
+        ellipse.center + Vec2::new(v.x * cos - v.y * sin, v.x * sin + v.y * cos)
+    };
+
+    (0..count)
+        .map(|i| {
+            let a = start + step * i as f32;
+            let b = a + step;
+
+            let (sin_a, cos_a) = a.sin_cos();
+            let (sin_b, cos_b) = b.sin_cos();
+
+            let p0 = Vec2::new(cos_a, sin_a);
+            let p3 = Vec2::new(cos_b, sin_b);
+            let p1 = p0 + Vec2::new(-sin_a, cos_a).scale(k);
+            let p2 = p3 - Vec2::new(-sin_b, cos_b).scale(k);
+
+            CubicBezier::new(point(p0), point(p1), point(p2), point(p3))
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod test {
+    use super::super::{Circle, FillRule, Path, PathEl, Point2, Segment};
+    use crate::math::Vec2;
+
+    fn p(x: f32, y: f32) -> Point2 {
+        Point2::new(x, y)
+    }
+
+    /// The point halfway along a quarter arc of radius 10 from `(10, 0)` to
+    /// `(0, 10)`.
+    fn arc_mid(large_arc: bool, sweep: bool) -> Point2 {
+        let path = Path::builder()
+            .move_to(p(10.0, 0.0))
+            .arc_to(Vec2::new(10.0, 10.0), 0.0, large_arc, sweep, p(0.0, 10.0))
+            .build();
+        let segments: Vec<_> = path.segments().collect();
+
+        assert_eq!(segments.first().map(|s| s.start()), Some(p(10.0, 0.0)));
+        assert_eq!(segments.last().map(|s| s.end()), Some(p(0.0, 10.0)));
+
+        // the arc is split into segments of equal angles
+        let half = segments.len() as f32 / 2.0;
+        let segment = segments[half as usize];
+
+        segment.eval(half.fract())
+    }
+
+    #[track_caller]
+    fn assert_near(a: Point2, b: Point2) {
+        assert!((a - b).x.hypot((a - b).y) < 1e-3, "{a:?} != {b:?}");
+    }
+
+    #[test]
+    fn arc_to() {
+        let half = 10.0 * std::f32::consts::FRAC_1_SQRT_2;
+
+        // from the x axis towards the y axis, clockwise on screen, around
+        // the origin
+        assert_near(arc_mid(false, true), p(half, half));
+        // the other way around `(10, 10)`
+        assert_near(arc_mid(false, false), p(10.0 - half, 10.0 - half));
+        // the long way around both
+        assert_near(arc_mid(true, false), p(-half, -half));
+        assert_near(arc_mid(true, true), p(10.0 + half, 10.0 + half));
+
+        // radii too small to reach are scaled up to a half circle
+        let path = Path::builder()
+            .move_to(p(0.0, 0.0))
+            .arc_to(Vec2::new(1.0, 1.0), 0.0, false, true, p(20.0, 0.0))
+            .build();
+        let bounds = path.bounds().unwrap();
+
+        assert_eq!(path.segments().last().map(|s| s.end()), Some(p(20.0, 0.0)));
+        assert!((bounds.min().y - -10.0).abs() < 1e-3 && bounds.max().y.abs() < 1e-3);
+
+        // zero radii are a line
+        let path = Path::builder()
+            .move_to(p(0.0, 0.0))
+            .arc_to(Vec2::new(0.0, 5.0), 0.0, false, true, p(20.0, 0.0))
+            .build();
+
+        assert_eq!(
+            path.elements(),
+            [PathEl::MoveTo(p(0.0, 0.0)), PathEl::LineTo(p(20.0, 0.0))]
+        );
+    }
+
+    #[test]
+    fn close() {
+        let builder = Path::builder()
+            .move_to(p(0.0, 0.0))
+            .line_to(p(10.0, 0.0))
+            .line_to(p(10.0, 10.0))
+            .close();
+
+        assert_eq!(builder.current(), p(0.0, 0.0));
+
+        // closing twice adds nothing
+        let path = builder.close().build();
+
+        assert_eq!(path.elements().last(), Some(&PathEl::Close));
+        assert_eq!(path.elements().iter().filter(|el| **el == PathEl::Close).count(), 1);
+
+        let segments: Vec<_> = path.segments().collect();
+
+        assert_eq!(segments.len(), 3);
+        assert_eq!(
+            segments.last().map(|s| (s.start(), s.end())),
+            Some((p(10.0, 10.0), p(0.0, 0.0)))
+        );
+        assert_eq!(
+            path.flatten(0.1)[0].points,
+            [p(0.0, 0.0), p(10.0, 0.0), p(10.0, 10.0)]
+        );
+
+        // a subpath ending at it's start has no closing line
+        let path = Path::builder()
+            .move_to(p(0.0, 0.0))
+            .line_to(p(10.0, 0.0))
+            .line_to(p(0.0, 10.0))
+            .line_to(p(0.0, 0.0))
+            .close()
+            .build();
+
+        assert_eq!(path.segments().count(), 3);
+        assert!(path.segments().all(|s| matches!(s, Segment::Line(_))));
+    }
+
+    #[test]
+    fn holes() {
+        let inside = |data: &str, point: Point2, fill_rule| {
+            Path::from_svg(data).unwrap().contains(point, fill_rule, 0.1)
+        };
+        let (hole, ring) = (p(15.0, 15.0), p(5.0, 5.0));
+
+        // a hole going the other way is a hole for both fill rules
+        let opposite = "M0 0H30V30H0Z M10 10V20H20V10Z";
+
+        for fill_rule in [FillRule::NonZero, FillRule::EvenOdd] {
+            assert!(!inside(opposite, hole, fill_rule));
+            assert!(inside(opposite, ring, fill_rule));
+            assert!(!inside(opposite, p(35.0, 15.0), fill_rule));
+        }
+
+        // one going the same way only for even-odd
+        let same = "M0 0H30V30H0Z M10 10H20V20H10Z";
+
+        assert!(inside(same, hole, FillRule::NonZero));
+        assert!(!inside(same, hole, FillRule::EvenOdd));
+        assert!(inside(same, ring, FillRule::EvenOdd));
+    }
+
+    #[test]
+    fn curves() {
+        let circle = Path::builder().circle(Circle::new(p(0.0, 0.0), 10.0)).build();
+        let tolerance = 0.1;
+
+        // the flattened outline is within the tolerance of the circle
+        for i in 0..32 {
+            let (sin, cos) = (i as f32 / 32.0 * std::f32::consts::TAU).sin_cos();
+            let at = |radius: f32| p(radius * cos, radius * sin);
+
+            for fill_rule in [FillRule::NonZero, FillRule::EvenOdd] {
+                assert!(circle.contains(at(9.85), fill_rule, tolerance));
+                assert!(!circle.contains(at(10.05), fill_rule, tolerance));
+            }
+
+            assert!((circle.distance_to(at(12.0), tolerance) - 2.0).abs() <= tolerance);
+            assert!(circle.hit_stroke(at(11.8), 4.0, 0.0, tolerance));
+            assert!(!circle.hit_stroke(at(12.05), 4.0, 0.0, tolerance));
+            assert!(circle.hit_stroke(at(12.05), 4.0, 0.2, tolerance));
+        }
+    }
+}
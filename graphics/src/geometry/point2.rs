@@ -1,15 +1,200 @@
+use std::cmp::Ordering;
+use std::fmt;
+use std::marker::PhantomData;
+use std::ops::{Add, AddAssign, Sub, SubAssign};
+
 use bytemuck::{Pod, Zeroable};
+use mint::IntoMint;
 
-use super::Point2;
+use super::{Logical, Physical, Point2, ScaleFactor};
+use crate::math::{Num, Vec2, Zero};
 
-impl<T> Point2<T> {
+impl<T, U> Point2<T, U> {
     /// A new point from x and y.
     #[inline]
     #[must_use]
     pub const fn new(x: T, y: T) -> Self {
+        Self { x, y, _unit: PhantomData }
+    }
+
+    /// A point at the location described by a vector from the origin.
+    #[inline]
+    #[must_use]
+    pub fn from_vec2(vec2: Vec2<T>) -> Self {
+        let Vec2 { x, y } = vec2;
+
+        Self::new(x, y)
+    }
+
+    /// The vector from the origin to this point.
+    #[inline]
+    #[must_use]
+    pub fn to_vec2(self) -> Vec2<T> {
+        let Self { x, y, .. } = self;
+
+        Vec2 { x, y }
+    }
+
+    /// Transform a `Point2<T>` -> `Point2<V>` with a callback called on each
+    /// component.
+    #[inline]
+    #[must_use]
+    pub fn map<V>(self, f: impl FnMut(T) -> V) -> Point2<V, U> {
+        let mut f = f;
+
+        Point2::new(f(self.x), f(self.y))
+    }
+
+    /// Reinterpret the point as being in another unit.
+    #[inline]
+    #[must_use]
+    pub fn cast_unit<V>(self) -> Point2<T, V> {
+        Point2::new(self.x, self.y)
+    }
+}
+
+impl<T: Zero, U> Point2<T, U> {
+    /// The point at `(0, 0)`.
+    pub const ORIGIN: Self = Self::new(T::ZERO, T::ZERO);
+}
+
+impl<T: Num> Point2<T, Logical> {
+    /// Convert to physical pixels.
+    #[inline]
+    #[must_use]
+    pub fn to_physical(self, scale: ScaleFactor<T>) -> Point2<T, Physical> {
+        Point2::new(self.x * scale.0, self.y * scale.0)
+    }
+}
+
+impl<T: Num> Point2<T, Physical> {
+    /// Convert to logical pixels.
+    #[inline]
+    #[must_use]
+    pub fn to_logical(self, scale: ScaleFactor<T>) -> Point2<T, Logical> {
+        Point2::new(self.x / scale.0, self.y / scale.0)
+    }
+}
+
+// ----------
+// operations
+// ----------
+
+/// The difference between two points is the vector from `rhs` to `self`.
+impl<T: Num, U> Sub for Point2<T, U> {
+    type Output = Vec2<T>;
+
+    fn sub(self, rhs: Self) -> Self::Output {
+        Vec2::new(self.x - rhs.x, self.y - rhs.y)
+    }
+}
+
+/// Translate a point by a vector.
+impl<T: Num, U> Add<Vec2<T>> for Point2<T, U> {
+    type Output = Self;
+
+    fn add(self, rhs: Vec2<T>) -> Self::Output {
+        Self::new(self.x + rhs.x, self.y + rhs.y)
+    }
+}
+
+/// Translate a point by the inverse of a vector.
+impl<T: Num, U> Sub<Vec2<T>> for Point2<T, U> {
+    type Output = Self;
+
+    fn sub(self, rhs: Vec2<T>) -> Self::Output {
+        Self::new(self.x - rhs.x, self.y - rhs.y)
+    }
+}
+
+impl<T: Num, U> AddAssign<Vec2<T>> for Point2<T, U> {
+    fn add_assign(&mut self, rhs: Vec2<T>) {
+        *self = *self + rhs
+    }
+}
+
+impl<T: Num, U> SubAssign<Vec2<T>> for Point2<T, U> {
+    fn sub_assign(&mut self, rhs: Vec2<T>) {
+        *self = *self - rhs
+    }
+}
+
+// ------
+// traits
+// ------
+
+// implemented by hand so that the unit marker doesn't need to implement them
+
+impl<T: fmt::Debug, U> fmt::Debug for Point2<T, U> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("Point2").field("x", &self.x).field("y", &self.y).finish()
+    }
+}
+
+impl<T: Default, U> Default for Point2<T, U> {
+    fn default() -> Self {
+        Self::new(T::default(), T::default())
+    }
+}
+
+impl<T: Clone, U> Clone for Point2<T, U> {
+    fn clone(&self) -> Self {
+        Self::new(self.x.clone(), self.y.clone())
+    }
+}
+
+impl<T: Copy, U> Copy for Point2<T, U> {}
+
+impl<T: PartialEq, U> PartialEq for Point2<T, U> {
+    fn eq(&self, other: &Self) -> bool {
+        self.x == other.x && self.y == other.y
+    }
+}
+
+impl<T: PartialOrd, U> PartialOrd for Point2<T, U> {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        match self.x.partial_cmp(&other.x) {
+            Some(Ordering::Equal) => self.y.partial_cmp(&other.y),
+            ord => ord,
+        }
+    }
+}
+
+// -----------
+// conversions
+// -----------
+
+impl<T, U> From<Vec2<T>> for Point2<T, U> {
+    fn from(vec2: Vec2<T>) -> Self {
+        Self::from_vec2(vec2)
+    }
+}
+
+impl<T, U> From<Point2<T, U>> for Vec2<T> {
+    fn from(point2: Point2<T, U>) -> Self {
+        point2.to_vec2()
+    }
+}
+
+impl<T, U> IntoMint for Point2<T, U> {
+    type MintType = mint::Point2<T>;
+}
+
+impl<T, U> From<Point2<T, U>> for mint::Point2<T> {
+    fn from(point2: Point2<T, U>) -> Self {
+        let Point2 { x, y, .. } = point2;
+
         Self { x, y }
     }
 }
 
-unsafe impl<T: Pod> Pod for Point2<T> {}
-unsafe impl<T: Zeroable> Zeroable for Point2<T> {}
+impl<T, U> From<mint::Point2<T>> for Point2<T, U> {
+    fn from(mint: mint::Point2<T>) -> Self {
+        let mint::Point2 { x, y } = mint;
+
+        Self::new(x, y)
+    }
+}
+
+unsafe impl<T: Pod, U: 'static> Pod for Point2<T, U> {}
+unsafe impl<T: Zeroable, U> Zeroable for Point2<T, U> {}
@@ -0,0 +1,489 @@
+use super::{FillRule, Line, Point2, Polygon, Rect, Transform, Winding};
+use crate::math::Vec2;
+
+impl Polygon {
+    /// A new polygon from it's corners.
+    #[inline]
+    #[must_use]
+    pub const fn new(points: Vec<Point2>) -> Self {
+        Self { points }
+    }
+
+    /// An iterator over the edges of the polygon, including the one from the
+    /// last point back to the first.
+    pub fn edges(&self) -> impl Iterator<Item = Line> + '_ {
+        let next = self.points.iter().cycle().skip(1);
+
+        self.points.iter().zip(next).map(|(a, b)| Line::new(*a, *b))
+    }
+
+    /// The smallest axis-aligned rectangle containing the polygon.
+    ///
+    /// Returns `None` if the polygon has no points.
+    #[must_use]
+    pub fn bounds(&self) -> Option<Rect> {
+        self.points.iter().map(|p| Rect::from_points(*p, *p)).reduce(Rect::union)
+    }
+
+    /// The area of the polygon, positive if it goes counter-clockwise on
+    /// screen and negative if it goes clockwise, like
+    /// [`Polygon::winding_number`].
+    ///
+    /// Parts of a self-intersecting polygon going opposite ways cancel out.
+    #[must_use]
+    pub fn signed_area(&self) -> f32 {
+        let twice: f32 = self
+            .edges()
+            .map(|Line { start, end }| end.to_vec2().cross(start.to_vec2()))
+            .sum();
+
+        twice / 2.0
+    }
+
+    /// The direction the polygon goes around it's inside, or `None` if it has
+    /// no area.
+    ///
+    /// ```
+    /// # use crossd_graphics::geometry::{Point2, Polygon, Winding};
+    /// #
+    /// let mut triangle: Polygon = [(0.0, 0.0), (10.0, 0.0), (0.0, 10.0)]
+    ///     .into_iter()
+    ///     .map(|(x, y)| Point2::new(x, y))
+    ///     .collect();
+    ///
+    /// assert_eq!(triangle.signed_area(), -50.0);
+    /// assert_eq!(triangle.winding(), Some(Winding::Clockwise));
+    ///
+    /// triangle.reverse();
+    /// assert_eq!(triangle.signed_area(), 50.0);
+    /// assert_eq!(triangle.winding(), Some(Winding::CounterClockwise));
+    /// ```
+    #[must_use]
+    pub fn winding(&self) -> Option<Winding> {
+        let area = self.signed_area();
+
+        if area > 0.0 {
+            Some(Winding::CounterClockwise)
+        } else if area < 0.0 {
+            Some(Winding::Clockwise)
+        } else {
+            None
+        }
+    }
+
+    /// Reverse the direction of the polygon.
+    #[inline]
+    pub fn reverse(&mut self) {
+        self.points.reverse();
+    }
+
+    /// The smallest convex polygon containing all the points, going clockwise
+    /// on screen.
+    ///
+    /// Points on the edges of the hull are left out. If all points are in
+    /// line, the result has fewer than three points.
+    ///
+    /// ```
+    /// # use crossd_graphics::geometry::{Point2, Polygon, Winding};
+    /// #
+    /// let points =
+    ///     [(0.0, 0.0), (5.0, 5.0), (10.0, 0.0), (5.0, 0.0), (10.0, 10.0), (0.0, 10.0)];
+    /// let hull = Polygon::convex_hull(points.into_iter().map(|(x, y)| Point2::new(x, y)));
+    ///
+    /// assert_eq!(hull.points.len(), 4);
+    /// assert_eq!(hull.signed_area(), -100.0);
+    /// assert_eq!(hull.winding(), Some(Winding::Clockwise));
+    /// ```
+    #[must_use]
+    pub fn convex_hull(points: impl IntoIterator<Item = Point2>) -> Self {
+        let mut points: Vec<Point2> = points.into_iter().collect();
+        points.sort_by(|a, b| a.x.total_cmp(&b.x).then(a.y.total_cmp(&b.y)));
+        points.dedup();
+
+        if points.len() < 3 {
+            return Self::new(points);
+        }
+
+        // Andrew's monotone chain, keeping only clockwise turns on screen
+        let mut hull: Vec<Point2> = Vec::with_capacity(points.len() + 1);
+        let turns_clockwise =
+            |hull: &[Point2], p: Point2| (hull[1] - hull[0]).cross(p - hull[1]) > 0.0;
+
+        // the top of the hull from left to right
+        for p in &points {
+            while hull.len() >= 2 && !turns_clockwise(&hull[hull.len() - 2..], *p) {
+                hull.pop();
+            }
+
+            hull.push(*p);
+        }
+
+        // then the bottom back from right to left
+        let top = hull.len();
+
+        for p in points.iter().rev().skip(1) {
+            while hull.len() > top && !turns_clockwise(&hull[hull.len() - 2..], *p) {
+                hull.pop();
+            }
+
+            hull.push(*p);
+        }
+
+        // the last point is the first one again
+        hull.pop();
+
+        Self::new(hull)
+    }
+
+    /// Remove points that are within `tolerance` of the outline without them,
+    /// using the Ramer-Douglas-Peucker algorithm.
+    ///
+    /// ```
+    /// # use crossd_graphics::geometry::{Point2, Polygon};
+    /// #
+    /// // a square with a slightly bumpy top
+    /// let square: Polygon =
+    ///     [(0.0, 0.0), (5.0, 0.1), (10.0, 0.0), (10.0, 10.0), (0.0, 10.0)]
+    ///         .into_iter()
+    ///         .map(|(x, y)| Point2::new(x, y))
+    ///         .collect();
+    ///
+    /// assert_eq!(square.simplify(0.5).points.len(), 4);
+    /// assert_eq!(square.simplify(0.05), square);
+    /// ```
+    #[must_use]
+    pub fn simplify(&self, tolerance: f32) -> Self {
+        let points = &self.points;
+
+        if points.len() < 3 {
+            return self.clone();
+        }
+
+        // split the outline between the first point and the one furthest
+        // from it, which are both kept
+        let far = (1..points.len())
+            .max_by(|a, b| {
+                let distance = |i: &usize| (points[*i] - points[0]).length();
+
+                distance(a).total_cmp(&distance(b))
+            })
+            .unwrap_or(1);
+
+        let mut first = simplify_polyline(&points[..=far], tolerance);
+        let mut rest: Vec<Point2> = points[far..].to_vec();
+        rest.push(points[0]);
+        let rest = simplify_polyline(&rest, tolerance);
+
+        first.pop();
+        first.extend(&rest[..rest.len() - 1]);
+
+        Self::new(first)
+    }
+
+    /// The amount of times the outline goes around the point, positive where
+    /// it goes counter-clockwise on screen.
+    #[must_use]
+    pub fn winding_number(&self, point: Point2) -> i32 {
+        self.edges()
+            .map(|Line { start, end }| {
+                // count edges crossing the horizontal line left of the point,
+                // including their top end but not their bottom end
+                let winding = match (start.y <= point.y, end.y <= point.y) {
+                    (true, false) => 1,
+                    (false, true) => -1,
+                    _ => return 0,
+                };
+                let t = (point.y - start.y) / (end.y - start.y);
+
+                if start.x + (end.x - start.x) * t < point.x {
+                    winding
+                } else {
+                    0
+                }
+            })
+            .sum()
+    }
+
+    /// Whether the point is inside the polygon according to the fill rule.
+    ///
+    /// Points exactly on the outline may be either inside or outside.
+    ///
+    /// ```
+    /// # use crossd_graphics::geometry::{FillRule, Point2, Polygon};
+    /// #
+    /// // a pentagram, whose center winds around twice
+    /// let star: Polygon =
+    ///     [(50.0, 0.0), (79.0, 90.0), (2.0, 35.0), (98.0, 35.0), (21.0, 90.0)]
+    ///         .into_iter()
+    ///         .map(|(x, y)| Point2::new(x, y))
+    ///         .collect();
+    /// let center = Point2::new(50.0, 50.0);
+    ///
+    /// assert!(star.contains(center, FillRule::NonZero));
+    /// assert!(!star.contains(center, FillRule::EvenOdd));
+    /// assert!(!star.contains(Point2::new(0.0, 0.0), FillRule::NonZero));
+    /// ```
+    #[inline]
+    #[must_use]
+    pub fn contains(&self, point: Point2, fill_rule: FillRule) -> bool {
+        fill_rule.is_inside(self.winding_number(point))
+    }
+
+    /// The point on the outline closest to `point`.
+    ///
+    /// Returns `None` if the polygon has no points.
+    #[must_use]
+    pub fn nearest_point(&self, point: Point2) -> Option<Point2> {
+        nearest_point(self.edges(), point)
+    }
+
+    /// The distance from `point` to the closest point on the outline.
+    ///
+    /// Returns infinity if the polygon has no points.
+    #[must_use]
+    pub fn distance_to(&self, point: Point2) -> f32 {
+        self.nearest_point(point).map_or(f32::INFINITY, |p| (point - p).length())
+    }
+
+    /// Whether the point is on a stroke of `width` along the outline, or at
+    /// most `hit_tolerance` away from it.
+    ///
+    /// The corners of the stroke are treated as round, whatever it's joins.
+    ///
+    /// ```
+    /// # use crossd_graphics::geometry::{Point2, Polygon};
+    /// #
+    /// let square: Polygon = [(0.0, 0.0), (10.0, 0.0), (10.0, 10.0), (0.0, 10.0)]
+    ///     .into_iter()
+    ///     .map(|(x, y)| Point2::new(x, y))
+    ///     .collect();
+    ///
+    /// assert!(square.hit_stroke(Point2::new(5.0, 1.0), 2.0, 0.0));
+    /// assert!(!square.hit_stroke(Point2::new(5.0, 5.0), 2.0, 0.0));
+    /// assert!(square.hit_stroke(Point2::new(5.0, 5.0), 2.0, 4.0));
+    /// ```
+    #[inline]
+    #[must_use]
+    pub fn hit_stroke(&self, point: Point2, width: f32, hit_tolerance: f32) -> bool {
+        self.distance_to(point) <= width / 2.0 + hit_tolerance
+    }
+
+    /// Move the polygon by a vector.
+    #[must_use]
+    pub fn translate(&self, by: Vec2) -> Self {
+        Self::new(self.points.iter().map(|p| *p + by).collect())
+    }
+
+    /// Apply a transformation to the polygon.
+    #[must_use]
+    pub fn transform(&self, trans: Transform) -> Self {
+        Self::new(self.points.iter().map(|p| trans.transform_point(*p)).collect())
+    }
+}
+
+impl From<Vec<Point2>> for Polygon {
+    fn from(points: Vec<Point2>) -> Self {
+        Self::new(points)
+    }
+}
+
+impl FromIterator<Point2> for Polygon {
+    fn from_iter<I: IntoIterator<Item = Point2>>(iter: I) -> Self {
+        Self::new(iter.into_iter().collect())
+    }
+}
+
+/// The point on any of the lines closest to `point`.
+pub(super) fn nearest_point(
+    lines: impl Iterator<Item = Line>,
+    point: Point2,
+) -> Option<Point2> {
+    lines
+        .map(|line| line.nearest_point(point))
+        .min_by(|a, b| (point - *a).length().total_cmp(&(point - *b).length()))
+}
+
+/// Remove points of a polyline that are within `tolerance` of the polyline
+/// without them, keeping the ends.
+pub(super) fn simplify_polyline(points: &[Point2], tolerance: f32) -> Vec<Point2> {
+    let Some(last) = points.len().checked_sub(1) else {
+        return Vec::new();
+    };
+
+    let mut keep = vec![false; points.len()];
+    keep[0] = true;
+    keep[last] = true;
+
+    let mut stack = vec![(0, last)];
+
+    while let Some((from, to)) = stack.pop() {
+        let line = Line::new(points[from], points[to]);
+        let furthest = (from + 1..to)
+            .map(|i| (i, line.distance_to(points[i])))
+            .max_by(|a, b| a.1.total_cmp(&b.1));
+
+        if let Some((i, _)) = furthest.filter(|(_, distance)| *distance > tolerance) {
+            keep[i] = true;
+            stack.extend([(from, i), (i, to)]);
+        }
+    }
+
+    points.iter().zip(keep).filter(|(_, keep)| *keep).map(|(p, _)| *p).collect()
+}
+
+#[cfg(test)]
+mod test {
+    use super::super::{FillRule, Path, Point2, Polygon, Winding};
+    use super::simplify_polyline;
+
+    fn polygon(points: &[(f32, f32)]) -> Polygon {
+        points.iter().map(|&(x, y)| Point2::new(x, y)).collect()
+    }
+
+    #[test]
+    fn winding_sign() {
+        // right, down, left and up, which is clockwise on screen
+        let clockwise = polygon(&[(0.0, 0.0), (10.0, 0.0), (10.0, 10.0), (0.0, 10.0)]);
+        let mut counter_clockwise = clockwise.clone();
+        counter_clockwise.reverse();
+
+        let (inside, outside) = (Point2::new(5.0, 5.0), Point2::new(15.0, 5.0));
+
+        assert_eq!(clockwise.winding(), Some(Winding::Clockwise));
+        assert_eq!(clockwise.signed_area(), -100.0);
+        assert_eq!(clockwise.winding_number(inside), -1);
+        assert_eq!(clockwise.winding_number(outside), 0);
+
+        assert_eq!(counter_clockwise.winding(), Some(Winding::CounterClockwise));
+        assert_eq!(counter_clockwise.signed_area(), 100.0);
+        assert_eq!(counter_clockwise.winding_number(inside), 1);
+        assert_eq!(counter_clockwise.winding_number(outside), 0);
+
+        let path = |data| Path::from_svg(data).unwrap();
+        assert_eq!(path("M0 0H10V10H0Z").winding_number(inside, 0.1), -1);
+        assert_eq!(path("M0 0V10H10V0Z").winding_number(inside, 0.1), 1);
+        // twice around
+        assert_eq!(path("M0 0V10H10V0ZM1 1V9H9V1Z").winding_number(inside, 0.1), 2);
+    }
+
+    #[test]
+    fn convex_hull() {
+        // corners given twice, with points inside and on the edges
+        let points = polygon(&[
+            (10.0, 10.0),
+            (0.0, 0.0),
+            (5.0, 0.0),
+            (0.0, 10.0),
+            (10.0, 0.0),
+            (5.0, 5.0),
+            (0.0, 0.0),
+            (10.0, 5.0),
+            (10.0, 10.0),
+        ]);
+        let hull = Polygon::convex_hull(points.points);
+
+        assert_eq!(hull, polygon(&[(0.0, 0.0), (10.0, 0.0), (10.0, 10.0), (0.0, 10.0)]));
+
+        // points in line only keep the ends
+        let line = polygon(&[(2.0, 2.0), (0.0, 0.0), (3.0, 3.0), (1.0, 1.0), (3.0, 3.0)]);
+
+        assert_eq!(Polygon::convex_hull(line.points), polygon(&[(0.0, 0.0), (3.0, 3.0)]));
+        assert_eq!(Polygon::convex_hull([Point2::new(1.0, 1.0); 3]).points.len(), 1);
+        assert!(Polygon::convex_hull([]).points.is_empty());
+    }
+
+    #[test]
+    fn simplify() {
+        // a zigzag within 0.1 of the line between it's ends
+        let zigzag: Vec<Point2> =
+            (0..=10).map(|i| Point2::new(i as f32, (i % 2) as f32 * 0.1)).collect();
+
+        assert_eq!(simplify_polyline(&zigzag, 0.5), [zigzag[0], zigzag[10]]);
+        assert_eq!(simplify_polyline(&zigzag, 0.05), zigzag);
+
+        // a circle of 64 points, where removed points are within the tolerance
+        let circle: Polygon = (0..64)
+            .map(|i| {
+                let (sin, cos) = (i as f32 / 64.0 * std::f32::consts::TAU).sin_cos();
+
+                Point2::new(10.0 * cos, 10.0 * sin)
+            })
+            .collect();
+
+        for tolerance in [0.05, 0.5, 2.0] {
+            let simplified = circle.simplify(tolerance);
+
+            assert!(simplified.points.len() < circle.points.len());
+            assert_eq!(simplified.points[0], circle.points[0]);
+            assert!(simplified.points.iter().all(|p| circle.points.contains(p)));
+            assert!(circle
+                .points
+                .iter()
+                .all(|p| simplified.distance_to(*p) <= tolerance));
+        }
+
+        assert_eq!(circle.simplify(0.0001), circle);
+    }
+
+    #[test]
+    fn fill_rules() {
+        // a bow tie, whose halves wind opposite ways
+        let bow_tie = polygon(&[(0.0, 0.0), (10.0, 10.0), (10.0, 0.0), (0.0, 10.0)]);
+        let (left, right) = (Point2::new(2.0, 5.0), Point2::new(8.0, 5.0));
+
+        assert_eq!(bow_tie.winding_number(left) + bow_tie.winding_number(right), 0);
+        assert_eq!(bow_tie.winding_number(left).abs(), 1);
+
+        for fill_rule in [FillRule::NonZero, FillRule::EvenOdd] {
+            assert!(bow_tie.contains(left, fill_rule));
+            assert!(bow_tie.contains(right, fill_rule));
+            assert!(!bow_tie.contains(Point2::new(5.0, 2.0), fill_rule));
+        }
+
+        // a pentagram, whose center winds around twice
+        let star = polygon(&[
+            (50.0, 0.0),
+            (79.0, 90.0),
+            (2.0, 35.0),
+            (98.0, 35.0),
+            (21.0, 90.0),
+        ]);
+        let (center, tip) = (Point2::new(50.0, 50.0), Point2::new(50.0, 10.0));
+
+        assert_eq!(star.winding_number(center).abs(), 2);
+        assert!(star.contains(center, FillRule::NonZero));
+        assert!(!star.contains(center, FillRule::EvenOdd));
+        assert!(star.contains(tip, FillRule::NonZero));
+        assert!(star.contains(tip, FillRule::EvenOdd));
+    }
+
+    #[test]
+    fn on_outline() {
+        let square = |x: f32, y: f32| {
+            polygon(&[(x, y), (x + 10.0, y), (x + 10.0, y + 10.0), (x, y + 10.0)])
+        };
+        let squares =
+            [square(0.0, 0.0), square(10.0, 0.0), square(0.0, 10.0), square(10.0, 10.0)];
+
+        // points on a shared edge or corner are inside exactly one of the
+        // squares, so they are neither missed nor hit twice
+        for point in [(10.0, 5.0), (5.0, 10.0), (10.0, 10.0), (10.0, 15.0), (15.0, 10.0)]
+        {
+            let point = Point2::new(point.0, point.1);
+            let hits =
+                squares.iter().filter(|s| s.contains(point, FillRule::NonZero)).count();
+
+            assert_eq!(hits, 1, "{point:?}");
+        }
+
+        // while strokes hit every outline they are on
+        let on_corner = Point2::new(10.0, 10.0);
+
+        assert!(squares.iter().all(|s| s.hit_stroke(on_corner, 0.0, 0.0)));
+        assert_eq!(squares[0].distance_to(Point2::new(12.0, 5.0)), 2.0);
+        assert!(squares[0].hit_stroke(Point2::new(12.0, 5.0), 4.0, 0.0));
+        assert!(!squares[0].hit_stroke(Point2::new(12.0, 5.0), 2.0, 0.5));
+        // corners are round
+        assert!(!squares[0].hit_stroke(Point2::new(11.5, 11.5), 4.0, 0.0));
+    }
+}
@@ -1,14 +1,375 @@
+use std::cmp::Ordering;
+use std::fmt;
+
 use bytemuck::{Pod, Zeroable};
 
-use super::{Point2, Rect, Size2};
+use super::{
+    Insets,
+    Logical,
+    Physical,
+    Point2,
+    Polygon,
+    Rect,
+    ScaleFactor,
+    Shape,
+    Size2,
+    Transform,
+};
+use crate::math::{Num, One, Vec2, Zero};
 
-impl<T> Rect<T> {
+impl<T, U> Rect<T, U> {
     #[inline]
     #[must_use]
-    pub const fn new(loc: Point2<T>, size: Size2<T>) -> Self {
+    pub const fn new(loc: Point2<T, U>, size: Size2<T, U>) -> Self {
         Self { loc, size }
     }
+
+    /// Reinterpret the rectangle as being in another unit.
+    #[inline]
+    #[must_use]
+    pub fn cast_unit<V>(self) -> Rect<T, V> {
+        Rect::new(self.loc.cast_unit(), self.size.cast_unit())
+    }
+}
+
+impl<T: Num, U> Rect<T, U> {
+    /// The upper-left corner.
+    #[inline]
+    #[must_use]
+    pub fn min(self) -> Point2<T, U> {
+        self.loc
+    }
+
+    /// The lower-right corner.
+    #[inline]
+    #[must_use]
+    pub fn max(self) -> Point2<T, U> {
+        self.loc + Vec2::new(self.size.w, self.size.h)
+    }
+
+    /// Move the rectangle by a vector.
+    #[inline]
+    #[must_use]
+    pub fn translate(self, by: Vec2<T>) -> Self {
+        Self::new(self.loc + by, self.size)
+    }
+
+    /// Move each edge of the rectangle outwards, as when applying a margin.
+    ///
+    /// ## Panics
+    ///
+    /// For unsigned integers, panics in debug builds if the insets move the
+    /// rectangle's location below zero.
+    #[inline]
+    #[must_use]
+    pub fn outset_by(self, insets: Insets<T, U>) -> Self {
+        Self::new(self.loc - insets.offset(), self.size.grow_by(insets))
+    }
+}
+
+impl<T: Num + One, U> Rect<T, U> {
+    /// The point in the middle of the rectangle.
+    #[inline]
+    #[must_use]
+    pub fn center(self) -> Point2<T, U> {
+        let two = T::ONE + T::ONE;
+
+        self.loc + Vec2::new(self.size.w / two, self.size.h / two)
+    }
+}
+
+impl<T: Zero + PartialOrd, U> Rect<T, U> {
+    /// Whether the rectangle has no area.
+    #[inline]
+    #[must_use]
+    pub fn is_empty(self) -> bool {
+        !(self.size.w > T::ZERO && self.size.h > T::ZERO)
+    }
+
+    /// Move each edge of the rectangle inwards, as when applying padding.
+    ///
+    /// Insets larger than the rectangle leave it empty, see
+    /// [`Size2::shrink_by`].
+    #[inline]
+    #[must_use]
+    pub fn inset_by(self, insets: Insets<T, U>) -> Self {
+        Self::new(self.loc + insets.offset(), self.size.shrink_by(insets))
+    }
 }
 
-unsafe impl<T: Pod> Pod for Rect<T> {}
-unsafe impl<T: Zeroable> Zeroable for Rect<T> {}
+impl<T: Num + PartialOrd, U> Rect<T, U> {
+    /// The smallest rectangle containing both points.
+    ///
+    /// ```
+    /// # use crossd_graphics::geometry::{Point2, Rect, Size2};
+    /// #
+    /// let rect: Rect = Rect::from_points(Point2::new(4.0, 1.0), Point2::new(2.0, 3.0));
+    ///
+    /// assert_eq!(rect, Rect::new(Point2::new(2.0, 1.0), Size2::new(2.0, 2.0)));
+    /// ```
+    #[inline]
+    #[must_use]
+    pub fn from_points(a: Point2<T, U>, b: Point2<T, U>) -> Self {
+        let min = Point2::new(min(a.x, b.x), min(a.y, b.y));
+        let max = Point2::new(max(a.x, b.x), max(a.y, b.y));
+        let Vec2 { x: w, y: h } = max - min;
+
+        Self::new(min, Size2::new(w, h))
+    }
+
+    /// Whether the point is inside the rectangle or on it's edge.
+    #[inline]
+    #[must_use]
+    pub fn contains(self, point: Point2<T, U>) -> bool {
+        let (min, max) = (self.min(), self.max());
+
+        point.x >= min.x && point.x <= max.x && point.y >= min.y && point.y <= max.y
+    }
+
+    /// Whether the rectangles overlap.
+    ///
+    /// Rectangles that only share an edge don't overlap.
+    #[inline]
+    #[must_use]
+    pub fn intersects(self, other: Self) -> bool {
+        let (a_min, a_max) = (self.min(), self.max());
+        let (b_min, b_max) = (other.min(), other.max());
+
+        a_min.x < b_max.x && b_min.x < a_max.x && a_min.y < b_max.y && b_min.y < a_max.y
+    }
+
+    /// The area where the rectangles overlap, if any.
+    #[inline]
+    #[must_use]
+    pub fn intersection(self, other: Self) -> Option<Self> {
+        if !self.intersects(other) {
+            return None;
+        }
+
+        let (a_min, a_max) = (self.min(), self.max());
+        let (b_min, b_max) = (other.min(), other.max());
+
+        Some(Self::from_points(
+            Point2::new(max(a_min.x, b_min.x), max(a_min.y, b_min.y)),
+            Point2::new(min(a_max.x, b_max.x), min(a_max.y, b_max.y)),
+        ))
+    }
+
+    /// The smallest rectangle containing both rectangles.
+    #[inline]
+    #[must_use]
+    pub fn union(self, other: Self) -> Self {
+        let (a_min, a_max) = (self.min(), self.max());
+        let (b_min, b_max) = (other.min(), other.max());
+
+        Self::from_points(
+            Point2::new(min(a_min.x, b_min.x), min(a_min.y, b_min.y)),
+            Point2::new(max(a_max.x, b_max.x), max(a_max.y, b_max.y)),
+        )
+    }
+}
+
+impl<T: Num> Rect<T, Logical> {
+    /// Convert to physical pixels.
+    #[inline]
+    #[must_use]
+    pub fn to_physical(self, scale: ScaleFactor<T>) -> Rect<T, Physical> {
+        Rect::new(self.loc.to_physical(scale), self.size.to_physical(scale))
+    }
+}
+
+impl<T: Num> Rect<T, Physical> {
+    /// Convert to logical pixels.
+    #[inline]
+    #[must_use]
+    pub fn to_logical(self, scale: ScaleFactor<T>) -> Rect<T, Logical> {
+        Rect::new(self.loc.to_logical(scale), self.size.to_logical(scale))
+    }
+}
+
+impl Rect {
+    /// Apply a transformation to the rectangle.
+    ///
+    /// Rotated and skewed rectangles aren't axis-aligned, so this returns a
+    /// [`Polygon`] of the corners, clockwise on screen from the upper-left
+    /// one.
+    ///
+    /// ```
+    /// # use crossd_graphics::geometry::{Point2, Polygon, Rect, Size2, Transform};
+    /// # use crossd_graphics::math::Vec2;
+    /// #
+    /// let rect = Rect::new(Point2::new(0.0, 0.0), Size2::new(20.0, 10.0));
+    /// // shift each point right by it's `y`
+    /// let skew =
+    ///     Transform::new(Vec2::new(1.0, 0.0), Vec2::new(1.0, 1.0), Vec2::new(0.0, 0.0));
+    ///
+    /// assert_eq!(
+    ///     rect.transform(skew),
+    ///     Polygon::new(vec![
+    ///         Point2::new(0.0, 0.0),
+    ///         Point2::new(20.0, 0.0),
+    ///         Point2::new(30.0, 10.0),
+    ///         Point2::new(10.0, 10.0),
+    ///     ]),
+    /// );
+    /// ```
+    #[must_use]
+    pub fn transform(self, trans: Transform) -> Polygon {
+        let (min, max) = (self.min(), self.max());
+
+        [min, Point2::new(max.x, min.y), max, Point2::new(min.x, max.y)]
+            .into_iter()
+            .map(|point| trans.transform_point(point))
+            .collect()
+    }
+}
+
+impl Shape for Rect {
+    fn contains(&self, point: Point2) -> bool {
+        Rect::contains(*self, point)
+    }
+
+    fn bounds(&self) -> Rect {
+        *self
+    }
+
+    fn area(&self) -> f32 {
+        self.size.area()
+    }
+
+    fn perimeter(&self) -> f32 {
+        2.0 * (self.size.w + self.size.h)
+    }
+}
+
+// ------
+// traits
+// ------
+
+// implemented by hand so that the unit marker doesn't need to implement them
+
+impl<T: fmt::Debug, U> fmt::Debug for Rect<T, U> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("Rect").field("loc", &self.loc).field("size", &self.size).finish()
+    }
+}
+
+impl<T: Default, U> Default for Rect<T, U> {
+    fn default() -> Self {
+        Self::new(Point2::default(), Size2::default())
+    }
+}
+
+impl<T: Clone, U> Clone for Rect<T, U> {
+    fn clone(&self) -> Self {
+        Self::new(self.loc.clone(), self.size.clone())
+    }
+}
+
+impl<T: Copy, U> Copy for Rect<T, U> {}
+
+impl<T: PartialEq, U> PartialEq for Rect<T, U> {
+    fn eq(&self, other: &Self) -> bool {
+        self.loc == other.loc && self.size == other.size
+    }
+}
+
+impl<T: PartialOrd, U> PartialOrd for Rect<T, U> {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        match self.loc.partial_cmp(&other.loc) {
+            Some(Ordering::Equal) => self.size.partial_cmp(&other.size),
+            ord => ord,
+        }
+    }
+}
+
+unsafe impl<T: Pod, U: 'static> Pod for Rect<T, U> {}
+unsafe impl<T: Zeroable, U> Zeroable for Rect<T, U> {}
+
+/// The smaller of two values.
+fn min<T: PartialOrd>(a: T, b: T) -> T {
+    if b < a {
+        b
+    } else {
+        a
+    }
+}
+
+/// The larger of two values.
+fn max<T: PartialOrd>(a: T, b: T) -> T {
+    if b > a {
+        b
+    } else {
+        a
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use std::f32::consts::FRAC_PI_2;
+
+    use super::super::{Point2, Polygon, Rect, Shape, Size2, Transform};
+    use crate::math::Vec2;
+
+    fn rect(x: f32, y: f32, w: f32, h: f32) -> Rect {
+        Rect::new(Point2::new(x, y), Size2::new(w, h))
+    }
+
+    #[test]
+    fn measure() {
+        let rect = rect(1.0, 2.0, 4.0, 3.0);
+
+        assert_eq!(Shape::area(&rect), 12.0);
+        assert_eq!(rect.perimeter(), 14.0);
+        assert_eq!(rect.bounds(), rect);
+        assert_eq!(rect.max(), Point2::new(5.0, 5.0));
+        assert_eq!(rect.center(), Point2::new(3.0, 3.5));
+    }
+
+    #[test]
+    fn contains() {
+        let rect = rect(1.0, 2.0, 4.0, 3.0);
+
+        assert!(Shape::contains(&rect, Point2::new(3.0, 3.0)));
+        // on the edges and corners
+        assert!(Shape::contains(&rect, Point2::new(1.0, 3.0)));
+        assert!(Shape::contains(&rect, Point2::new(5.0, 5.0)));
+        assert!(!Shape::contains(&rect, Point2::new(5.1, 3.0)));
+        assert!(!Shape::contains(&rect, Point2::new(3.0, 1.9)));
+    }
+
+    #[test]
+    fn combine() {
+        let a = rect(0.0, 0.0, 4.0, 4.0);
+        let b = rect(2.0, 1.0, 4.0, 2.0);
+
+        assert_eq!(a.union(b), rect(0.0, 0.0, 6.0, 4.0));
+        assert_eq!(a.intersection(b), Some(rect(2.0, 1.0, 2.0, 2.0)));
+        assert_eq!(a.intersection(rect(5.0, 5.0, 1.0, 1.0)), None);
+        assert_eq!(Rect::from_points(a.max(), b.loc), rect(2.0, 1.0, 2.0, 3.0));
+    }
+
+    #[test]
+    fn transform() {
+        let rect = rect(1.0, 2.0, 4.0, 3.0);
+
+        let moved = rect.translate(Vec2::new(-1.0, 1.0));
+        assert_eq!(moved, self::rect(0.0, 3.0, 4.0, 3.0));
+
+        assert_eq!(
+            rect.transform(Transform::scale(2.0, 1.0)),
+            Polygon::new(vec![
+                Point2::new(2.0, 2.0),
+                Point2::new(10.0, 2.0),
+                Point2::new(10.0, 5.0),
+                Point2::new(2.0, 5.0),
+            ])
+        );
+
+        // a quarter turn keeps the area, and the bounds are turned too
+        let turned = rect.transform(Transform::rotation(FRAC_PI_2));
+        let bounds = turned.bounds().unwrap();
+        assert!((bounds.loc - Point2::new(-5.0, 1.0)).length() < 1e-5);
+        assert!((bounds.size.w - 3.0).abs() < 1e-5 && (bounds.size.h - 4.0).abs() < 1e-5);
+    }
+}
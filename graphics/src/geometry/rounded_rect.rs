@@ -0,0 +1,334 @@
+use std::f32::consts::{FRAC_PI_2, PI};
+
+use super::{CornerRadii, Point2, Polygon, Rect, RoundedRect, Shape, Size2, Transform};
+use crate::math::Vec2;
+
+impl RoundedRect {
+    /// A new rounded rectangle.
+    ///
+    /// The radii are not adjusted; see [`RoundedRect::radii`].
+    #[inline]
+    #[must_use]
+    pub const fn new(rect: Rect, radii: CornerRadii) -> Self {
+        Self { rect, radii }
+    }
+
+    /// A new rounded rectangle with the same radius on each corner.
+    #[inline]
+    #[must_use]
+    pub const fn uniform(rect: Rect, radius: f32) -> Self {
+        Self::new(rect, CornerRadii::uniform(radius))
+    }
+
+    /// The radii actually used for the corners.
+    ///
+    /// Like in CSS, if the radii of two adjacent corners add up to more than
+    /// the length of the edge between them, all radii are scaled down until
+    /// they fit. Negative radii are treated as zero.
+    #[must_use]
+    pub fn radii(self) -> CornerRadii {
+        let CornerRadii { top_left, top_right, bottom_right, bottom_left } =
+            self.radii.map(|r| r.max(0.0));
+        let Size2 { w, h, .. } = self.rect.size.map(|v| v.max(0.0));
+
+        let scale = [
+            w / (top_left + top_right),
+            h / (top_right + bottom_right),
+            w / (bottom_right + bottom_left),
+            h / (bottom_left + top_left),
+        ]
+        .into_iter()
+        .fold(1.0, f32::min);
+
+        CornerRadii::new(top_left, top_right, bottom_right, bottom_left)
+            .map(|r| r * scale)
+    }
+
+    /// Move the rectangle by a vector.
+    #[inline]
+    #[must_use]
+    pub fn translate(self, by: Vec2) -> Self {
+        Self::new(self.rect.translate(by), self.radii)
+    }
+
+    /// Scale the rectangle and it's radii around it's center.
+    #[must_use]
+    pub fn scale(self, by: f32) -> Self {
+        let by = by.abs();
+        let size = self.rect.size * by;
+        let loc = self.rect.center() - Vec2::new(size.w, size.h).scale(0.5);
+
+        Self::new(Rect::new(loc, size), self.radii.map(|r| r * by))
+    }
+
+    /// Apply a transformation to the rounded rectangle.
+    ///
+    /// Rotated and skewed corners aren't circular, so this returns a
+    /// [`Polygon`] of the outline, clockwise on screen from the top edge. The
+    /// corners are flattened into lines no further than `tolerance` from the
+    /// arcs, measured before transforming.
+    ///
+    /// ```
+    /// # use crossd_graphics::geometry::{Point2, Rect, RoundedRect, Size2, Transform};
+    /// # use crossd_graphics::math::Vec2;
+    /// #
+    /// let rect = Rect::new(Point2::new(0.0, 0.0), Size2::new(20.0, 10.0));
+    /// let rounded = RoundedRect::uniform(rect, 3.0);
+    /// let outline = rounded.transform(Transform::translation(Vec2::new(5.0, 0.0)), 0.1);
+    ///
+    /// assert_eq!(outline.bounds(), Some(rect.translate(Vec2::new(5.0, 0.0))));
+    /// ```
+    #[must_use]
+    pub fn transform(self, trans: Transform, tolerance: f32) -> Polygon {
+        let CornerRadii { top_left, top_right, bottom_right, bottom_left } = self.radii();
+        let (min, max) = (self.rect.min(), self.rect.max());
+
+        // each corner as it's radius, the center of it's circle and the angle it
+        // starts at
+        let corners = [
+            (top_right, Point2::new(max.x - top_right, min.y + top_right), -FRAC_PI_2),
+            (bottom_right, Point2::new(max.x - bottom_right, max.y - bottom_right), 0.0),
+            (
+                bottom_left,
+                Point2::new(min.x + bottom_left, max.y - bottom_left),
+                FRAC_PI_2,
+            ),
+            (top_left, Point2::new(min.x + top_left, min.y + top_left), PI),
+        ];
+        let mut points = Vec::new();
+
+        for (radius, center, start) in corners {
+            if radius <= 0.0 {
+                points.push(trans.transform_point(center));
+                continue;
+            }
+
+            // a chord of `step` radians is at most `radius * (1 - cos(step / 2))`
+            // from it's arc
+            let step = 2.0 * (1.0 - (tolerance / radius).clamp(0.0, 1.0)).acos();
+            let steps = ((FRAC_PI_2 / step).ceil().min(256.0) as usize).max(1);
+
+            points.extend((0..=steps).map(|i| {
+                let angle = start + FRAC_PI_2 * i as f32 / steps as f32;
+
+                trans.transform_point(
+                    center + Vec2::new(angle.cos(), angle.sin()).scale(radius),
+                )
+            }));
+        }
+
+        Polygon::new(points)
+    }
+}
+
+impl Shape for RoundedRect {
+    fn contains(&self, point: Point2) -> bool {
+        if !self.rect.contains(point) {
+            return false;
+        }
+
+        let CornerRadii { top_left, top_right, bottom_right, bottom_left } = self.radii();
+        let (min, max) = (self.rect.min(), self.rect.max());
+
+        // the center of each corner's circle and the direction of the corner from it
+        let corners = [
+            (
+                top_left,
+                Point2::new(min.x + top_left, min.y + top_left),
+                Vec2::new(-1.0, -1.0),
+            ),
+            (
+                top_right,
+                Point2::new(max.x - top_right, min.y + top_right),
+                Vec2::new(1.0, -1.0),
+            ),
+            (
+                bottom_right,
+                Point2::new(max.x - bottom_right, max.y - bottom_right),
+                Vec2::new(1.0, 1.0),
+            ),
+            (
+                bottom_left,
+                Point2::new(min.x + bottom_left, max.y - bottom_left),
+                Vec2::new(-1.0, 1.0),
+            ),
+        ];
+
+        corners.into_iter().all(|(radius, center, dir)| {
+            let d = point - center;
+            // only points between the circle and the corner are outside
+            let in_corner = d.x * dir.x > 0.0 && d.y * dir.y > 0.0;
+
+            !in_corner || d.dot(d) <= radius * radius
+        })
+    }
+
+    fn bounds(&self) -> Rect {
+        self.rect
+    }
+
+    fn area(&self) -> f32 {
+        // each corner removes the difference between a square and a quarter circle
+        let corners: f32 = self.radii().to_array().iter().map(|r| r * r).sum();
+
+        self.rect.size.area() - (1.0 - PI / 4.0) * corners
+    }
+
+    fn perimeter(&self) -> f32 {
+        // each corner replaces two straight edges with a quarter circle
+        let corners: f32 = self.radii().to_array().iter().sum();
+        let Size2 { w, h, .. } = self.rect.size;
+
+        2.0 * (w + h) - (2.0 - PI / 2.0) * corners
+    }
+}
+
+impl CornerRadii {
+    /// Radii with each corner set to zero.
+    pub const ZERO: Self = Self::uniform(0.0);
+
+    /// New radii, clockwise from the upper-left corner.
+    #[inline]
+    #[must_use]
+    pub const fn new(
+        top_left: f32,
+        top_right: f32,
+        bottom_right: f32,
+        bottom_left: f32,
+    ) -> Self {
+        Self { top_left, top_right, bottom_right, bottom_left }
+    }
+
+    /// Radii with each corner set to `radius`.
+    #[inline]
+    #[must_use]
+    pub const fn uniform(radius: f32) -> Self {
+        Self::new(radius, radius, radius, radius)
+    }
+
+    /// Transform each radius with a callback.
+    #[inline]
+    #[must_use]
+    pub fn map(self, f: impl FnMut(f32) -> f32) -> Self {
+        let [top_left, top_right, bottom_right, bottom_left] = self.to_array().map(f);
+
+        Self::new(top_left, top_right, bottom_right, bottom_left)
+    }
+
+    /// Convert to `[top_left, top_right, bottom_right, bottom_left]`.
+    #[inline]
+    #[must_use]
+    pub const fn to_array(self) -> [f32; 4] {
+        [self.top_left, self.top_right, self.bottom_right, self.bottom_left]
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use std::f32::consts::{FRAC_PI_2, PI};
+
+    use super::super::{CornerRadii, Point2, Rect, RoundedRect, Shape, Size2, Transform};
+    use crate::math::Vec2;
+
+    fn rect(w: f32, h: f32) -> Rect {
+        Rect::new(Point2::new(0.0, 0.0), Size2::new(w, h))
+    }
+
+    #[test]
+    fn radii() {
+        let radii = CornerRadii::new(1.0, 2.0, 3.0, 4.0);
+        assert_eq!(RoundedRect::new(rect(20.0, 20.0), radii).radii(), radii);
+
+        // the left edge needs `4 + 1 = 5` but only has 2, scaling all radii by 0.4
+        let scaled = RoundedRect::new(rect(20.0, 2.0), radii).radii();
+        let expected = radii.map(|r| r * 0.4);
+        for (a, b) in scaled.to_array().into_iter().zip(expected.to_array()) {
+            assert!((a - b).abs() < 1e-6, "{scaled:?} != {expected:?}");
+        }
+
+        // a pill shape
+        let pill = RoundedRect::uniform(rect(20.0, 10.0), 100.0).radii();
+        assert_eq!(pill, CornerRadii::uniform(5.0));
+
+        let negative =
+            RoundedRect::new(rect(10.0, 10.0), CornerRadii::new(-1.0, 2.0, 2.0, 2.0));
+        assert_eq!(negative.radii(), CornerRadii::new(0.0, 2.0, 2.0, 2.0));
+    }
+
+    #[test]
+    fn measure() {
+        let sharp = RoundedRect::uniform(rect(20.0, 10.0), 0.0);
+        assert_eq!(sharp.area(), 200.0);
+        assert_eq!(sharp.perimeter(), 60.0);
+        assert_eq!(sharp.bounds(), rect(20.0, 10.0));
+
+        let rounded = RoundedRect::uniform(rect(20.0, 10.0), 2.0);
+        assert!((rounded.area() - (200.0 - 4.0 * 4.0 + 4.0 * PI)).abs() < 1e-4);
+        assert!((rounded.perimeter() - (60.0 - 16.0 + 4.0 * PI)).abs() < 1e-4);
+
+        // a circle
+        let circle = RoundedRect::uniform(rect(10.0, 10.0), 5.0);
+        assert!((circle.area() - 25.0 * PI).abs() < 1e-4);
+        assert!((circle.perimeter() - 10.0 * PI).abs() < 1e-4);
+
+        // overflowing radii are measured as scaled down
+        let pill = RoundedRect::uniform(rect(20.0, 10.0), 100.0);
+        assert!((pill.area() - (100.0 + 25.0 * PI)).abs() < 1e-4);
+    }
+
+    #[test]
+    fn contains() {
+        let rounded =
+            RoundedRect::new(rect(20.0, 10.0), CornerRadii::new(4.0, 0.0, 2.0, 0.0));
+
+        assert!(rounded.contains(Point2::new(10.0, 5.0)));
+        // cut off by the upper-left corner, but not the upper-right one
+        assert!(!rounded.contains(Point2::new(0.5, 0.5)));
+        assert!(rounded.contains(Point2::new(19.9, 0.1)));
+        // on the arc
+        let on_arc =
+            Point2::new(4.0, 4.0) + Vec2::new(-1.0, -1.0).scale(4.0 / 2.0_f32.sqrt());
+        assert!(rounded.contains(on_arc + Vec2::splat(0.01)));
+        assert!(!rounded.contains(on_arc - Vec2::splat(0.01)));
+        // outside the rectangle
+        assert!(!rounded.contains(Point2::new(10.0, 10.5)));
+    }
+
+    #[test]
+    fn transform() {
+        let rounded = RoundedRect::uniform(rect(20.0, 10.0), 3.0);
+
+        let moved = rounded.translate(Vec2::new(1.0, 2.0));
+        assert_eq!(moved.rect.loc, Point2::new(1.0, 2.0));
+        assert_eq!(moved.radii, rounded.radii);
+
+        let scaled = rounded.scale(2.0);
+        assert_eq!(
+            scaled.rect,
+            Rect::new(Point2::new(-10.0, -5.0), Size2::new(40.0, 20.0))
+        );
+        assert_eq!(scaled.radii, CornerRadii::uniform(6.0));
+
+        // the outline is within the tolerance of the arcs
+        let tolerance = 0.05;
+        let outline = rounded.transform(Transform::IDENTITY, tolerance);
+        let area = outline
+            .edges()
+            .map(|edge| edge.start.to_vec2().cross(edge.end.to_vec2()))
+            .sum::<f32>()
+            / 2.0;
+        assert!(area > 0.0);
+        assert!((area - rounded.area()).abs() < tolerance * rounded.perimeter());
+        // with it's points on them
+        let grown = rounded.scale(1.001);
+        assert!(outline.points.iter().all(|&point| grown.contains(point)));
+
+        // a quarter turn around the origin
+        let turned = rounded.transform(Transform::rotation(FRAC_PI_2), tolerance);
+        let bounds = turned.bounds().unwrap();
+        assert!((bounds.loc - Point2::new(-10.0, 0.0)).length() < 1e-4);
+        assert!(
+            (bounds.size.w - 10.0).abs() < 1e-4 && (bounds.size.h - 20.0).abs() < 1e-4
+        );
+    }
+}
@@ -0,0 +1,87 @@
+use super::ScaleFactor;
+use crate::math::One;
+
+impl<T> ScaleFactor<T> {
+    /// A new scale factor of `v` physical pixels per logical pixel.
+    #[inline]
+    #[must_use]
+    pub const fn new(v: T) -> Self {
+        Self(v)
+    }
+}
+
+impl<T: Copy> ScaleFactor<T> {
+    /// The amount of physical pixels per logical pixel.
+    #[inline]
+    #[must_use]
+    pub const fn get(self) -> T {
+        self.0
+    }
+}
+
+impl<T: One> ScaleFactor<T> {
+    /// A scale factor where logical and physical pixels are the same size.
+    pub const ONE: Self = Self::new(T::ONE);
+}
+
+impl<T: One> Default for ScaleFactor<T> {
+    fn default() -> Self {
+        Self::ONE
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::super::{Logical, Physical, Point2, Rect, ScaleFactor, Size2};
+
+    const SCALES: [f32; 4] = [1.0, 1.25, 1.5, 2.0];
+
+    #[test]
+    fn points() {
+        let point: Point2<f32, Logical> = Point2::new(10.0, -4.0);
+
+        for scale in SCALES.map(ScaleFactor::new) {
+            let physical = point.to_physical(scale);
+
+            assert_eq!(physical, Point2::new(10.0 * scale.get(), -4.0 * scale.get()));
+            assert_eq!(physical.to_logical(scale), point);
+        }
+
+        let physical: Point2<f32, Physical> = Point2::new(3.0, 9.0);
+        assert_eq!(physical.to_logical(ScaleFactor::new(1.5)), Point2::new(2.0, 6.0));
+    }
+
+    #[test]
+    fn sizes() {
+        let size: Size2<f32, Logical> = Size2::new(400.0, 300.0);
+
+        for scale in SCALES.map(ScaleFactor::new) {
+            let physical = size.to_physical(scale);
+
+            assert_eq!(physical, Size2::new(400.0 * scale.get(), 300.0 * scale.get()));
+            assert_eq!(physical.to_logical(scale), size);
+        }
+
+        let physical: Size2<f32, Physical> = Size2::new(1920.0, 1080.0);
+        assert_eq!(physical.to_logical(ScaleFactor::new(2.0)), Size2::new(960.0, 540.0));
+    }
+
+    #[test]
+    fn rects() {
+        let rect: Rect<f32, Logical> =
+            Rect::new(Point2::new(8.0, 6.0), Size2::new(20.0, 12.0));
+
+        for scale in SCALES.map(ScaleFactor::new) {
+            let physical = rect.to_physical(scale);
+
+            assert_eq!(physical.loc, rect.loc.to_physical(scale));
+            assert_eq!(physical.size, rect.size.to_physical(scale));
+            assert_eq!(physical.to_logical(scale), rect);
+        }
+
+        assert_eq!(
+            rect.to_physical(ScaleFactor::new(1.5)),
+            Rect::new(Point2::new(12.0, 9.0), Size2::new(30.0, 18.0))
+        );
+    }
+}
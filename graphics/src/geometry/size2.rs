@@ -1,28 +1,48 @@
-use std::ops::{Add, Sub};
+use std::cmp::Ordering;
+use std::fmt;
+use std::marker::PhantomData;
+use std::ops::{Add, Div, DivAssign, Mul, MulAssign, Sub};
 
 use bytemuck::{Pod, Zeroable};
 use crossd_math::Float;
 
-use super::Size2;
-use crate::math::{One, Zero};
+use super::{Anchor, FitMode, Insets, Logical, Physical, Rect, ScaleFactor, Size2};
+use crate::math::{Num, One, Vec2, Zero};
 
-impl<T> Size2<T> {
+impl<T, U> Size2<T, U> {
     #[must_use]
     #[inline(always)]
     pub const fn new(w: T, h: T) -> Self {
-        Self { w, h }
+        Self { w, h, _unit: PhantomData }
+    }
+
+    /// Transform a `Size2<T>` -> `Size2<V>` with a callback called on each
+    /// component.
+    #[inline]
+    #[must_use]
+    pub fn map<V>(self, f: impl FnMut(T) -> V) -> Size2<V, U> {
+        let mut f = f;
+
+        Size2::new(f(self.w), f(self.h))
+    }
+
+    /// Reinterpret the size as being in another unit.
+    #[inline]
+    #[must_use]
+    pub fn cast_unit<V>(self) -> Size2<T, V> {
+        Size2::new(self.w, self.h)
     }
 }
 
-impl<T: Copy> Size2<T> {
+impl<T: Copy, U> Size2<T, U> {
     #[must_use]
     #[inline(always)]
     pub const fn splat(v: T) -> Self {
-        Self { w: v, h: v }
+        Self::new(v, v)
     }
 }
 
-impl<T> Size2<T>
+impl<T, U> Size2<T, U>
 where
     T: Add<Output = T>,
 {
@@ -45,7 +65,7 @@ where
     }
 }
 
-impl<T> Size2<T>
+impl<T, U> Size2<T, U>
 where
     T: Sub<Output = T>,
 {
@@ -68,29 +88,193 @@ where
     }
 }
 
-impl<T: Zero> Size2<T> {
+impl<T: Num, U> Size2<T, U> {
+    /// Extend the size by the space taken up by the insets.
+    ///
+    /// See [`Size2::extend`].
+    #[inline]
+    #[must_use]
+    pub fn grow_by(self, insets: Insets<T, U>) -> Self {
+        self.extend(insets.size())
+    }
+
+    /// The width multiplied by the height.
+    #[inline]
+    #[must_use]
+    pub fn area(self) -> T {
+        self.w * self.h
+    }
+
+    /// The width divided by the height.
+    ///
+    /// For integers this is truncated; convert to floats with [`Size2::map`]
+    /// first to get an exact ratio.
+    ///
+    /// ## Panics
+    ///
+    /// For integers, panics if the height is zero.
+    #[inline]
+    #[must_use]
+    pub fn aspect_ratio(self) -> T {
+        self.w / self.h
+    }
+}
+
+impl<T: Num + PartialOrd, U> Size2<T, U> {
+    /// The smaller of each component.
+    ///
+    /// ```
+    /// # use crossd_graphics::geometry::Size2;
+    /// #
+    /// let a: Size2<f32> = Size2::new(1.0, 4.0);
+    /// let b: Size2<f32> = Size2::new(3.0, 2.0);
+    ///
+    /// assert_eq!(a.min(b), Size2::new(1.0, 2.0));
+    /// assert_eq!(a.max(b), Size2::new(3.0, 4.0));
+    /// ```
+    #[inline]
+    #[must_use]
+    pub fn min(self, other: Self) -> Self {
+        let w = if other.w < self.w { other.w } else { self.w };
+        let h = if other.h < self.h { other.h } else { self.h };
+
+        Self::new(w, h)
+    }
+
+    /// The larger of each component.
+    #[inline]
+    #[must_use]
+    pub fn max(self, other: Self) -> Self {
+        let w = if other.w > self.w { other.w } else { self.w };
+        let h = if other.h > self.h { other.h } else { self.h };
+
+        Self::new(w, h)
+    }
+}
+
+impl<T: Zero + PartialOrd, U> Size2<T, U> {
+    /// Reduce the size by the space taken up by the insets.
+    ///
+    /// Each component stops at zero, so insets larger than the size leave it
+    /// empty instead of negative, or wrapped around for unsigned integers.
+    ///
+    /// ```
+    /// # use crossd_graphics::geometry::{Insets, Size2};
+    /// #
+    /// let size: Size2 = Size2::new(10, 4);
+    ///
+    /// assert_eq!(size.shrink_by(Insets::symmetric(1, 2)), Size2::new(6, 2));
+    /// assert_eq!(size.shrink_by(Insets::symmetric(3, 2)), Size2::new(6, 0));
+    /// ```
+    #[inline]
+    #[must_use]
+    pub fn shrink_by(self, insets: Insets<T, U>) -> Self {
+        let Size2 { w, h, .. } = insets.size();
+        let saturating_sub = |v: T, by: T| if v > by { v - by } else { T::ZERO };
+
+        Self::new(saturating_sub(self.w, w), saturating_sub(self.h, h))
+    }
+}
+
+impl<U> Size2<f32, U> {
+    /// Fit this size into a container, centered.
+    ///
+    /// See [`Size2::fit_aligned`].
+    #[inline]
+    #[must_use]
+    pub fn fit(self, container: Rect<f32, U>, mode: FitMode) -> Rect<f32, U> {
+        self.fit_aligned(container, mode, Anchor::CENTER)
+    }
+
+    /// Fit this size into a container, placing it using `anchor`.
+    ///
+    /// The result may overflow the container when using [`FitMode::Cover`] or
+    /// [`FitMode::None`].
+    ///
+    /// ```
+    /// # use crossd_graphics::geometry::{Anchor, FitMode, Point2, Rect, Size2};
+    /// #
+    /// let image: Size2<f32> = Size2::new(200.0, 100.0);
+    /// let frame = Rect::new(Point2::new(0.0, 0.0), Size2::new(100.0, 100.0));
+    ///
+    /// assert_eq!(
+    ///     image.fit(frame, FitMode::Contain),
+    ///     Rect::new(Point2::new(0.0, 25.0), Size2::new(100.0, 50.0)),
+    /// );
+    /// assert_eq!(
+    ///     image.fit_aligned(frame, FitMode::Cover, Anchor::LEFT),
+    ///     Rect::new(Point2::new(0.0, 0.0), Size2::new(200.0, 100.0)),
+    /// );
+    /// ```
+    #[must_use]
+    pub fn fit_aligned(
+        self,
+        container: Rect<f32, U>,
+        mode: FitMode,
+        anchor: Anchor,
+    ) -> Rect<f32, U> {
+        let scale_x = container.size.w / self.w;
+        let scale_y = container.size.h / self.h;
+        // an empty size can't be scaled to fit anything
+        let empty = self.w == 0.0 || self.h == 0.0;
+
+        let size = match mode {
+            // stretching doesn't scale, so empty sizes fill too
+            FitMode::Fill => container.size,
+            _ if empty => self,
+            FitMode::Contain => self * scale_x.min(scale_y),
+            FitMode::Cover => self * scale_x.max(scale_y),
+            FitMode::ScaleDown => self * scale_x.min(scale_y).min(1.0),
+            FitMode::None => self,
+        };
+        let free = container.size - size;
+        let loc = container.loc + Vec2::new(free.w * anchor.x, free.h * anchor.y);
+
+        Rect::new(loc, size)
+    }
+}
+
+impl<T: Zero, U> Size2<T, U> {
     /// A size with width and height set to `T::ZERO`.
     pub const ZERO: Self = Self::splat(T::ZERO);
 }
 
-impl<T: One> Size2<T> {
+impl<T: One, U> Size2<T, U> {
     /// A size with width and height set to `T::ONE`.
     pub const ONE: Self = Self::splat(T::ONE);
 }
 
-impl<T: Float> Size2<T> {
+impl<T: Float, U> Size2<T, U> {
     /// Round to the nearest integer.
     pub fn snap(self) -> Self {
         Self::new(self.w.snap(), self.h.snap())
     }
 
     /// Round to the nearest integer and convert to an integer.
-    pub fn round(self) -> Size2<T::Int> {
+    pub fn round(self) -> Size2<T::Int, U> {
         Size2::new(self.w.round(), self.h.round())
     }
 }
 
-impl<T> Add for Size2<T>
+impl<T: Num> Size2<T, Logical> {
+    /// Convert to physical pixels.
+    #[inline]
+    #[must_use]
+    pub fn to_physical(self, scale: ScaleFactor<T>) -> Size2<T, Physical> {
+        Size2::new(self.w * scale.0, self.h * scale.0)
+    }
+}
+
+impl<T: Num> Size2<T, Physical> {
+    /// Convert to logical pixels.
+    #[inline]
+    #[must_use]
+    pub fn to_logical(self, scale: ScaleFactor<T>) -> Size2<T, Logical> {
+        Size2::new(self.w / scale.0, self.h / scale.0)
+    }
+}
+
+impl<T, U> Add for Size2<T, U>
 where
     T: Add<Output = T>,
 {
@@ -101,7 +285,7 @@ where
     }
 }
 
-impl<T> Sub for Size2<T>
+impl<T, U> Sub for Size2<T, U>
 where
     T: Sub<Output = T>,
 {
@@ -112,5 +296,160 @@ where
     }
 }
 
-unsafe impl<T: Pod> Pod for Size2<T> {}
-unsafe impl<T: Zeroable> Zeroable for Size2<T> {}
+/// Scale each component.
+impl<T: Num, U> Mul<T> for Size2<T, U> {
+    type Output = Self;
+
+    fn mul(self, rhs: T) -> Self::Output {
+        self.map(|v| v * rhs)
+    }
+}
+
+/// Scale each component by the inverse.
+impl<T: Num, U> Div<T> for Size2<T, U> {
+    type Output = Self;
+
+    fn div(self, rhs: T) -> Self::Output {
+        self.map(|v| v / rhs)
+    }
+}
+
+impl<T: Num, U> MulAssign<T> for Size2<T, U> {
+    fn mul_assign(&mut self, rhs: T) {
+        *self = *self * rhs
+    }
+}
+
+impl<T: Num, U> DivAssign<T> for Size2<T, U> {
+    fn div_assign(&mut self, rhs: T) {
+        *self = *self / rhs
+    }
+}
+
+// ------
+// traits
+// ------
+
+// implemented by hand so that the unit marker doesn't need to implement them
+
+impl<T: fmt::Debug, U> fmt::Debug for Size2<T, U> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("Size2").field("w", &self.w).field("h", &self.h).finish()
+    }
+}
+
+impl<T: Default, U> Default for Size2<T, U> {
+    fn default() -> Self {
+        Self::new(T::default(), T::default())
+    }
+}
+
+impl<T: Clone, U> Clone for Size2<T, U> {
+    fn clone(&self) -> Self {
+        Self::new(self.w.clone(), self.h.clone())
+    }
+}
+
+impl<T: Copy, U> Copy for Size2<T, U> {}
+
+impl<T: PartialEq, U> PartialEq for Size2<T, U> {
+    fn eq(&self, other: &Self) -> bool {
+        self.w == other.w && self.h == other.h
+    }
+}
+
+impl<T: PartialOrd, U> PartialOrd for Size2<T, U> {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        match self.w.partial_cmp(&other.w) {
+            Some(Ordering::Equal) => self.h.partial_cmp(&other.h),
+            ord => ord,
+        }
+    }
+}
+
+unsafe impl<T: Pod, U: 'static> Pod for Size2<T, U> {}
+unsafe impl<T: Zeroable, U> Zeroable for Size2<T, U> {}
+
+#[cfg(test)]
+mod test {
+    use super::super::{Anchor, FitMode, Point2, Rect, Size2};
+
+    fn rect(x: f32, y: f32, w: f32, h: f32) -> Rect {
+        Rect::new(Point2::new(x, y), Size2::new(w, h))
+    }
+
+    #[test]
+    fn fit_modes() {
+        let container = rect(10.0, 20.0, 100.0, 50.0);
+        let fit = |w: f32, h: f32, mode| Size2::new(w, h).fit(container, mode);
+
+        // wider than the container
+        assert_eq!(fit(400.0, 100.0, FitMode::Contain), rect(10.0, 32.5, 100.0, 25.0));
+        assert_eq!(fit(400.0, 100.0, FitMode::Cover), rect(-40.0, 20.0, 200.0, 50.0));
+        assert_eq!(fit(400.0, 100.0, FitMode::Fill), container);
+        assert_eq!(fit(400.0, 100.0, FitMode::ScaleDown), rect(10.0, 32.5, 100.0, 25.0));
+        assert_eq!(fit(400.0, 100.0, FitMode::None), rect(-140.0, -5.0, 400.0, 100.0));
+
+        // smaller than the container
+        assert_eq!(fit(10.0, 10.0, FitMode::Contain), rect(35.0, 20.0, 50.0, 50.0));
+        assert_eq!(fit(10.0, 10.0, FitMode::Cover), rect(10.0, -5.0, 100.0, 100.0));
+        assert_eq!(fit(10.0, 10.0, FitMode::Fill), container);
+        assert_eq!(fit(10.0, 10.0, FitMode::ScaleDown), rect(55.0, 40.0, 10.0, 10.0));
+        assert_eq!(fit(10.0, 10.0, FitMode::None), rect(55.0, 40.0, 10.0, 10.0));
+    }
+
+    #[test]
+    fn fit_empty() {
+        let container = rect(0.0, 0.0, 100.0, 50.0);
+
+        // stretched like any other size
+        assert_eq!(Size2::new(0.0, 0.0).fit(container, FitMode::Fill), container);
+        assert_eq!(Size2::new(10.0, 0.0).fit(container, FitMode::Fill), container);
+
+        // kept as it is, rather than scaled by infinity
+        for mode in [FitMode::Contain, FitMode::Cover, FitMode::ScaleDown, FitMode::None]
+        {
+            assert_eq!(
+                Size2::new(10.0, 0.0).fit(container, mode),
+                rect(45.0, 25.0, 10.0, 0.0),
+                "{mode:?}"
+            );
+            assert_eq!(Size2::ZERO.fit(container, mode), rect(50.0, 25.0, 0.0, 0.0));
+        }
+    }
+
+    #[test]
+    fn anchors() {
+        let container = rect(0.0, 0.0, 100.0, 100.0);
+        let size: Size2<f32> = Size2::new(20.0, 40.0);
+        let at = |anchor| size.fit_aligned(container, FitMode::None, anchor).loc;
+
+        assert_eq!(at(Anchor::TOP_LEFT), Point2::new(0.0, 0.0));
+        assert_eq!(at(Anchor::TOP), Point2::new(40.0, 0.0));
+        assert_eq!(at(Anchor::TOP_RIGHT), Point2::new(80.0, 0.0));
+        assert_eq!(at(Anchor::LEFT), Point2::new(0.0, 30.0));
+        assert_eq!(at(Anchor::CENTER), Point2::new(40.0, 30.0));
+        assert_eq!(at(Anchor::RIGHT), Point2::new(80.0, 30.0));
+        assert_eq!(at(Anchor::BOTTOM_LEFT), Point2::new(0.0, 60.0));
+        assert_eq!(at(Anchor::BOTTOM), Point2::new(40.0, 60.0));
+        assert_eq!(at(Anchor::BOTTOM_RIGHT), Point2::new(80.0, 60.0));
+        assert_eq!(at(Anchor::new(0.25, 0.75)), Point2::new(20.0, 45.0));
+
+        // overflowing sizes are aligned the same way
+        let cover = Size2::new(200.0, 100.0);
+        assert_eq!(
+            cover.fit_aligned(container, FitMode::Cover, Anchor::RIGHT),
+            rect(-100.0, 0.0, 200.0, 100.0)
+        );
+        assert_eq!(
+            cover.fit_aligned(container, FitMode::Cover, Anchor::CENTER),
+            rect(-50.0, 0.0, 200.0, 100.0)
+        );
+
+        // filling leaves no room to align in
+        assert_eq!(
+            size.fit_aligned(container, FitMode::Fill, Anchor::BOTTOM_RIGHT),
+            container
+        );
+    }
+}
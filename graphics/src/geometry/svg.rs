@@ -0,0 +1,527 @@
+use std::str::FromStr;
+
+use super::{ParsePathError, Path, PathBuilder, PathEl, Point2};
+use crate::math::Vec2;
+
+impl Path {
+    /// Parse SVG path data, as found in the `d` attribute of a `<path>`.
+    ///
+    /// All commands are supported in both their absolute and relative forms.
+    /// Arcs are converted to cubic Bézier curves.
+    ///
+    /// ```
+    /// # use crossd_graphics::geometry::{Path, PathEl, Point2};
+    /// #
+    /// let path = Path::from_svg("M10 10 h 5 v5 H10 z").unwrap();
+    ///
+    /// assert_eq!(
+    ///     path.elements(),
+    ///     &[
+    ///         PathEl::MoveTo(Point2::new(10.0, 10.0)),
+    ///         PathEl::LineTo(Point2::new(15.0, 10.0)),
+    ///         PathEl::LineTo(Point2::new(15.0, 15.0)),
+    ///         PathEl::LineTo(Point2::new(10.0, 15.0)),
+    ///         PathEl::Close,
+    ///     ]
+    /// );
+    /// ```
+    pub fn from_svg(data: &str) -> Result<Self, ParsePathError> {
+        let mut parser = Parser { data, pos: 0 };
+        let mut builder = PathBuilder::new();
+        // the last command, repeated when it's arguments are repeated
+        let mut last = None;
+        // the last control point of the previous curve, reflected by `S` and `T`
+        let mut cubic_ctrl = None;
+        let mut quad_ctrl = None;
+
+        while let Some(byte) = parser.peek() {
+            let pos = parser.pos;
+
+            let command = match last {
+                // implicit repetition of the previous command
+                Some(command) if parser.at_number() => match command {
+                    b'M' => b'L',
+                    b'm' => b'l',
+                    b'Z' | b'z' => return Err(ParsePathError::ExpectedCommand(pos)),
+                    command => command,
+                },
+                _ if COMMANDS.contains(&byte) => {
+                    parser.pos += 1;
+                    byte
+                },
+                _ => {
+                    return Err(match parser.data[pos..].chars().next() {
+                        Some(c) if c.is_alphabetic() => {
+                            ParsePathError::UnknownCommand(pos, c)
+                        },
+                        _ => ParsePathError::ExpectedCommand(pos),
+                    })
+                },
+            };
+
+            if last.is_none() && !matches!(command, b'M' | b'm') {
+                return Err(ParsePathError::ExpectedMove(pos));
+            }
+
+            let current = builder.current();
+            let origin =
+                if command.is_ascii_lowercase() { current.to_vec2() } else { Vec2::ZERO };
+            let (prev_cubic, prev_quad) = (cubic_ctrl.take(), quad_ctrl.take());
+
+            builder = match command.to_ascii_uppercase() {
+                b'M' => builder.move_to(parser.point(origin)?),
+                b'L' => builder.line_to(parser.point(origin)?),
+                b'H' => {
+                    builder.line_to(Point2::new(origin.x + parser.number()?, current.y))
+                },
+                b'V' => {
+                    builder.line_to(Point2::new(current.x, origin.y + parser.number()?))
+                },
+                b'C' => {
+                    let ctrl1 = parser.point(origin)?;
+                    let ctrl2 = parser.point(origin)?;
+                    let to = parser.point(origin)?;
+                    cubic_ctrl = Some(ctrl2);
+
+                    builder.cubic_to(ctrl1, ctrl2, to)
+                },
+                b'S' => {
+                    let ctrl1 = prev_cubic.map_or(current, |ctrl| reflect(ctrl, current));
+                    let ctrl2 = parser.point(origin)?;
+                    let to = parser.point(origin)?;
+                    cubic_ctrl = Some(ctrl2);
+
+                    builder.cubic_to(ctrl1, ctrl2, to)
+                },
+                b'Q' => {
+                    let ctrl = parser.point(origin)?;
+                    let to = parser.point(origin)?;
+                    quad_ctrl = Some(ctrl);
+
+                    builder.quad_to(ctrl, to)
+                },
+                b'T' => {
+                    let ctrl = prev_quad.map_or(current, |ctrl| reflect(ctrl, current));
+                    let to = parser.point(origin)?;
+                    quad_ctrl = Some(ctrl);
+
+                    builder.quad_to(ctrl, to)
+                },
+                b'A' => {
+                    let radii = Vec2::new(parser.number()?, parser.number()?);
+                    let rotation = parser.number()?.to_radians();
+                    let large_arc = parser.flag()?;
+                    let sweep = parser.flag()?;
+                    let to = parser.point(origin)?;
+
+                    builder.arc_to(radii, rotation, large_arc, sweep, to)
+                },
+                _ => builder.close(),
+            };
+
+            last = Some(command);
+        }
+
+        Ok(builder.build())
+    }
+
+    /// Serialize to compact SVG path data.
+    ///
+    /// Only absolute commands are used, and numbers are written so that
+    /// parsing the result with [`Path::from_svg`] gives back the same path.
+    ///
+    /// ```
+    /// # use crossd_graphics::geometry::{Path, Point2};
+    /// #
+    /// let path = Path::builder()
+    ///     .move_to(Point2::new(0.0, 0.0))
+    ///     .line_to(Point2::new(10.0, -5.0))
+    ///     .line_to(Point2::new(10.0, 10.5))
+    ///     .close()
+    ///     .build();
+    ///
+    /// assert_eq!(path.to_svg(), "M0 0 10-5 10 10.5Z");
+    /// assert_eq!(Path::from_svg(&path.to_svg()), Ok(path));
+    /// ```
+    #[must_use]
+    pub fn to_svg(&self) -> String {
+        let mut writer = Writer::default();
+
+        for el in self {
+            match *el {
+                PathEl::MoveTo(p) => writer.command(b'M').point(p),
+                PathEl::LineTo(p) => writer.command(b'L').point(p),
+                PathEl::QuadTo(p1, p2) => writer.command(b'Q').point(p1).point(p2),
+                PathEl::CubicTo(p1, p2, p3) => {
+                    writer.command(b'C').point(p1).point(p2).point(p3)
+                },
+                PathEl::Close => writer.command(b'Z'),
+            };
+        }
+
+        writer.out
+    }
+}
+
+impl FromStr for Path {
+    type Err = ParsePathError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Self::from_svg(s)
+    }
+}
+
+/// The letters of all path commands.
+const COMMANDS: &[u8] = b"MmLlHhVvCcSsQqTtAaZz";
+
+/// A cursor over SVG path data.
+struct Parser<'a> {
+    data: &'a str,
+    /// The byte position of the cursor.
+    pos: usize,
+}
+
+impl Parser<'_> {
+    /// Skip whitespace and commas, then return the next byte.
+    fn peek(&mut self) -> Option<u8> {
+        let bytes = self.data.as_bytes();
+
+        while bytes
+            .get(self.pos)
+            .is_some_and(|byte| byte.is_ascii_whitespace() || *byte == b',')
+        {
+            self.pos += 1;
+        }
+
+        bytes.get(self.pos).copied()
+    }
+
+    /// Whether the next argument is a number.
+    fn at_number(&mut self) -> bool {
+        matches!(self.peek(), Some(b'0'..=b'9' | b'+' | b'-' | b'.'))
+    }
+
+    /// Parse the next number.
+    fn number(&mut self) -> Result<f32, ParsePathError> {
+        self.peek();
+
+        let bytes = self.data.as_bytes();
+        let start = self.pos;
+        let digits = |pos: &mut usize| {
+            let from = *pos;
+
+            while bytes.get(*pos).is_some_and(u8::is_ascii_digit) {
+                *pos += 1;
+            }
+
+            *pos > from
+        };
+
+        let mut end = start;
+
+        if matches!(bytes.get(end), Some(b'+' | b'-')) {
+            end += 1;
+        }
+
+        let mut has_digits = digits(&mut end);
+
+        if bytes.get(end) == Some(&b'.') {
+            end += 1;
+            has_digits |= digits(&mut end);
+        }
+
+        if !has_digits {
+            return Err(ParsePathError::ExpectedNumber(start));
+        }
+
+        // only treat `e` as an exponent if it's followed by one
+        if matches!(bytes.get(end), Some(b'e' | b'E')) {
+            let mut exp = end + 1;
+
+            if matches!(bytes.get(exp), Some(b'+' | b'-')) {
+                exp += 1;
+            }
+
+            if digits(&mut exp) {
+                end = exp;
+            }
+        }
+
+        self.pos = end;
+
+        self.data[start..end].parse().map_err(|_| ParsePathError::ExpectedNumber(start))
+    }
+
+    /// Parse the next arc flag, which doesn't need to be separated from the
+    /// next argument.
+    fn flag(&mut self) -> Result<bool, ParsePathError> {
+        let flag = match self.peek() {
+            Some(b'0') => false,
+            Some(b'1') => true,
+            _ => return Err(ParsePathError::ExpectedFlag(self.pos)),
+        };
+
+        self.pos += 1;
+
+        Ok(flag)
+    }
+
+    /// Parse the next pair of numbers as a point relative to `origin`.
+    fn point(&mut self, origin: Vec2) -> Result<Point2, ParsePathError> {
+        let x = self.number()?;
+        let y = self.number()?;
+
+        Ok(Point2::from_vec2(origin + Vec2::new(x, y)))
+    }
+}
+
+/// A builder for compact SVG path data.
+#[derive(Default)]
+struct Writer {
+    out: String,
+    /// The command that is implied if none is written.
+    implied: Option<u8>,
+}
+
+impl Writer {
+    /// Write a command, unless it is implied by the previous one.
+    fn command(&mut self, command: u8) -> &mut Self {
+        if self.implied != Some(command) {
+            self.out.push(command as char);
+        }
+
+        // a move is implicitly followed by lines
+        self.implied = Some(if command == b'M' { b'L' } else { command });
+
+        self
+    }
+
+    /// Write a point's coordinates.
+    fn point(&mut self, point: Point2) -> &mut Self {
+        self.number(point.x).number(point.y)
+    }
+
+    /// Write a number, separating it from a previous number if needed.
+    fn number(&mut self, number: f32) -> &mut Self {
+        let number = number.to_string();
+
+        if !number.starts_with('-') && self.out.ends_with(|c: char| c.is_ascii_digit()) {
+            self.out.push(' ');
+        }
+
+        self.out.push_str(&number);
+
+        self
+    }
+}
+
+/// Reflect a control point about the current point, for smooth curves.
+fn reflect(ctrl: Point2, current: Point2) -> Point2 {
+    current + (current - ctrl)
+}
+
+#[cfg(test)]
+mod test {
+    use super::super::{ParsePathError, Path, PathEl, Point2};
+
+    fn p(x: f32, y: f32) -> Point2 {
+        Point2::new(x, y)
+    }
+
+    fn parse(data: &str) -> Vec<PathEl> {
+        Path::from_svg(data).unwrap().elements().to_vec()
+    }
+
+    /// Assert that absolute and relative data parse to `expected`.
+    #[track_caller]
+    fn assert_both(absolute: &str, relative: &str, expected: &[PathEl]) {
+        assert_eq!(parse(absolute), expected, "{absolute:?}");
+        assert_eq!(parse(relative), expected, "{relative:?}");
+    }
+
+    #[test]
+    fn lines() {
+        use PathEl::*;
+
+        assert_both(
+            "M1 2 L4 6",
+            "m1 2 l3 4",
+            &[MoveTo(p(1.0, 2.0)), LineTo(p(4.0, 6.0))],
+        );
+        assert_both("M1 2 H5", "m1 2 h4", &[MoveTo(p(1.0, 2.0)), LineTo(p(5.0, 2.0))]);
+        assert_both("M1 2 V-3", "m1 2 v-5", &[MoveTo(p(1.0, 2.0)), LineTo(p(1.0, -3.0))]);
+        assert_both(
+            "M1 2 L3 2 Z",
+            "m1 2 l2 0 z",
+            &[MoveTo(p(1.0, 2.0)), LineTo(p(3.0, 2.0)), Close],
+        );
+
+        // relative commands after a close start from the start of the subpath
+        assert_both(
+            "M1 2 H3 Z M1 2 L1 5",
+            "m1 2 h2 z m0 0 l0 3",
+            &[
+                MoveTo(p(1.0, 2.0)),
+                LineTo(p(3.0, 2.0)),
+                Close,
+                MoveTo(p(1.0, 2.0)),
+                LineTo(p(1.0, 5.0)),
+            ],
+        );
+    }
+
+    #[test]
+    fn curves() {
+        use PathEl::*;
+
+        assert_both(
+            "M1 1 C2 0 4 0 5 1",
+            "m1 1 c1 -1 3 -1 4 0",
+            &[MoveTo(p(1.0, 1.0)), CubicTo(p(2.0, 0.0), p(4.0, 0.0), p(5.0, 1.0))],
+        );
+        assert_both(
+            "M1 1 Q3 -1 5 1",
+            "m1 1 q2 -2 4 0",
+            &[MoveTo(p(1.0, 1.0)), QuadTo(p(3.0, -1.0), p(5.0, 1.0))],
+        );
+
+        // the first control point reflects the previous one
+        assert_both(
+            "M0 0 C1 -1 2 -1 3 0 S5 1 6 0",
+            "m0 0 c1 -1 2 -1 3 0 s2 1 3 0",
+            &[
+                MoveTo(p(0.0, 0.0)),
+                CubicTo(p(1.0, -1.0), p(2.0, -1.0), p(3.0, 0.0)),
+                CubicTo(p(4.0, 1.0), p(5.0, 1.0), p(6.0, 0.0)),
+            ],
+        );
+        assert_both(
+            "M0 0 Q1 -1 2 0 T4 0 T6 0",
+            "m0 0 q1 -1 2 0 t2 0 t2 0",
+            &[
+                MoveTo(p(0.0, 0.0)),
+                QuadTo(p(1.0, -1.0), p(2.0, 0.0)),
+                QuadTo(p(3.0, 1.0), p(4.0, 0.0)),
+                QuadTo(p(5.0, -1.0), p(6.0, 0.0)),
+            ],
+        );
+    }
+
+    #[test]
+    fn smooth_without_previous_curve() {
+        use PathEl::*;
+
+        // the control point is the current point
+        assert_both(
+            "M0 0 L1 0 S2 1 3 0",
+            "m0 0 l1 0 s1 1 2 0",
+            &[
+                MoveTo(p(0.0, 0.0)),
+                LineTo(p(1.0, 0.0)),
+                CubicTo(p(1.0, 0.0), p(2.0, 1.0), p(3.0, 0.0)),
+            ],
+        );
+        assert_both(
+            "M1 0 T3 0",
+            "m1 0 t2 0",
+            &[MoveTo(p(1.0, 0.0)), QuadTo(p(1.0, 0.0), p(3.0, 0.0))],
+        );
+
+        // nor is a curve of the other kind reflected
+        assert_eq!(
+            parse("M0 0 Q1 1 2 0 S3 1 4 0")[2],
+            CubicTo(p(2.0, 0.0), p(3.0, 1.0), p(4.0, 0.0))
+        );
+        assert_eq!(parse("M0 0 C1 1 1 1 2 0 T4 0")[2], QuadTo(p(2.0, 0.0), p(4.0, 0.0)));
+    }
+
+    #[test]
+    fn arcs() {
+        let absolute = parse("M0 0 A1 1 0 0 1 1 1");
+
+        assert_eq!(absolute, parse("m0 0 a1 1 0 0 1 1 1"));
+        assert!(
+            matches!(absolute[1..], [PathEl::CubicTo(_, _, to)] if to == p(1.0, 1.0))
+        );
+
+        // flags don't need separators
+        assert_eq!(parse("M0 0 a1 1 0 011 1"), absolute);
+        assert_eq!(parse("M0 0a1,1,0,0,1,1,1"), absolute);
+        assert_eq!(parse("M0 0 a1 1 0 111 1"), parse("M0 0 A1 1 0 1 1 1 1"));
+
+        // the flags pick one of four arcs
+        let arcs = ["00", "01", "10", "11"]
+            .map(|flags| parse(&format!("M0 0 a1 1 0 {flags}1 1")));
+
+        for (i, a) in arcs.iter().enumerate() {
+            assert!(arcs[i + 1..].iter().all(|b| a != b), "{arcs:?}");
+        }
+    }
+
+    #[test]
+    fn implicit_commands() {
+        use PathEl::*;
+
+        // lines after a move
+        assert_eq!(
+            parse("M0 0 1 1 2 0"),
+            [MoveTo(p(0.0, 0.0)), LineTo(p(1.0, 1.0)), LineTo(p(2.0, 0.0))]
+        );
+        assert_eq!(
+            parse("m1 1 2 2-1 0"),
+            [MoveTo(p(1.0, 1.0)), LineTo(p(3.0, 3.0)), LineTo(p(2.0, 3.0))]
+        );
+
+        // and other commands repeating
+        assert_eq!(parse("M0 0 h1 2"), parse("M0 0 h1 h2"));
+        assert_eq!(parse("M0 0 Q1 1 2 0 3 -1 4 0"), parse("M0 0 Q1 1 2 0 Q3 -1 4 0"));
+        assert_eq!(
+            parse("M0 0 c1 1 2 1 3 0 1 1 2 1 3 0"),
+            parse("M0 0 c1 1 2 1 3 0 c1 1 2 1 3 0")
+        );
+
+        // numbers without separators
+        assert_eq!(parse("M.5.5L-1-1"), [MoveTo(p(0.5, 0.5)), LineTo(p(-1.0, -1.0))]);
+        assert_eq!(parse("M1e1-1E-1 0 0"), [MoveTo(p(10.0, -0.1)), LineTo(p(0.0, 0.0))]);
+    }
+
+    #[test]
+    fn errors() {
+        use ParsePathError::*;
+
+        assert_eq!(Path::from_svg("L0 0"), Err(ExpectedMove(0)));
+        assert_eq!(Path::from_svg("  1 2"), Err(ExpectedCommand(2)));
+        assert_eq!(Path::from_svg("M0 0 X1 1"), Err(UnknownCommand(5, 'X')));
+        assert_eq!(Path::from_svg("M0 0 Lé"), Err(ExpectedNumber(6)));
+        assert_eq!(Path::from_svg("M0 0 é"), Err(UnknownCommand(5, 'é')));
+        assert_eq!(Path::from_svg("M0 0 #"), Err(ExpectedCommand(5)));
+        assert_eq!(Path::from_svg("M0 0 L1e"), Err(ExpectedNumber(7)));
+        assert_eq!(Path::from_svg("M0 0 L1 ,"), Err(ExpectedNumber(9)));
+        assert_eq!(Path::from_svg("M0 0 L.-1"), Err(ExpectedNumber(6)));
+        assert_eq!(Path::from_svg("M0 0 A1 1 0 2 1 1 1"), Err(ExpectedFlag(12)));
+        assert_eq!(Path::from_svg("M0 0 Z 1 1"), Err(ExpectedCommand(7)));
+        assert_eq!(Path::from_svg(""), Ok(Path::builder().build()));
+    }
+
+    #[test]
+    fn to_svg() {
+        let path = |data: &str| Path::from_svg(data).unwrap();
+
+        // only commands that aren't implied are written
+        assert_eq!(path("M0 0 L1 1 L2 0").to_svg(), "M0 0 1 1 2 0");
+        assert_eq!(path("M0 0 Q1 1 2 0 Q3 -1 4 0").to_svg(), "M0 0Q1 1 2 0 3-1 4 0");
+        assert_eq!(path("m0 0 c1 1 2 1 3 0 l1 0 z").to_svg(), "M0 0C1 1 2 1 3 0L4 0Z");
+        assert_eq!(path("M-1 -.5 h.25 z M2 2 H3").to_svg(), "M-1-0.5-0.75-0.5ZM2 2 3 2");
+
+        for data in [
+            "M0 0 L10 -5 L10 10.5 Z",
+            "m1 2 c1 -1 3 -1 4 0 s2 1 3 0 q1 1 2 0 t2 0 z m5 5 h-1e-3 v1e3",
+            "M10 80 A45 45 0 0 0 125 125 A30 50 -45 1 1 215 210",
+            "M0.1 0.2 L0.3 0.7 L1e-7 -3.4028235e38",
+        ] {
+            let path = path(data);
+
+            assert_eq!(Path::from_svg(&path.to_svg()), Ok(path.clone()), "{data:?}");
+        }
+    }
+}
@@ -0,0 +1,201 @@
+//! The plane is cut into horizontal slabs at every vertex and every crossing
+//! of two edges. Within a slab no edges cross, so they can be ordered from
+//! left to right and the winding number is constant between each pair of
+//! neighbouring edges. Each run of edges enclosing an inside area is a
+//! trapezoid.
+
+use super::Point2;
+
+/// The amount of polygon sets (operands) that can be swept together.
+pub(crate) const OPERANDS: usize = 2;
+
+/// A horizontal trapezoid, with it's top and bottom edges parallel to the `x`
+/// axis.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub(crate) struct Trapezoid {
+    /// The `y` of the top edge.
+    pub top: f32,
+    /// The `y` of the bottom edge.
+    pub bottom: f32,
+    /// The `x` of the left side, at the top and bottom.
+    pub left: [f32; 2],
+    /// The `x` of the right side, at the top and bottom.
+    pub right: [f32; 2],
+}
+
+/// Reusable state for decomposing polygons into [`Trapezoid`]s.
+#[derive(Debug, Default, Clone)]
+pub(crate) struct Sweep {
+    edges: Vec<Edge>,
+    /// The `y` of every vertex.
+    ys: Vec<f32>,
+    /// Edges spanning the current slab.
+    active: Vec<usize>,
+}
+
+/// A non-horizontal polygon edge.
+#[derive(Debug, Clone, Copy)]
+struct Edge {
+    /// The end with the smallest `y`.
+    top: Point2,
+    /// The end with the largest `y`.
+    bottom: Point2,
+    /// `1` if the edge goes down, `-1` if it goes up.
+    winding: i32,
+    /// The polygon set the edge is part of.
+    operand: usize,
+}
+
+impl Sweep {
+    /// Remove all polygons.
+    pub fn clear(&mut self) {
+        self.edges.clear();
+    }
+
+    /// Add a closed polygon to a set of polygons.
+    pub fn add_polygon(&mut self, points: &[Point2], operand: usize) {
+        let next = points.iter().cycle().skip(1);
+
+        for (from, to) in points.iter().zip(next) {
+            let finite = [from.x, from.y, to.x, to.y].iter().all(|v| v.is_finite());
+
+            // horizontal edges don't change the winding number of any slab
+            if !finite || from.y == to.y {
+                continue;
+            }
+
+            let edge = if from.y < to.y {
+                Edge { top: *from, bottom: *to, winding: 1, operand }
+            } else {
+                Edge { top: *to, bottom: *from, winding: -1, operand }
+            };
+
+            self.edges.push(edge);
+        }
+    }
+
+    /// Decompose the area where `inside` holds for the winding numbers of each
+    /// polygon set into trapezoids, ordered top to bottom and then left to
+    /// right.
+    pub fn trapezoids(
+        &mut self,
+        inside: impl Fn([i32; OPERANDS]) -> bool,
+        mut emit: impl FnMut(Trapezoid),
+    ) {
+        self.edges.sort_by(|a, b| a.top.y.total_cmp(&b.top.y));
+
+        self.ys.clear();
+        self.ys.extend(self.edges.iter().flat_map(|e| [e.top.y, e.bottom.y]));
+        self.ys.sort_by(f32::total_cmp);
+        self.ys.dedup();
+
+        self.active.clear();
+        let mut next = 0;
+
+        for i in 1..self.ys.len() {
+            let (mut top, bottom) = (self.ys[i - 1], self.ys[i]);
+
+            while self.edges.get(next).is_some_and(|e| e.top.y <= top) {
+                self.active.push(next);
+                next += 1;
+            }
+
+            let edges = &self.edges;
+            self.active.retain(|i| edges[*i].bottom.y > top);
+
+            // crossings closer than this to the ends of a slab are ignored
+            let epsilon = (bottom - top) * 1e-5;
+
+            while top < bottom {
+                let slab_bottom = self.split(top, bottom, epsilon);
+                self.emit_slab(top, slab_bottom, &inside, &mut emit);
+                top = slab_bottom;
+            }
+        }
+    }
+
+    /// Order the active edges from left to right and find the bottom of the
+    /// slab starting at `top`, above where any of them cross.
+    fn split(&mut self, top: f32, mut bottom: f32, epsilon: f32) -> f32 {
+        let edges = &self.edges;
+
+        loop {
+            let mid = (top + bottom) / 2.0;
+            self.active
+                .sort_by(|a, b| edges[*a].x_at(mid).total_cmp(&edges[*b].x_at(mid)));
+
+            // the first crossing is always between neighbours
+            let crossing = self
+                .active
+                .windows(2)
+                .filter_map(|pair| {
+                    let (a, b) = (edges[pair[0]], edges[pair[1]]);
+                    let gap = |y| b.x_at(y) - a.x_at(y);
+                    let (top_gap, mid_gap, bottom_gap) =
+                        (gap(top), gap(mid), gap(bottom));
+
+                    if top_gap < 0.0 {
+                        Some(top + (mid - top) * top_gap / (top_gap - mid_gap))
+                    } else if bottom_gap < 0.0 {
+                        Some(mid + (bottom - mid) * mid_gap / (mid_gap - bottom_gap))
+                    } else {
+                        None
+                    }
+                })
+                .filter(|y| *y > top + epsilon && *y < bottom - epsilon)
+                .min_by(f32::total_cmp);
+
+            match crossing {
+                Some(y) => bottom = y,
+                None => return bottom,
+            }
+        }
+    }
+
+    /// Emit the trapezoids of a slab whose active edges are ordered.
+    fn emit_slab(
+        &self,
+        top: f32,
+        bottom: f32,
+        inside: &impl Fn([i32; OPERANDS]) -> bool,
+        emit: &mut impl FnMut(Trapezoid),
+    ) {
+        let mut winding = [0; OPERANDS];
+        let mut left = None;
+
+        for edge in self.active.iter().map(|i| self.edges[*i]) {
+            let was_inside = inside(winding);
+            winding[edge.operand] += edge.winding;
+            let is_inside = inside(winding);
+
+            if !was_inside && is_inside {
+                left = Some(edge);
+            } else if was_inside && !is_inside {
+                if let Some(left) = left.take() {
+                    emit(Trapezoid {
+                        top,
+                        bottom,
+                        left: [left.x_at(top), left.x_at(bottom)],
+                        right: [edge.x_at(top), edge.x_at(bottom)],
+                    });
+                }
+            }
+        }
+    }
+}
+
+impl Edge {
+    /// The `x` of the edge at `y`.
+    fn x_at(self, y: f32) -> f32 {
+        // exact at the ends so that trapezoids share vertices
+        if y <= self.top.y {
+            self.top.x
+        } else if y >= self.bottom.y {
+            self.bottom.x
+        } else {
+            let t = (y - self.top.y) / (self.bottom.y - self.top.y);
+
+            self.top.x + (self.bottom.x - self.top.x) * t
+        }
+    }
+}
@@ -0,0 +1,218 @@
+use super::{Point2, Transform};
+use crate::math::{Mat4, Vec2};
+
+impl Transform {
+    /// The transformation that does nothing.
+    pub const IDENTITY: Self = Self::new(Vec2::X, Vec2::Y, Vec2::ZERO);
+
+    /// A new transformation from it's columns.
+    #[inline]
+    #[must_use]
+    pub const fn new(x: Vec2, y: Vec2, translation: Vec2) -> Self {
+        Self { x, y, translation }
+    }
+
+    /// A transformation that moves points by `by`.
+    #[inline]
+    #[must_use]
+    pub const fn translation(by: Vec2) -> Self {
+        Self::new(Vec2::X, Vec2::Y, by)
+    }
+
+    /// A transformation that scales around the origin.
+    #[inline]
+    #[must_use]
+    pub const fn scale(x: f32, y: f32) -> Self {
+        Self::new(Vec2::new(x, 0.0), Vec2::new(0.0, y), Vec2::ZERO)
+    }
+
+    /// A transformation that rotates around the origin by `angle` radians.
+    ///
+    /// Positive angles rotate from the `x` axis towards the `y` axis, which is
+    /// clockwise on screen where `y` points down.
+    #[inline]
+    #[must_use]
+    pub fn rotation(angle: f32) -> Self {
+        let (sin, cos) = angle.sin_cos();
+
+        Self::new(Vec2::new(cos, sin), Vec2::new(-sin, cos), Vec2::ZERO)
+    }
+
+    /// Apply this transformation and then `next`.
+    ///
+    /// ```
+    /// # use crossd_graphics::geometry::{Point2, Transform};
+    /// # use crossd_graphics::math::Vec2;
+    /// #
+    /// let trans =
+    ///     Transform::scale(2.0, 2.0).then(Transform::translation(Vec2::new(1.0, 0.0)));
+    /// let point: Point2 = Point2::new(1.0, 1.0);
+    ///
+    /// assert_eq!(trans.transform_point(point), Point2::new(3.0, 2.0));
+    /// ```
+    #[inline]
+    #[must_use]
+    pub fn then(self, next: Self) -> Self {
+        Self::new(
+            next.transform_vector(self.x),
+            next.transform_vector(self.y),
+            next.transform_vector(self.translation) + next.translation,
+        )
+    }
+
+    /// The determinant of the linear part of the transformation.
+    ///
+    /// This is the factor areas are scaled by; it is negative if the
+    /// transformation flips the orientation of shapes.
+    #[inline]
+    #[must_use]
+    pub fn determinant(self) -> f32 {
+        self.x.cross(self.y)
+    }
+
+    /// The transformation that undoes this one, if any.
+    ///
+    /// Returns `None` if the transformation collapses the plane onto a line or
+    /// a point.
+    #[must_use]
+    pub fn inverse(self) -> Option<Self> {
+        let det = self.determinant();
+
+        if det == 0.0 || !det.is_finite() {
+            return None;
+        }
+
+        let x = Vec2::new(self.y.y, -self.x.y).scale(1.0 / det);
+        let y = Vec2::new(-self.y.x, self.x.x).scale(1.0 / det);
+        let translation =
+            (x.scale(self.translation.x) + y.scale(self.translation.y)).scale(-1.0);
+
+        Some(Self::new(x, y, translation))
+    }
+
+    /// Transform a point.
+    #[inline]
+    #[must_use]
+    pub fn transform_point<U>(self, point: Point2<f32, U>) -> Point2<f32, U> {
+        Point2::from_vec2(self.transform_vector(point.to_vec2()) + self.translation)
+    }
+
+    /// Transform a vector, ignoring the translation.
+    #[inline]
+    #[must_use]
+    pub fn transform_vector(self, vec2: Vec2) -> Vec2 {
+        self.x.scale(vec2.x) + self.y.scale(vec2.y)
+    }
+
+    /// Convert to a 4x4 matrix for use in shaders.
+    #[inline]
+    #[must_use]
+    pub const fn to_mat4(self) -> Mat4 {
+        let Self { x, y, translation: t } = self;
+
+        Mat4::new(
+            [x.x, x.y, 0.0, 0.0],
+            [y.x, y.y, 0.0, 0.0],
+            [0.0, 0.0, 1.0, 0.0],
+            [t.x, t.y, 0.0, 1.0],
+        )
+    }
+}
+
+impl Default for Transform {
+    fn default() -> Self {
+        Self::IDENTITY
+    }
+}
+
+impl From<Transform> for Mat4 {
+    fn from(trans: Transform) -> Self {
+        trans.to_mat4()
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use std::f32::consts::FRAC_PI_2;
+
+    use super::super::{Point2, Transform};
+    use crate::math::{Mat4, Vec2};
+
+    fn assert_close(a: Point2, b: Point2) {
+        assert!((a - b).length() < 1e-5, "{a:?} != {b:?}");
+    }
+
+    #[test]
+    fn compose() {
+        let scale = Transform::scale(2.0, 3.0);
+        let rotate = Transform::rotation(FRAC_PI_2);
+        let translate = Transform::translation(Vec2::new(1.0, -1.0));
+        let point = Point2::new(1.0, 1.0);
+
+        // `then` applies `self` first
+        let trans = scale.then(rotate).then(translate);
+        let step = translate
+            .transform_point(rotate.transform_point(scale.transform_point(point)));
+        assert_close(trans.transform_point(point), step);
+        assert_close(trans.transform_point(point), Point2::new(-2.0, 1.0));
+
+        // the order matters
+        assert_close(translate.then(scale).transform_point(point), Point2::new(4.0, 0.0));
+
+        // and composing is associative
+        let grouped = scale.then(rotate.then(translate));
+        assert_close(grouped.transform_point(point), trans.transform_point(point));
+
+        assert_eq!(Transform::IDENTITY.then(trans), trans);
+        assert_eq!(trans.then(Transform::IDENTITY), trans);
+        assert_eq!(trans.determinant(), 6.0);
+    }
+
+    #[test]
+    fn inverse() {
+        let trans = Transform::new(
+            Vec2::new(2.0, 1.0),
+            Vec2::new(1.0, 3.0),
+            Vec2::new(4.0, -2.0),
+        );
+        let inverse = trans.inverse().unwrap();
+
+        for point in
+            [Point2::new(0.0, 0.0), Point2::new(1.0, 2.0), Point2::new(-7.0, 3.5)]
+        {
+            assert_close(inverse.transform_point(trans.transform_point(point)), point);
+            assert_close(trans.transform_point(inverse.transform_point(point)), point);
+        }
+
+        assert_close(
+            trans.then(inverse).transform_point(Point2::new(5.0, 6.0)),
+            Point2::new(5.0, 6.0),
+        );
+        assert!((inverse.determinant() * trans.determinant() - 1.0).abs() < 1e-6);
+
+        // collapsing onto a line or a point can't be undone
+        assert_eq!(Transform::scale(1.0, 0.0).inverse(), None);
+        assert_eq!(
+            Transform::new(Vec2::new(1.0, 2.0), Vec2::new(2.0, 4.0), Vec2::ZERO)
+                .inverse(),
+            None
+        );
+    }
+
+    #[test]
+    fn to_mat4() {
+        let trans =
+            Transform::new(Vec2::new(1.0, 2.0), Vec2::new(3.0, 4.0), Vec2::new(5.0, 6.0));
+
+        assert_eq!(
+            Mat4::from(trans),
+            Mat4::new(
+                [1.0, 2.0, 0.0, 0.0],
+                [3.0, 4.0, 0.0, 0.0],
+                [0.0, 0.0, 1.0, 0.0],
+                [5.0, 6.0, 0.0, 1.0],
+            )
+        );
+        assert_eq!(Transform::default(), Transform::IDENTITY);
+    }
+}
@@ -0,0 +1,151 @@
+use super::{Line, Point2, Rect, Shape, Transform, Triangle};
+use crate::math::Vec2;
+
+impl Triangle {
+    /// A new triangle from it's corners.
+    #[inline]
+    #[must_use]
+    pub const fn new(a: Point2, b: Point2, c: Point2) -> Self {
+        Self { a, b, c }
+    }
+
+    /// The edges of the triangle, `ab`, `bc` and `ca`.
+    #[inline]
+    #[must_use]
+    pub fn edges(self) -> [Line; 3] {
+        [Line::new(self.a, self.b), Line::new(self.b, self.c), Line::new(self.c, self.a)]
+    }
+
+    /// Twice the signed area of the triangle.
+    ///
+    /// Positive when the corners go from the `x` axis towards the `y` axis
+    /// (clockwise on screen where `y` points down).
+    #[inline]
+    #[must_use]
+    pub fn signed_area2(self) -> f32 {
+        (self.b - self.a).cross(self.c - self.a)
+    }
+
+    /// The average of the corners.
+    #[inline]
+    #[must_use]
+    pub fn centroid(self) -> Point2 {
+        let sum = self.a.to_vec2() + self.b.to_vec2() + self.c.to_vec2();
+
+        Point2::from_vec2(sum.scale(1.0 / 3.0))
+    }
+
+    /// Move the triangle by a vector.
+    #[inline]
+    #[must_use]
+    pub fn translate(self, by: Vec2) -> Self {
+        Self::new(self.a + by, self.b + by, self.c + by)
+    }
+
+    /// Apply a transformation to the triangle.
+    #[inline]
+    #[must_use]
+    pub fn transform(self, trans: Transform) -> Self {
+        Self::new(
+            trans.transform_point(self.a),
+            trans.transform_point(self.b),
+            trans.transform_point(self.c),
+        )
+    }
+}
+
+impl Shape for Triangle {
+    fn contains(&self, point: Point2) -> bool {
+        // a flat triangle is the same as it's edges
+        if self.signed_area2() == 0.0 {
+            return self.edges().iter().any(|edge| edge.contains(point));
+        }
+
+        let ab = (self.b - self.a).cross(point - self.a);
+        let bc = (self.c - self.b).cross(point - self.b);
+        let ca = (self.a - self.c).cross(point - self.c);
+
+        // inside if the point is on the same side of each edge
+        (ab >= 0.0 && bc >= 0.0 && ca >= 0.0) || (ab <= 0.0 && bc <= 0.0 && ca <= 0.0)
+    }
+
+    fn bounds(&self) -> Rect {
+        Rect::from_points(self.a, self.b).union(Rect::from_points(self.c, self.c))
+    }
+
+    fn area(&self) -> f32 {
+        self.signed_area2().abs() / 2.0
+    }
+
+    fn perimeter(&self) -> f32 {
+        self.edges().iter().map(|edge| edge.length()).sum()
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::super::{Point2, Rect, Shape, Size2, Transform, Triangle};
+    use crate::math::Vec2;
+
+    fn triangle() -> Triangle {
+        Triangle::new(Point2::new(0.0, 0.0), Point2::new(4.0, 0.0), Point2::new(0.0, 3.0))
+    }
+
+    #[test]
+    fn measure() {
+        let triangle = triangle();
+
+        assert_eq!(triangle.signed_area2(), 12.0);
+        assert_eq!(triangle.area(), 6.0);
+        assert_eq!(triangle.perimeter(), 12.0);
+        assert_eq!(
+            triangle.bounds(),
+            Rect::new(Point2::new(0.0, 0.0), Size2::new(4.0, 3.0))
+        );
+
+        // the other way around
+        let flipped = Triangle::new(triangle.a, triangle.c, triangle.b);
+        assert_eq!(flipped.signed_area2(), -12.0);
+        assert_eq!(flipped.area(), 6.0);
+    }
+
+    #[test]
+    fn contains() {
+        for triangle in
+            [triangle(), Triangle::new(triangle().c, triangle().b, triangle().a)]
+        {
+            assert!(triangle.contains(Point2::new(1.0, 1.0)));
+            // on an edge and a corner
+            assert!(triangle.contains(Point2::new(2.0, 1.5)));
+            assert!(triangle.contains(Point2::new(4.0, 0.0)));
+            assert!(!triangle.contains(Point2::new(2.1, 1.6)));
+            assert!(!triangle.contains(Point2::new(-0.1, 1.0)));
+        }
+
+        let flat = Triangle::new(
+            Point2::new(0.0, 0.0),
+            Point2::new(1.0, 1.0),
+            Point2::new(2.0, 2.0),
+        );
+        assert!(flat.contains(Point2::new(1.5, 1.5)));
+        assert!(!flat.contains(Point2::new(1.5, 1.0)));
+    }
+
+    #[test]
+    fn transform() {
+        let triangle = triangle();
+
+        assert_eq!(triangle.centroid(), Point2::new(4.0 / 3.0, 1.0));
+        let moved = triangle.translate(Vec2::new(1.0, 1.0)).centroid();
+        assert!((moved - Point2::new(7.0 / 3.0, 2.0)).length() < 1e-6);
+
+        // areas scale by the determinant
+        let trans = Transform::scale(2.0, -3.0);
+        let scaled = triangle.transform(trans);
+        assert_eq!(scaled.signed_area2(), triangle.signed_area2() * trans.determinant());
+        assert_eq!(
+            scaled.bounds(),
+            Rect::new(Point2::new(0.0, -9.0), Size2::new(8.0, 9.0))
+        );
+    }
+}
@@ -0,0 +1,294 @@
+// the pipelines aren't used until `Graphics::prepare` and `Graphics::render`
+// are implemented
+#![allow(dead_code)]
+
+use std::collections::HashMap;
+
+use wgpu::{
+    BindGroup,
+    BindGroupDescriptor,
+    BindGroupEntry,
+    BindGroupLayout,
+    BindGroupLayoutDescriptor,
+    BindGroupLayoutEntry,
+    BindingResource,
+    BindingType,
+    BlendState,
+    ColorTargetState,
+    ColorWrites,
+    CommandEncoder,
+    Extent3d,
+    FragmentState,
+    FrontFace,
+    MultisampleState,
+    PipelineLayout,
+    PipelineLayoutDescriptor,
+    PrimitiveState,
+    PrimitiveTopology,
+    RenderPipeline,
+    RenderPipelineDescriptor,
+    ShaderModule,
+    ShaderModuleDescriptor,
+    ShaderSource,
+    ShaderStages,
+    Texture,
+    TextureDescriptor,
+    TextureDimension,
+    TextureSampleType,
+    TextureUsages,
+    TextureViewDescriptor,
+    TextureViewDimension,
+    VertexBufferLayout,
+    VertexState,
+};
+
+use crate::backend::Backend;
+use crate::color::{BlendMode, PorterDuff};
+
+/// How draws with a [`BlendMode`] are blended with the target.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Blending {
+    /// By the blend state of the pipeline alone.
+    State(BlendState),
+    /// In the shader, reading a [`Backdrop`] copied from the target before
+    /// the draw.
+    Backdrop,
+}
+
+/// Render pipelines for one shader, built the first time each [`Blending`]
+/// is used.
+pub struct Pipelines {
+    /// Label of the pipelines.
+    label: &'static str,
+    module: ShaderModule,
+    /// Layout of pipelines blending by state.
+    layout: PipelineLayout,
+    /// Layout of pipelines reading the backdrop as `@group(1)`.
+    backdrop_layout: PipelineLayout,
+    buffers: &'static [VertexBufferLayout<'static>],
+
+    pipelines: HashMap<Blending, RenderPipeline>,
+}
+
+/// A copy of the target, for blend modes that read what they're drawn onto.
+pub struct Backdrop {
+    /// [`Backdrop::texture`] layout.
+    layout: BindGroupLayout,
+    /// The copy and it's bind group, once made.
+    texture: Option<(Texture, BindGroup)>,
+}
+
+/// The fragment shader entry point for blend modes that read the backdrop,
+/// see `shader/blend.wgsl`.
+const BACKDROP_ENTRY: &str = "frag_backdrop";
+
+impl Blending {
+    /// The blending of `mode`.
+    pub fn of(mode: BlendMode) -> Self {
+        mode.blend_state().map_or(Self::Backdrop, Self::State)
+    }
+
+    /// Whether the target has to be copied into the [`Backdrop`] before
+    /// drawing.
+    pub fn reads_backdrop(self) -> bool {
+        self == Self::Backdrop
+    }
+}
+
+impl Pipelines {
+    /// Prepare pipelines for the shader `source`, which has `vert` and `frag`
+    /// entry points and `frag_backdrop` from `shader/blend.wgsl`.
+    ///
+    /// `bind_group` is the layout of `@group(0)`.
+    pub fn new(
+        backend: &Backend,
+        label: &'static str,
+        source: &str,
+        bind_group: &BindGroupLayout,
+        backdrop: &Backdrop,
+        buffers: &'static [VertexBufferLayout<'static>],
+    ) -> Self {
+        let device = backend.device();
+
+        let module = device.create_shader_module(ShaderModuleDescriptor {
+            label: Some(label),
+            source: ShaderSource::Wgsl(source.into()),
+        });
+        let layout = device.create_pipeline_layout(&PipelineLayoutDescriptor {
+            label: Some(label),
+            bind_group_layouts: &[bind_group],
+            push_constant_ranges: &[],
+        });
+        let backdrop_layout = device.create_pipeline_layout(&PipelineLayoutDescriptor {
+            label: Some(label),
+            bind_group_layouts: &[bind_group, &backdrop.layout],
+            push_constant_ranges: &[],
+        });
+
+        let mut pipelines = Self {
+            label,
+            module,
+            layout,
+            backdrop_layout,
+            buffers,
+            pipelines: HashMap::new(),
+        };
+
+        // the blend mode of most draws
+        pipelines.prepare(backend, Blending::of(BlendMode::Normal));
+
+        pipelines
+    }
+
+    /// Build the pipeline for `blending`, unless it's already built.
+    pub fn prepare(&mut self, backend: &Backend, blending: Blending) {
+        if !self.pipelines.contains_key(&blending) {
+            let pipeline = self.build(backend, blending);
+
+            self.pipelines.insert(blending, pipeline);
+        }
+    }
+
+    /// The pipeline for `blending`.
+    ///
+    /// ## Panics
+    ///
+    /// If it isn't [prepared](Pipelines::prepare).
+    pub fn get(&self, blending: Blending) -> &RenderPipeline {
+        &self.pipelines[&blending]
+    }
+
+    fn build(&self, backend: &Backend, blending: Blending) -> RenderPipeline {
+        let (layout, entry_point, blend) = match blending {
+            Blending::State(state) => (&self.layout, "frag", state),
+            // the shader blends, and the backdrop is composited under it
+            Blending::Backdrop => {
+                (&self.backdrop_layout, BACKDROP_ENTRY, PorterDuff::SrcOver.blend_state())
+            },
+        };
+
+        let primitive = PrimitiveState {
+            topology: PrimitiveTopology::TriangleList,
+            front_face: FrontFace::Cw,
+            ..Default::default()
+        };
+        let multisample = MultisampleState {
+            // non-multisampled -> `1`
+            count: 1,
+            // `!0` -> all samples
+            mask: !0,
+            alpha_to_coverage_enabled: false,
+        };
+        let targets = &[Some(ColorTargetState {
+            format: backend.format(),
+            // colors are premultiplied
+            blend: Some(blend),
+            write_mask: ColorWrites::ALL,
+        })];
+
+        backend.device().create_render_pipeline(&RenderPipelineDescriptor {
+            label: Some(self.label),
+            layout: Some(layout),
+            vertex: VertexState {
+                module: &self.module,
+                entry_point: "vert",
+                buffers: self.buffers,
+            },
+            primitive,
+            depth_stencil: None,
+            multisample,
+            fragment: Some(FragmentState { module: &self.module, entry_point, targets }),
+            multiview: None,
+        })
+    }
+}
+
+impl Backdrop {
+    pub fn new(backend: &Backend) -> Self {
+        let layout =
+            backend.device().create_bind_group_layout(&BindGroupLayoutDescriptor {
+                label: Some("Backdrop.layout"),
+                // `@group(1)`
+                entries: &[BindGroupLayoutEntry {
+                    // ...`@binding(0)`
+                    binding: 0,
+                    visibility: ShaderStages::FRAGMENT,
+                    ty: BindingType::Texture {
+                        // only loaded, which float formats all allow
+                        sample_type: TextureSampleType::Float { filterable: false },
+                        view_dimension: TextureViewDimension::D2,
+                        multisampled: false,
+                    },
+                    count: None,
+                }],
+            });
+
+        Self { layout, texture: None }
+    }
+
+    /// Copy `target` into the backdrop, which has to be done outside of a
+    /// render pass.
+    ///
+    /// The target needs [`TextureUsages::COPY_SRC`], and is drawn to with
+    /// the backend's format.
+    pub fn copy(
+        &mut self,
+        backend: &Backend,
+        encoder: &mut CommandEncoder,
+        target: &Texture,
+    ) {
+        let size = target.size();
+
+        let reuse = matches!(&self.texture, Some((texture, _)) if texture.size() == size);
+
+        if !reuse {
+            self.texture = Some(self.create(backend, size));
+        }
+
+        if let Some((texture, _)) = &self.texture {
+            encoder.copy_texture_to_texture(
+                target.as_image_copy(),
+                texture.as_image_copy(),
+                size,
+            );
+        }
+    }
+
+    /// The bind group of the copy, as `@group(1)`.
+    ///
+    /// ## Panics
+    ///
+    /// If the target hasn't been [copied](Backdrop::copy) yet.
+    pub fn bind_group(&self) -> &BindGroup {
+        let (_, bgroup) = self.texture.as_ref().expect("backdrop is copied before use");
+
+        bgroup
+    }
+
+    /// Create a texture of `size` to copy the target into.
+    fn create(&self, backend: &Backend, size: Extent3d) -> (Texture, BindGroup) {
+        let device = backend.device();
+
+        let texture = device.create_texture(&TextureDescriptor {
+            label: Some("Backdrop.texture"),
+            size,
+            mip_level_count: 1,
+            sample_count: 1,
+            dimension: TextureDimension::D2,
+            format: backend.format(),
+            usage: TextureUsages::COPY_DST | TextureUsages::TEXTURE_BINDING,
+            view_formats: &[],
+        });
+        let view = texture.create_view(&TextureViewDescriptor::default());
+        let bgroup = device.create_bind_group(&BindGroupDescriptor {
+            label: Some("Backdrop.bgroup"),
+            layout: &self.layout,
+            entries: &[BindGroupEntry {
+                binding: 0,
+                resource: BindingResource::TextureView(&view),
+            }],
+        });
+
+        (texture, bgroup)
+    }
+}
@@ -1,12 +1,12 @@
 use std::convert::Infallible;
 
-use crate::geometry::Size2;
+use crate::geometry::{Physical, Size2};
 use crate::{Canvas, Target};
 
 impl Target for Canvas {
     type Error = Infallible;
 
-    fn size(&self) -> Size2<u32> {
+    fn size(&self) -> Size2<u32, Physical> {
         self.size
     }
 
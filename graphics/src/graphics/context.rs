@@ -1,16 +1,20 @@
 use std::sync::RwLock;
 
+use super::blending::Backdrop;
 use super::quad::QuadPipeline;
+use super::triangle::TrianglePipeline;
 use super::{Context, Inner, Scene};
 use crate::backend::Backend;
 
 impl Context {
     /// Initializes drawing state.
     pub fn new(backend: &Backend) -> Self {
-        let quad = QuadPipeline::new(&backend);
+        let backdrop = Backdrop::new(backend);
+        let quad = QuadPipeline::new(backend, &backdrop);
+        let triangle = TrianglePipeline::new(backend, &backdrop);
         let scene = Scene::new();
 
-        Self { inner: RwLock::new(Inner { quad, scene }) }
+        Self { inner: RwLock::new(Inner { quad, triangle, backdrop, scene }) }
     }
 
     /// With [`&Inner`](Inner).
@@ -1,5 +1,8 @@
 use wgpu::{CommandEncoder, RenderPass};
 
+use crate::color::BlendMode;
+use crate::geometry::ScaleFactor;
+use crate::spatial::SpatialIndex;
 use crate::{Draw, Frame, Graphics, Target};
 
 impl<'frame, T: Target> Frame<'frame, T> {
@@ -12,7 +15,35 @@ impl<'frame, T: Target> Frame<'frame, T> {
         let backend = graphics.backend.clone();
         let context = graphics.context.clone();
 
-        Self { backend, context, rpass, encoder, target }
+        Self { backend, context, rpass, encoder, target, blend_mode: BlendMode::Normal }
+    }
+
+    /// The amount of physical pixels per logical pixel of the target.
+    pub fn scale_factor(&self) -> ScaleFactor {
+        self.target.scale_factor()
+    }
+
+    /// How draws are blended with what's below them.
+    pub fn blend_mode(&self) -> BlendMode {
+        self.blend_mode
+    }
+
+    /// Set how following draws are blended with what's below them.
+    ///
+    /// Defaults to [`BlendMode::Normal`]. Modes without a
+    /// [blend state](BlendMode::blend_state) read a copy of the target made
+    /// before each run of draws with them, so overlapping draws in the same
+    /// run blend with what was below the run rather than with each other.
+    pub fn set_blend_mode(&mut self, blend_mode: BlendMode) {
+        self.blend_mode = blend_mode;
+    }
+
+    /// Index the quads drawn to the current layer of this frame so far, by
+    /// the order they were drawn in.
+    ///
+    /// For finding what's under the cursor, see [`SpatialIndex::query_point`].
+    pub fn spatial_index(&self) -> SpatialIndex {
+        self.context.with(|state| state.scene.spatial_index())
     }
 
     /// Draw an item.
@@ -1,15 +1,34 @@
 use super::Layer;
-use crate::geometry::Rect;
+use crate::color::BlendMode;
+use crate::geometry::{Physical, Rect};
 use crate::primitive::Quad;
+use crate::spatial::SpatialIndex;
 
 impl Layer {
     /// Create a new empty layer with the given clip bounds.
-    pub fn new(bounds: Rect<u32>) -> Self {
-        Self { bounds, quads: Vec::new() }
+    pub fn new(bounds: Rect<u32, Physical>) -> Self {
+        Self { bounds, quads: Vec::new(), blend_modes: Vec::new() }
     }
 
-    /// Add a quad to this layer.
-    pub fn add_quad(&mut self, quad: Quad) {
+    /// Add a quad drawn with `blend_mode` to this layer.
+    pub fn add_quad(&mut self, quad: Quad, blend_mode: BlendMode) {
+        let current =
+            self.blend_modes.last().map_or(BlendMode::Normal, |&(_, mode)| mode);
+
+        if blend_mode != current {
+            self.blend_modes.push((self.quads.len(), blend_mode));
+        }
+
         self.quads.push(quad)
     }
+
+    /// Index the quads in this layer by their index in [`Layer::quads`].
+    ///
+    /// The index doesn't follow changes to the layer, so moved quads need to
+    /// be reinserted with [`SpatialIndex::insert`].
+    pub fn spatial_index(&self) -> SpatialIndex {
+        SpatialIndex::from_rects(
+            self.quads.iter().map(|quad| Rect::new(quad.loc, quad.size)),
+        )
+    }
 }
@@ -1,11 +1,16 @@
 use std::sync::{Arc, RwLock};
 
+use self::blending::Backdrop;
 use self::quad::QuadPipeline;
+use self::triangle::TrianglePipeline;
 use crate::backend::{Backend, BackendError};
-use crate::geometry::Rect;
+use crate::color::BlendMode;
+use crate::geometry::{Physical, Rect};
 use crate::primitive::Quad;
 use crate::{Frame, Graphics, Target};
 
+/// Render pipelines for each blend mode, and the backdrop some read.
+mod blending;
 mod canvas;
 mod context;
 mod frame;
@@ -14,6 +19,8 @@ mod layer;
 mod quad;
 mod scene;
 mod surface;
+/// Triangle mesh render pipeline.
+mod triangle;
 
 /// Shared drawing state.
 pub struct Context {
@@ -24,6 +31,10 @@ pub struct Context {
 pub struct Inner {
     /// Pipeline for rendering colored "quads" (rectangles).
     pub quad: QuadPipeline,
+    /// Pipeline for rendering tessellated triangle meshes.
+    pub triangle: TrianglePipeline,
+    /// Copy of the target for blend modes that read it.
+    pub backdrop: Backdrop,
     /// "Scene Graph".
     pub scene: Scene,
 }
@@ -41,9 +52,13 @@ pub struct Scene {
 #[derive(Debug, Default, Clone)]
 pub struct Layer {
     /// Clip bounds.
-    pub bounds: Rect<u32>,
+    pub bounds: Rect<u32, Physical>,
     /// Quads in this layer.
     pub quads: Vec<Quad>,
+    /// The blend mode of each run of quads, by the index of it's first quad.
+    ///
+    /// Quads before the first run are drawn with [`BlendMode::Normal`].
+    pub blend_modes: Vec<(usize, BlendMode)>,
 }
 
 impl Graphics {
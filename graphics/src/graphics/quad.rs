@@ -1,4 +1,9 @@
+// the pipelines aren't used until `Graphics::prepare` and `Graphics::render`
+// are implemented
+#![allow(dead_code)]
+
 use std::mem;
+use std::ops::Range;
 
 use bytemuck::{Pod, Zeroable};
 use crossd_math::Mat4;
@@ -10,44 +15,31 @@ use wgpu::{
     BindGroupLayoutDescriptor,
     BindGroupLayoutEntry,
     BindingType,
-    BlendComponent,
-    BlendFactor,
-    BlendOperation,
-    BlendState,
     BufferBindingType,
     BufferSize,
     BufferUsages,
-    ColorTargetState,
-    ColorWrites,
-    FragmentState,
-    FrontFace,
+    Device,
     IndexFormat,
-    MultisampleState,
-    PipelineLayoutDescriptor,
-    PrimitiveState,
-    PrimitiveTopology,
     RenderPass,
-    RenderPipeline,
-    RenderPipelineDescriptor,
-    ShaderModuleDescriptor,
-    ShaderSource,
     ShaderStages,
     VertexAttribute,
     VertexBufferLayout,
     VertexFormat,
-    VertexState,
     VertexStepMode,
 };
 
+use super::blending::{Backdrop, Blending, Pipelines};
 use crate::backend::Backend;
-use crate::geometry::Rect;
+use crate::color::{BlendMode, PremulColor};
+use crate::geometry::{Physical, Point2, Rect, Size2};
+use crate::paint::{GradientKind, Paint, Spread};
 use crate::primitive::{Quad, Vertex};
 use crate::utils::gpu_buf::GpuBuf;
 use crate::utils::gpu_store::GpuStore;
 use crate::{Draw, Frame, Target};
 
 pub struct QuadPipeline {
-    pipeline: RenderPipeline,
+    pipelines: Pipelines,
     /// [`QuadUniforms`] layout.
     layout: BindGroupLayout,
 
@@ -61,14 +53,47 @@ pub struct QuadPipeline {
 }
 
 struct QuadLayer {
-    /// Uniform bind group.
+    /// Uniform and ramp bind group.
     bgroup: BindGroup,
     /// Uniform storage buffer.
     uniforms: GpuStore<QuadUniforms>,
     /// Quads in this layer.
-    quads: GpuBuf<Quad>,
+    quads: GpuBuf<QuadInstance>,
+    /// [`RAMP_LEN`] colors for each gradient in this layer, see
+    /// [`Gradient::ramp`](crate::paint::Gradient::ramp).
+    ramps: GpuBuf<PremulColor>,
+    /// Runs of quads drawn with the same blend mode, in order.
+    runs: Vec<Run>,
+}
+
+/// Quads in a layer drawn one after another with the same blend mode.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Run {
+    /// The indices of the quads in the layer.
+    pub quads: Range<u32>,
+    /// How the quads are blended with the target.
+    pub blending: Blending,
 }
 
+/// A [`Quad`] as read by the shader.
+#[repr(C)]
+#[derive(Debug, Clone, Copy, Pod, Zeroable)]
+struct QuadInstance {
+    loc: Point2,
+    size: Size2<f32>,
+    /// The premultiplied color of solid quads.
+    color: PremulColor,
+    /// The points and values of the gradient, depending on `kind`.
+    params: [f32; 4],
+    /// `0` for solid quads, then linear, radial and conic gradients.
+    kind: u32,
+    /// [`Spread`] of the gradient, in order.
+    spread: u32,
+    /// Index of the gradient's first color in [`QuadLayer::ramps`].
+    ramp: u32,
+    /// The [`BlendMode`], in order, for quads reading the backdrop.
+    blend: u32,
+}
 #[repr(C)]
 #[derive(Debug, Clone, Copy, Pod, Zeroable)]
 struct QuadUniforms {
@@ -78,30 +103,63 @@ struct QuadUniforms {
     _padding: [f32; 3],
 }
 
-/// Shader source.
-const SOURCE: &str = include_str!("../shader/quad.wgsl");
+/// Shader source, with the blend modes reading the backdrop.
+const SOURCE: &str =
+    concat!(include_str!("../shader/quad.wgsl"), include_str!("../shader/blend.wgsl"));
 
 /// Initial [`QuadLayer`] quad buffer capacity.
 const INITIAL: usize = 2000;
+/// Amount of colors each gradient is drawn with.
+///
+/// Must match `RAMP_LEN` in the shader.
+const RAMP_LEN: usize = 256;
+/// Layouts of the [`Vertex`] and [`QuadInstance`] buffers.
+const BUFFERS: &[VertexBufferLayout<'static>] = &[
+    VertexBufferLayout {
+        array_stride: mem::size_of::<Vertex>() as _,
+        step_mode: VertexStepMode::Vertex,
+        attributes: &[VertexAttribute {
+            // layout for `Vertex` (`Point2`)
+            format: VertexFormat::Float32x2,
+            offset: 0,
+            shader_location: 0,
+        }],
+    },
+    VertexBufferLayout {
+        array_stride: mem::size_of::<QuadInstance>() as _,
+        step_mode: VertexStepMode::Instance,
+        // layout for `QuadInstance`
+        attributes: &wgpu::vertex_attr_array![
+            1 => Float32x2,
+            2 => Float32x2,
+            3 => Float32x4,
+            4 => Float32x4,
+            5 => Uint32,
+            6 => Uint32,
+            7 => Uint32,
+            8 => Uint32,
+        ],
+    },
+];
 /// Connections between the vertices, forming a rectangle.
 const INDICES: [u16; 6] = [0, 1, 2, 0, 2, 3];
 /// Vertices in the shape of a square.
 const VERTICES: [Vertex; 4] = [
-    Vertex::new(0.0, 1.0),
+    Vertex::new(0.0, 0.0),
     Vertex::new(1.0, 0.0),
     Vertex::new(1.0, 1.0),
     Vertex::new(0.0, 1.0),
 ];
 
 impl QuadPipeline {
-    pub fn new(backend: &Backend) -> Self {
+    pub fn new(backend: &Backend, backdrop: &Backdrop) -> Self {
         let device = backend.device();
 
-        let layout =
-            device.create_bind_group_layout(&BindGroupLayoutDescriptor {
-                label: Some("QuadPipeline.layout"),
-                // `@group(0)`,
-                entries: &[BindGroupLayoutEntry {
+        let layout = device.create_bind_group_layout(&BindGroupLayoutDescriptor {
+            label: Some("QuadPipeline.layout"),
+            // `@group(0)`,
+            entries: &[
+                BindGroupLayoutEntry {
                     // ...`@binding(0)`
                     binding: 0,
                     visibility: ShaderStages::VERTEX,
@@ -114,79 +172,32 @@ impl QuadPipeline {
                     },
                     // none indicates that this is not an array
                     count: None,
-                }],
-            });
-
-        let pipeline = {
-            let layout = device.create_pipeline_layout(&PipelineLayoutDescriptor {
-                label: Some("QuadPipeline.pipeline:layout"),
-                bind_group_layouts: &[&layout],
-                push_constant_ranges: &[],
-            });
-
-            let module = device.create_shader_module(ShaderModuleDescriptor {
-                label: Some("QuadPipeline.pipeline:module"),
-                source: ShaderSource::Wgsl(SOURCE.into()),
-            });
-
-            let vertex = VertexState {
-                module: &module,
-                entry_point: "vert",
-                buffers: &[VertexBufferLayout {
-                    array_stride: mem::size_of::<Vertex>() as _,
-                    step_mode: VertexStepMode::Vertex,
-                    attributes: &[VertexAttribute {
-                        // layout for `Vertex` (`Point2`)
-                        format: VertexFormat::Float32x2,
-                        offset: 0,
-                        shader_location: 0,
-                    }],
-                }],
-            };
-            let primitive = PrimitiveState {
-                topology: PrimitiveTopology::TriangleList,
-                front_face: FrontFace::Cw,
-                ..Default::default()
-            };
-            let multisample = MultisampleState {
-                // non-multisampled -> `1`
-                count: 1,
-                // `!0` -> all samples
-                mask: !0,
-                alpha_to_coverage_enabled: false,
-            };
-            let targets = &[Some(ColorTargetState {
-                format: backend.format(),
-                blend: Some(BlendState {
-                    color: BlendComponent {
-                        src_factor: BlendFactor::SrcAlpha,
-                        dst_factor: BlendFactor::OneMinusSrcAlpha,
-                        operation: BlendOperation::Add,
-                    },
-                    alpha: BlendComponent {
-                        src_factor: BlendFactor::One,
-                        dst_factor: BlendFactor::OneMinusSrcAlpha,
-                        operation: BlendOperation::Add,
+                },
+                BindGroupLayoutEntry {
+                    // ...`@binding(1)`
+                    binding: 1,
+                    // gradients are sampled by the fragment shader
+                    visibility: ShaderStages::FRAGMENT,
+                    ty: BindingType::Buffer {
+                        ty: BufferBindingType::Storage { read_only: true },
+                        has_dynamic_offset: false,
+                        min_binding_size: BufferSize::new(
+                            mem::size_of::<PremulColor>() as _
+                        ),
                     },
-                }),
-                write_mask: ColorWrites::ALL,
-            })];
-
-            device.create_render_pipeline(&RenderPipelineDescriptor {
-                label: Some("QuadPipeline.pipeline"),
-                layout: Some(&layout),
-                vertex,
-                primitive,
-                depth_stencil: None,
-                multisample,
-                fragment: Some(FragmentState {
-                    module: &module,
-                    entry_point: "frag",
-                    targets,
-                }),
-                multiview: None,
-            })
-        };
+                    count: None,
+                },
+            ],
+        });
+
+        let pipelines = Pipelines::new(
+            backend,
+            "QuadPipeline.pipelines",
+            SOURCE,
+            &layout,
+            backdrop,
+            BUFFERS,
+        );
 
         let vertices = GpuBuf::init(
             device,
@@ -201,34 +212,59 @@ impl QuadPipeline {
             &INDICES,
         );
 
-        Self { pipeline, layout, vertices, indices, layers: Vec::new(), current: 0 }
+        Self { pipelines, layout, vertices, indices, layers: Vec::new(), current: 0 }
     }
 
     /// Prepare the current layer.
+    ///
+    /// `blend_modes` has the blend mode of each run of quads, by the index of
+    /// it's first quad. Quads before the first run are drawn normally.
     pub fn prepare(
         &mut self,
         backend: &Backend,
         trans: Mat4,
         scale: f32,
         quads: &[Quad],
+        blend_modes: &[(usize, BlendMode)],
     ) {
         if self.layers.len() <= self.current {
             self.layers.push(QuadLayer::new(backend, &self.layout));
         }
 
-        self.layers[self.current].prepare(backend, trans, scale, quads);
+        for &(_, mode) in blend_modes {
+            self.pipelines.prepare(backend, Blending::of(mode));
+        }
+
+        self.layers[self.current].prepare(
+            backend,
+            &self.layout,
+            trans,
+            scale,
+            quads,
+            blend_modes,
+        );
         self.current += 1;
     }
 
-    /// Render the requested layer.
+    /// The runs of quads in the requested layer.
+    ///
+    /// Runs that [read the backdrop](Blending::reads_backdrop) need the target
+    /// copied into it with [`Backdrop::copy`] right before they are rendered,
+    /// in between render passes.
+    pub fn runs(&self, layer: usize) -> &[Run] {
+        self.layers.get(layer).map_or(&[], |layer| &layer.runs)
+    }
+
+    /// Render the requested runs of the requested layer, see
+    /// [`QuadPipeline::runs`].
     pub fn render<'pass>(
         &'pass self,
         layer: usize,
-        bounds: Rect<u32>,
+        runs: Range<usize>,
+        bounds: Rect<u32, Physical>,
+        backdrop: &'pass Backdrop,
         rpass: &mut RenderPass<'pass>,
     ) {
-        rpass.set_pipeline(&self.pipeline);
-
         if let Some(layer) = self.layers.get(layer) {
             rpass.set_scissor_rect(
                 bounds.loc.x,
@@ -243,8 +279,15 @@ impl QuadPipeline {
             rpass.set_bind_group(0, &layer.bgroup, &[]);
             rpass.set_vertex_buffer(1, layer.quads.slice(..));
 
-            // draw all quads in the layer
-            rpass.draw_indexed(0..INDICES.len() as _, 0, 0..layer.quads.len() as _);
+            for run in &layer.runs[runs] {
+                rpass.set_pipeline(self.pipelines.get(run.blending));
+
+                if run.blending.reads_backdrop() {
+                    rpass.set_bind_group(1, backdrop.bind_group(), &[]);
+                }
+
+                rpass.draw_indexed(0..INDICES.len() as _, 0, run.quads.clone());
+            }
         }
     }
 }
@@ -258,14 +301,13 @@ impl QuadLayer {
             BufferUsages::UNIFORM | BufferUsages::COPY_DST,
             Some("QuadLayer.uniforms"),
         );
-        let bgroup = device.create_bind_group(&BindGroupDescriptor {
-            label: Some("QuadLayer.bgroup"),
-            layout,
-            entries: &[BindGroupEntry {
-                binding: 0,
-                resource: uniforms.buffer().as_entire_binding(),
-            }],
-        });
+        let ramps = GpuBuf::new(
+            device,
+            BufferUsages::STORAGE | BufferUsages::COPY_DST,
+            Some("QuadLayer.ramps"),
+            RAMP_LEN,
+        );
+        let bgroup = bind_group(device, layout, &uniforms, &ramps);
 
         let quads = GpuBuf::new(
             device,
@@ -274,18 +316,139 @@ impl QuadLayer {
             INITIAL,
         );
 
-        Self { bgroup, uniforms, quads }
+        Self { bgroup, uniforms, quads, ramps, runs: Vec::new() }
     }
 
-    fn prepare(&mut self, backend: &Backend, trans: Mat4, scale: f32, quads: &[Quad]) {
+    fn prepare(
+        &mut self,
+        backend: &Backend,
+        layout: &BindGroupLayout,
+        trans: Mat4,
+        scale: f32,
+        quads: &[Quad],
+        blend_modes: &[(usize, BlendMode)],
+    ) {
         let device = backend.device();
         let queue = backend.queue();
 
+        self.runs.clear();
+
+        let mut starts = blend_modes.iter().peekable();
+        let mut mode = BlendMode::Normal;
+        let mut ramps = Vec::new();
+        let mut instances = Vec::with_capacity(quads.len());
+
+        for (i, quad) in quads.iter().enumerate() {
+            let mut changed = false;
+
+            while let Some(&(_, next)) = starts.next_if(|(start, _)| *start <= i) {
+                mode = next;
+                changed = true;
+            }
+
+            // a new run at every change, so that runs reading the backdrop
+            // see the ones before them
+            match self.runs.last_mut() {
+                Some(run) if !changed => run.quads.end += 1,
+                _ => {
+                    let i = i as u32;
+
+                    self.runs.push(Run { quads: i..i + 1, blending: Blending::of(mode) });
+                },
+            }
+
+            instances.push(QuadInstance::new(quad, mode, backend, &mut ramps));
+        }
+
         self.uniforms.write(queue, QuadUniforms::new(trans, scale));
-        self.quads.write(device, queue, quads);
+        self.quads.write(device, queue, &instances);
+
+        // the bind group refers to the old buffer after a reallocation
+        if !ramps.is_empty() && self.ramps.write(device, queue, &ramps) {
+            self.bgroup = bind_group(device, layout, &self.uniforms, &self.ramps);
+        }
+    }
+}
+
+impl QuadInstance {
+    /// The instance for `quad` drawn with `mode`, adding the ramp of it's
+    /// gradient to `ramps`.
+    ///
+    /// Colors are as written to the target, see [`Backend::target_color`].
+    fn new(
+        quad: &Quad,
+        mode: BlendMode,
+        backend: &Backend,
+        ramps: &mut Vec<PremulColor>,
+    ) -> Self {
+        let solid = |color: PremulColor| Self {
+            loc: quad.loc,
+            size: quad.size,
+            color,
+            params: [0.0; 4],
+            kind: 0,
+            spread: 0,
+            ramp: 0,
+            blend: mode as u32,
+        };
+
+        let gradient = match &quad.paint {
+            Paint::Solid(color) => return solid(backend.target_color(*color)),
+            Paint::Gradient(gradient) => gradient,
+        };
+
+        // the offset is missing everywhere, see `Gradient::sample`
+        if gradient.kind.offset(quad.loc).is_none() {
+            return solid(backend.target_color(gradient.sample(quad.loc)));
+        }
+
+        let (kind, params) = match gradient.kind {
+            GradientKind::Linear { start, end } => (1, [start.x, start.y, end.x, end.y]),
+            GradientKind::Radial { center, radius } => {
+                (2, [center.x, center.y, radius, 0.0])
+            },
+            GradientKind::Conic { center, angle } => {
+                (3, [center.x, center.y, angle, 0.0])
+            },
+        };
+        let spread = match gradient.spread {
+            Spread::Pad => 0,
+            Spread::Repeat => 1,
+            Spread::Reflect => 2,
+        };
+        let ramp = ramps.len() as u32;
+
+        ramps.extend(
+            gradient
+                .ramp(RAMP_LEN)
+                .into_iter()
+                .map(|color| backend.target_color(color.unpremultiply())),
+        );
+
+        Self { params, kind, spread, ramp, ..solid(PremulColor::TRANSPARENT) }
     }
 }
 
+/// Bind `uniforms` and `ramps` for a [`QuadLayer`].
+fn bind_group(
+    device: &Device,
+    layout: &BindGroupLayout,
+    uniforms: &GpuStore<QuadUniforms>,
+    ramps: &GpuBuf<PremulColor>,
+) -> BindGroup {
+    device.create_bind_group(&BindGroupDescriptor {
+        label: Some("QuadLayer.bgroup"),
+        layout,
+        entries: &[
+            BindGroupEntry {
+                binding: 0,
+                resource: uniforms.buffer().as_entire_binding(),
+            },
+            BindGroupEntry { binding: 1, resource: ramps.buffer().as_entire_binding() },
+        ],
+    })
+}
+
 impl QuadUniforms {
     /// Create uniforms with given values.
     const fn new(trans: Mat4, scale: f32) -> Self {
@@ -306,6 +469,68 @@ impl Default for QuadUniforms {
 
 impl Draw for Quad {
     fn draw(frame: &mut Frame<'_, impl Target>, quad: Self) {
-        frame.context.with_mut(|state| state.scene.add_quad(quad))
+        let blend_mode = frame.blend_mode;
+
+        frame.context.with_mut(|state| state.scene.add_quad(quad, blend_mode))
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use wgpu::TextureFormat;
+
+    use super::{QuadInstance, RAMP_LEN};
+    use crate::backend::Backend;
+    use crate::color::{linear_to_srgb, BlendMode, Color, LinearRgba, PremulColor};
+    use crate::geometry::{Point2, Size2};
+    use crate::paint::{Gradient, Paint};
+    use crate::primitive::Quad;
+
+    /// The colors of `paint` as the shader reads them, unless there's no GPU.
+    fn colors(
+        format: TextureFormat,
+        paint: Paint,
+    ) -> Option<(PremulColor, Vec<PremulColor>)> {
+        let backend = Backend::new_using(format).ok()?;
+        let quad = Quad::new(Point2::new(0.0, 0.0), Size2::new(10.0, 10.0), paint);
+        let mut ramps = Vec::new();
+
+        let instance = QuadInstance::new(&quad, BlendMode::Normal, &backend, &mut ramps);
+
+        Some((instance.color, ramps))
+    }
+
+    #[test]
+    fn premultiplied() {
+        let color = LinearRgba::new(0.5, 0.25, 1.0, 0.5);
+
+        // pipelines composite with premultiplied source-over
+        if let Some((solid, _)) = colors(TextureFormat::Rgba16Float, Paint::Solid(color))
+        {
+            assert_eq!(solid, PremulColor::new(0.25, 0.125, 0.5, 0.5));
+        }
+
+        // encoded before it's multiplied by alpha
+        if let Some((solid, _)) = colors(TextureFormat::Rgba8Unorm, Paint::Solid(color)) {
+            let [r, g, b] = [0.5, 0.25, 1.0].map(|v| linear_to_srgb(v) * 0.5);
+
+            assert_eq!(solid, PremulColor::new(r, g, b, 0.5));
+        }
+
+        let gradient = Gradient::linear(Point2::new(0.0, 0.0), Point2::new(10.0, 0.0))
+            .stop(0.0, Color::new(255, 0, 0, 0))
+            .stop(1.0, Color::WHITE);
+
+        if let Some((_, ramp)) =
+            colors(TextureFormat::Rgba8Unorm, Paint::Gradient(gradient))
+        {
+            assert_eq!(ramp.len(), RAMP_LEN);
+            assert_eq!(ramp[0], PremulColor::TRANSPARENT);
+            assert_eq!(ramp[RAMP_LEN - 1].a, 1.0);
+            assert!(
+                ramp.iter().all(|c| c.r <= c.a && c.g <= c.a && c.b <= c.a),
+                "{ramp:?}"
+            );
+        }
     }
 }
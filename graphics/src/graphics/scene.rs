@@ -1,5 +1,7 @@
-use super::Scene;
+use super::{Layer, Scene};
+use crate::color::BlendMode;
 use crate::primitive::Quad;
+use crate::spatial::SpatialIndex;
 
 impl Scene {
     /// A new empty scene.
@@ -7,8 +9,52 @@ impl Scene {
         Self::default()
     }
 
-    /// Add a quad to the current layer.
-    pub fn add_quad(&mut self, quad: Quad) {
-        self.scenes[self.current].quads.push(quad)
+    /// Add a quad drawn with `blend_mode` to the current layer.
+    pub fn add_quad(&mut self, quad: Quad, blend_mode: BlendMode) {
+        self.scenes[self.current].add_quad(quad, blend_mode)
+    }
+
+    /// The layer draws are currently added to, or `None` if there are no
+    /// layers.
+    pub fn layer(&self) -> Option<&Layer> {
+        self.scenes.get(self.current)
+    }
+
+    /// Index the quads in the current layer, see [`Layer::spatial_index`].
+    ///
+    /// The index is empty if there are no layers.
+    pub fn spatial_index(&self) -> SpatialIndex {
+        self.layer().map_or_else(SpatialIndex::default, Layer::spatial_index)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::super::{Layer, Scene};
+    use crate::color::{BlendMode, Color};
+    use crate::geometry::{Point2, Rect, Size2};
+    use crate::primitive::Quad;
+
+    #[test]
+    fn empty() {
+        let scene = Scene::new();
+
+        assert!(scene.layer().is_none());
+        assert!(scene.spatial_index().is_empty());
+    }
+
+    #[test]
+    fn spatial_index() {
+        let mut scene = Scene::new();
+        scene.scenes.push(Layer::new(Rect::new(Point2::new(0, 0), Size2::new(100, 100))));
+
+        assert!(scene.spatial_index().is_empty());
+
+        let quad =
+            Quad::new(Point2::new(10.0, 10.0), Size2::new(20.0, 20.0), Color::BLACK);
+        scene.add_quad(quad, BlendMode::Normal);
+
+        assert_eq!(scene.layer().map(|layer| layer.quads.len()), Some(1));
+        assert_eq!(scene.spatial_index().query_point(Point2::new(15.0, 15.0)), [0]);
     }
 }
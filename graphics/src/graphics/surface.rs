@@ -1,8 +1,8 @@
-use wgpu::SurfaceConfiguration;
+use wgpu::{SurfaceConfiguration, TextureUsages};
 #[cfg(feature = "winit")]
 use winit::{dpi::PhysicalSize, window::Window};
 
-use crate::geometry::Size2;
+use crate::geometry::{Logical, Physical, ScaleFactor, Size2};
 use crate::{Graphics, Surface, SurfaceTarget, Target};
 
 #[derive(Debug, thiserror::Error)]
@@ -19,8 +19,23 @@ impl<T: SurfaceTarget> Surface<T> {
         use NewError::*;
 
         let surface = unsafe { graphics.backend.instance().create_surface(&target) }?;
+        let format = graphics.backend.format();
+
+        let capabilities = surface.get_capabilities(graphics.backend.adapter());
+
+        // the pipelines are made for the backend's format
+        if !capabilities.formats.contains(&format) {
+            return Err(UnsupportedSurface);
+        }
+
+        // blend modes reading the target copy it
+        let usage = TextureUsages::RENDER_ATTACHMENT
+            | (capabilities.usages & TextureUsages::COPY_SRC);
+
         let size = target.size();
         let config = SurfaceConfiguration {
+            usage,
+            format,
             width: size.w,
             height: size.h,
             ..surface
@@ -32,18 +47,32 @@ impl<T: SurfaceTarget> Surface<T> {
     }
 
     /// The size of the surface target.
-    pub fn size(&self) -> Size2 {
+    pub fn size(&self) -> Size2<u32, Physical> {
         self.target.size()
     }
+
+    /// The size of the surface target in logical pixels.
+    pub fn logical_size(&self) -> Size2<f32, Logical> {
+        self.size().map(|v| v as f32).to_logical(self.scale_factor())
+    }
+
+    /// The amount of physical pixels per logical pixel of the surface target.
+    pub fn scale_factor(&self) -> ScaleFactor {
+        self.target.scale_factor()
+    }
 }
 
 impl<T: SurfaceTarget> Target for Surface<T> {
     type Error = wgpu::SurfaceError;
 
-    fn size(&self) -> Size2<u32> {
+    fn size(&self) -> Size2<u32, Physical> {
         self.target.size()
     }
 
+    fn scale_factor(&self) -> ScaleFactor {
+        self.target.scale_factor()
+    }
+
     fn present(&self) -> Result<(), wgpu::SurfaceError> {
         todo!()
     }
@@ -51,9 +80,13 @@ impl<T: SurfaceTarget> Target for Surface<T> {
 
 #[cfg(feature = "winit")]
 impl SurfaceTarget for Window {
-    fn size(&self) -> Size2<u32> {
+    fn size(&self) -> Size2<u32, Physical> {
         let PhysicalSize { width, height } = self.inner_size();
 
         Size2::new(width, height)
     }
+
+    fn scale_factor(&self) -> ScaleFactor {
+        ScaleFactor::new(Window::scale_factor(self) as f32)
+    }
 }
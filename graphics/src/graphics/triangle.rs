@@ -1,9 +1,292 @@
+// the pipelines aren't used until `Graphics::prepare` and `Graphics::render`
+// are implemented
+#![allow(dead_code)]
+
+use std::mem;
+
+use bytemuck::{Pod, Zeroable};
+use crossd_math::Mat4;
+use wgpu::{
+    BindGroup,
+    BindGroupDescriptor,
+    BindGroupEntry,
+    BindGroupLayout,
+    BindGroupLayoutDescriptor,
+    BindGroupLayoutEntry,
+    BindingType,
+    BufferBindingType,
+    BufferSize,
+    BufferUsages,
+    IndexFormat,
+    RenderPass,
+    ShaderStages,
+    VertexAttribute,
+    VertexBufferLayout,
+    VertexFormat,
+    VertexStepMode,
+    COPY_BUFFER_ALIGNMENT,
+};
+
+use super::blending::{Backdrop, Blending, Pipelines};
 use crate::backend::Backend;
+use crate::color::{BlendMode, LinearRgba, PremulColor};
+use crate::geometry::{Physical, Rect};
+use crate::primitive::Vertex;
+use crate::tessellation::{Index, VertexBuffers};
+use crate::utils::gpu_buf::GpuBuf;
+use crate::utils::gpu_store::GpuStore;
+
+pub struct TrianglePipeline {
+    pipelines: Pipelines,
+    /// [`TriangleUniforms`] layout.
+    layout: BindGroupLayout,
+
+    /// Layers of triangle meshes.
+    layers: Vec<TriangleLayer>,
+    /// The current layer.
+    current: usize,
+}
+
+struct TriangleLayer {
+    /// Uniform bind group.
+    bgroup: BindGroup,
+    /// Uniform storage buffer.
+    uniforms: GpuStore<TriangleUniforms>,
+    /// Vertices of the mesh.
+    vertices: GpuBuf<Vertex>,
+    /// Triangles of the mesh, as bytes of either [`Index`] type.
+    indices: GpuBuf<u8>,
+    /// The format of [`TriangleLayer::indices`].
+    format: IndexFormat,
+    /// The amount of indices, without padding.
+    count: u32,
+    /// How the mesh is blended with the target.
+    blending: Blending,
+}
+
+#[repr(C)]
+#[derive(Debug, Clone, Copy, Pod, Zeroable)]
+struct TriangleUniforms {
+    trans: Mat4,
+    /// The premultiplied fill color of the mesh.
+    color: PremulColor,
+    scale: f32,
+    /// The [`BlendMode`], in order, for meshes reading the backdrop.
+    blend: u32,
+    /// Uniforms must be aligned to their largest member.
+    _padding: [u32; 2],
+}
+
+/// Shader source, with the blend modes reading the backdrop.
+const SOURCE: &str = concat!(
+    include_str!("../shader/triangle.wgsl"),
+    include_str!("../shader/blend.wgsl")
+);
 
-pub struct TrianglePipeline {}
+/// Initial [`TriangleLayer`] vertex and index buffer capacity.
+const INITIAL: usize = 2000;
+/// Layout of the [`Vertex`] buffer.
+const BUFFERS: &[VertexBufferLayout<'static>] = &[VertexBufferLayout {
+    array_stride: mem::size_of::<Vertex>() as _,
+    step_mode: VertexStepMode::Vertex,
+    attributes: &[VertexAttribute {
+        // layout for `Vertex` (`Point2`)
+        format: VertexFormat::Float32x2,
+        offset: 0,
+        shader_location: 0,
+    }],
+}];
 
 impl TrianglePipeline {
-    pub fn new(backend: &Backend) -> Self {
-        todo!()
+    pub fn new(backend: &Backend, backdrop: &Backdrop) -> Self {
+        let device = backend.device();
+
+        let layout = device.create_bind_group_layout(&BindGroupLayoutDescriptor {
+            label: Some("TrianglePipeline.layout"),
+            // `@group(0)`,
+            entries: &[BindGroupLayoutEntry {
+                // ...`@binding(0)`
+                binding: 0,
+                // the color is used by the fragment shader
+                visibility: ShaderStages::VERTEX_FRAGMENT,
+                ty: BindingType::Buffer {
+                    ty: BufferBindingType::Uniform,
+                    has_dynamic_offset: false,
+                    min_binding_size: BufferSize::new(
+                        mem::size_of::<TriangleUniforms>() as _
+                    ),
+                },
+                // none indicates that this is not an array
+                count: None,
+            }],
+        });
+
+        // tessellated triangles are clockwise on screen, as the pipelines
+        // expect
+        let pipelines = Pipelines::new(
+            backend,
+            "TrianglePipeline.pipelines",
+            SOURCE,
+            &layout,
+            backdrop,
+            BUFFERS,
+        );
+
+        Self { pipelines, layout, layers: Vec::new(), current: 0 }
+    }
+
+    /// Prepare a mesh filled with a color and drawn with `blend_mode` as the
+    /// current layer.
+    ///
+    /// The mesh can be indexed by either [`Index`] type.
+    pub fn prepare<I: Index>(
+        &mut self,
+        backend: &Backend,
+        trans: Mat4,
+        scale: f32,
+        color: LinearRgba,
+        blend_mode: BlendMode,
+        mesh: &VertexBuffers<I>,
+    ) {
+        if self.layers.len() <= self.current {
+            self.layers.push(TriangleLayer::new(backend, &self.layout));
+        }
+
+        self.pipelines.prepare(backend, Blending::of(blend_mode));
+        self.layers[self.current].prepare(backend, trans, scale, color, blend_mode, mesh);
+        self.current += 1;
+    }
+
+    /// Whether the requested layer reads the backdrop, so that the target
+    /// needs to be copied into it with [`Backdrop::copy`] right before it's
+    /// rendered, in between render passes.
+    pub fn reads_backdrop(&self, layer: usize) -> bool {
+        self.layers.get(layer).is_some_and(|layer| layer.blending.reads_backdrop())
+    }
+
+    /// Render the requested layer.
+    pub fn render<'pass>(
+        &'pass self,
+        layer: usize,
+        bounds: Rect<u32, Physical>,
+        backdrop: &'pass Backdrop,
+        rpass: &mut RenderPass<'pass>,
+    ) {
+        if let Some(layer) = self.layers.get(layer) {
+            rpass.set_pipeline(self.pipelines.get(layer.blending));
+            rpass.set_scissor_rect(
+                bounds.loc.x,
+                bounds.loc.y,
+                bounds.size.w,
+                bounds.size.h,
+            );
+            rpass.set_index_buffer(layer.indices.slice(..), layer.format);
+            rpass.set_vertex_buffer(0, layer.vertices.slice(..));
+
+            // use layer uniforms
+            rpass.set_bind_group(0, &layer.bgroup, &[]);
+
+            if layer.blending.reads_backdrop() {
+                rpass.set_bind_group(1, backdrop.bind_group(), &[]);
+            }
+
+            // draw the whole mesh
+            rpass.draw_indexed(0..layer.count, 0, 0..1);
+        }
+    }
+}
+
+impl TriangleLayer {
+    fn new(backend: &Backend, layout: &BindGroupLayout) -> Self {
+        let device = backend.device();
+
+        let uniforms = GpuStore::new(
+            device,
+            BufferUsages::UNIFORM | BufferUsages::COPY_DST,
+            Some("TriangleLayer.uniforms"),
+        );
+        let bgroup = device.create_bind_group(&BindGroupDescriptor {
+            label: Some("TriangleLayer.bgroup"),
+            layout,
+            entries: &[BindGroupEntry {
+                binding: 0,
+                resource: uniforms.buffer().as_entire_binding(),
+            }],
+        });
+
+        let vertices = GpuBuf::new(
+            device,
+            BufferUsages::VERTEX | BufferUsages::COPY_DST,
+            Some("TriangleLayer.vertices"),
+            INITIAL,
+        );
+        let indices = GpuBuf::new(
+            device,
+            BufferUsages::INDEX | BufferUsages::COPY_DST,
+            Some("TriangleLayer.indices"),
+            INITIAL * mem::size_of::<u32>(),
+        );
+
+        let blending = Blending::of(BlendMode::Normal);
+
+        Self {
+            bgroup,
+            uniforms,
+            vertices,
+            indices,
+            format: u32::FORMAT,
+            count: 0,
+            blending,
+        }
+    }
+
+    fn prepare<I: Index>(
+        &mut self,
+        backend: &Backend,
+        trans: Mat4,
+        scale: f32,
+        color: LinearRgba,
+        blend_mode: BlendMode,
+        mesh: &VertexBuffers<I>,
+    ) {
+        let device = backend.device();
+        let queue = backend.queue();
+
+        let color = backend.target_color(color);
+
+        self.blending = Blending::of(blend_mode);
+        self.uniforms
+            .write(queue, TriangleUniforms::new(trans, scale, color, blend_mode));
+        self.vertices.write(device, queue, &mesh.vertices);
+        self.write_indices(backend, &mesh.indices);
+    }
+
+    /// Write `indices` as bytes, padded to the 4 bytes copies to the GPU are
+    /// made in.
+    fn write_indices<I: Index>(&mut self, backend: &Backend, indices: &[I]) {
+        let bytes: &[u8] = bytemuck::cast_slice(indices);
+        let padding =
+            bytes.len().next_multiple_of(COPY_BUFFER_ALIGNMENT as _) - bytes.len();
+
+        if padding == 0 {
+            self.indices.write(backend.device(), backend.queue(), bytes);
+        } else {
+            let padded = [bytes, &[0; COPY_BUFFER_ALIGNMENT as _][..padding]].concat();
+
+            self.indices.write(backend.device(), backend.queue(), &padded);
+        }
+
+        self.format = I::FORMAT;
+        self.count = indices.len() as _;
+    }
+}
+
+impl TriangleUniforms {
+    /// Create uniforms with given values.
+    ///
+    /// The color is as written to the target, see
+    /// [`Backend::target_color`].
+    const fn new(trans: Mat4, scale: f32, color: PremulColor, blend: BlendMode) -> Self {
+        Self { trans, color, scale, blend: blend as u32, _padding: [0; 2] }
     }
 }
@@ -5,8 +5,8 @@
 use std::fmt;
 use std::sync::Arc;
 
-use color::Color;
-use geometry::Size2;
+use color::{BlendMode, Color};
+use geometry::{Physical, ScaleFactor, Size2};
 use raw_window_handle::{HasRawDisplayHandle, HasRawWindowHandle};
 
 pub extern crate bytemuck;
@@ -19,12 +19,16 @@ extern crate self as crossd_graphics;
 pub extern crate tracing;
 pub extern crate wgpu;
 
+pub mod atlas;
 pub mod backend;
 pub mod color;
 pub mod geometry;
 /// Implementations for [`Graphics`], [`Frame`], etc.
 mod graphics;
+pub mod paint;
 pub mod primitive;
+pub mod spatial;
+pub mod tessellation;
 /// Various internal utilities.
 mod utils;
 
@@ -43,6 +47,8 @@ pub struct Frame<'pass, T: Target> {
     encoder: wgpu::CommandEncoder,
 
     target: &'pass T,
+    /// How following draws are blended with what's below them.
+    blend_mode: BlendMode,
 }
 
 /// A window-backed target.
@@ -55,13 +61,24 @@ pub struct Surface<T: SurfaceTarget> {
 /// Trait for types that can be used with [`Surface`] to be drawn to.
 pub trait SurfaceTarget: HasRawWindowHandle + HasRawDisplayHandle {
     /// The size of the surface target.
-    fn size(&self) -> Size2<u32>;
+    fn size(&self) -> Size2<u32, Physical>;
+
+    /// The amount of physical pixels per logical pixel.
+    ///
+    /// Drawing doesn't scale by it, logical geometry needs to be converted
+    /// with it first, see
+    /// [`Point2::to_physical`](geometry::Point2::to_physical).
+    ///
+    /// Defaults to [`ScaleFactor::ONE`].
+    fn scale_factor(&self) -> ScaleFactor {
+        ScaleFactor::ONE
+    }
 }
 
 /// A pixel-buffer-backed [target](Targetable).
 pub struct Canvas {
     /// Dimensions of the buffer.
-    size: Size2,
+    size: Size2<u32, Physical>,
     /// The pixels in the buffer.
     pixels: Vec<Color>,
 }
@@ -72,7 +89,18 @@ pub trait Target {
     type Error: fmt::Debug;
 
     /// Size of the drawable area in pixels.
-    fn size(&self) -> Size2<u32>;
+    fn size(&self) -> Size2<u32, Physical>;
+
+    /// The amount of physical pixels per logical pixel.
+    ///
+    /// Drawing doesn't scale by it, logical geometry needs to be converted
+    /// with it first, see
+    /// [`Point2::to_physical`](geometry::Point2::to_physical).
+    ///
+    /// Defaults to [`ScaleFactor::ONE`].
+    fn scale_factor(&self) -> ScaleFactor {
+        ScaleFactor::ONE
+    }
 
     /// Present to this target to be displayed.
     fn present(
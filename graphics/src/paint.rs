@@ -0,0 +1,93 @@
+//! Ways of filling shapes with color.
+//!
+//! A [`Paint`] is either a solid color or a [`Gradient`]. Gradients can
+//! be sampled on the CPU, which is what the renderer is tested against.
+//! Both convert into a paint, so a [`Color`] can be passed wherever one is
+//! expected, like to [`Quad::new`](crate::primitive::Quad::new).
+//!
+//! ```
+//! # use crossd_graphics::color::Color;
+//! # use crossd_graphics::geometry::Point2;
+//! # use crossd_graphics::paint::Gradient;
+//! #
+//! let gradient = Gradient::linear(Point2::new(0.0, 0.0), Point2::new(100.0, 0.0))
+//!     .stop(0.0, Color::BLACK)
+//!     .stop(1.0, Color::WHITE);
+//!
+//! assert_eq!(Color::from(gradient.sample(Point2::new(-10.0, 5.0))), Color::BLACK);
+//! assert_eq!(Color::from(gradient.sample(Point2::new(100.0, 5.0))), Color::WHITE);
+//! ```
+
+use crate::color::{Color, ColorSpace, LinearRgba};
+use crate::geometry::Point2;
+
+/// Implementations for [`Paint`], [`Gradient`] and [`GradientKind`].
+mod gradient;
+
+/// What a shape is filled with.
+#[derive(Debug, Clone, PartialEq)]
+pub enum Paint {
+    /// A single color.
+    ///
+    /// Components outside of `0.0..=1.0` show colors outside of sRGB on
+    /// targets with extended range, see [`Backend::writes_linear`].
+    ///
+    /// [`Backend::writes_linear`]: crate::backend::Backend::writes_linear
+    Solid(LinearRgba),
+    /// Colors changing across the shape.
+    Gradient(Gradient),
+}
+
+/// Colors changing smoothly across a shape.
+///
+/// Each point is given an offset along the gradient, depending on it's
+/// [`GradientKind`]. The color at an offset is interpolated between the
+/// [`ColorStop`]s around it in the gradient's [`ColorSpace`], and offsets
+/// outside of `0.0..=1.0` are brought back into it according to the
+/// [`Spread`].
+#[derive(Debug, Clone, PartialEq)]
+pub struct Gradient {
+    /// The shape of the gradient.
+    pub kind: GradientKind,
+    /// The colors of the gradient, sorted by offset.
+    pub stops: Vec<ColorStop>,
+    /// How the gradient continues outside of it's stops.
+    pub spread: Spread,
+    /// The color space colors are interpolated in.
+    pub space: ColorSpace,
+}
+
+/// The shape of a [`Gradient`], deciding the offset of each point.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum GradientKind {
+    /// Offsets go from `0.0` at `start` to `1.0` at `end`, and are the same
+    /// along lines perpendicular to it.
+    Linear { start: Point2, end: Point2 },
+    /// Offsets go from `0.0` at `center` to `1.0` on the circle with
+    /// `radius` around it.
+    Radial { center: Point2, radius: f32 },
+    /// Offsets go from `0.0` to `1.0` once around `center`, clockwise on
+    /// screen, starting at `angle` radians from the x axis.
+    Conic { center: Point2, angle: f32 },
+}
+
+/// A color at an offset along a [`Gradient`].
+#[derive(Debug, Default, Clone, Copy, PartialEq)]
+pub struct ColorStop {
+    /// The offset of the stop, usually between `0.0` and `1.0`.
+    pub offset: f32,
+    /// The color at the offset.
+    pub color: Color,
+}
+
+/// How a [`Gradient`] continues for offsets outside of `0.0..=1.0`.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Spread {
+    /// Continue with the color of the nearest end.
+    #[default]
+    Pad,
+    /// Start over from the beginning.
+    Repeat,
+    /// Go back and forth, mirroring every other repetition.
+    Reflect,
+}
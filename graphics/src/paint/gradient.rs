@@ -0,0 +1,322 @@
+use std::f32::consts::TAU;
+
+use super::{ColorStop, Gradient, GradientKind, Paint, Spread};
+use crate::color::{Color, ColorSpace, LinearRgba, PremulColor};
+use crate::geometry::Point2;
+
+impl Paint {
+    /// The color of the paint at `point`, see [`Gradient::sample`].
+    #[must_use]
+    pub fn sample(&self, point: Point2) -> LinearRgba {
+        match self {
+            Self::Solid(color) => *color,
+            Self::Gradient(gradient) => gradient.sample(point),
+        }
+    }
+}
+
+impl Gradient {
+    /// A gradient of the given kind without any stops, which is transparent.
+    #[must_use]
+    pub fn new(kind: GradientKind) -> Self {
+        Self {
+            kind,
+            stops: Vec::new(),
+            spread: Spread::default(),
+            space: ColorSpace::default(),
+        }
+    }
+
+    /// A gradient from `start` to `end`, see [`GradientKind::Linear`].
+    #[must_use]
+    pub fn linear(start: Point2, end: Point2) -> Self {
+        Self::new(GradientKind::Linear { start, end })
+    }
+
+    /// A gradient out from `center`, see [`GradientKind::Radial`].
+    #[must_use]
+    pub fn radial(center: Point2, radius: f32) -> Self {
+        Self::new(GradientKind::Radial { center, radius })
+    }
+
+    /// A gradient around `center`, see [`GradientKind::Conic`].
+    #[must_use]
+    pub fn conic(center: Point2, angle: f32) -> Self {
+        Self::new(GradientKind::Conic { center, angle })
+    }
+
+    /// Add a stop with `color` at `offset`.
+    ///
+    /// Stops are kept sorted by offset. A stop at the same offset as an
+    /// existing one goes after it, so two stops at the same offset make a
+    /// hard edge between colors.
+    #[must_use]
+    pub fn stop(mut self, offset: f32, color: Color) -> Self {
+        let i = self.stops.partition_point(|stop| stop.offset <= offset);
+        self.stops.insert(i, ColorStop { offset, color });
+
+        self
+    }
+
+    /// Set how the gradient continues outside of it's stops.
+    #[must_use]
+    pub fn spread(self, spread: Spread) -> Self {
+        Self { spread, ..self }
+    }
+
+    /// Set the color space colors are interpolated in.
+    #[must_use]
+    pub fn space(self, space: ColorSpace) -> Self {
+        Self { space, ..self }
+    }
+
+    /// The color at `point`.
+    ///
+    /// A linear gradient with the same start and end, or a radial gradient
+    /// with a radius of `0.0`, has the color of it's last stop everywhere.
+    ///
+    /// ```
+    /// # use crossd_graphics::color::{Color, ColorSpace};
+    /// # use crossd_graphics::geometry::Point2;
+    /// # use crossd_graphics::paint::{Gradient, Spread};
+    /// #
+    /// let gradient = Gradient::radial(Point2::new(50.0, 50.0), 10.0)
+    ///     .stop(0.0, Color::RED)
+    ///     .stop(1.0, Color::BLUE)
+    ///     .spread(Spread::Reflect)
+    ///     .space(ColorSpace::Srgb);
+    ///
+    /// let sample = |x, y| Color::from(gradient.sample(Point2::new(x, y)));
+    ///
+    /// assert_eq!(sample(52.5, 50.0), Color::new_opaque(191, 0, 64));
+    /// assert_eq!(sample(50.0, 60.0), Color::BLUE);
+    /// // reflected back towards red
+    /// assert_eq!(sample(50.0, 70.0), Color::RED);
+    /// ```
+    #[must_use]
+    pub fn sample(&self, point: Point2) -> LinearRgba {
+        match self.kind.offset(point) {
+            Some(offset) => self.color_at(offset),
+            None => self
+                .stops
+                .last()
+                .map_or(LinearRgba::TRANSPARENT, |stop| stop.color.into()),
+        }
+    }
+
+    /// The color at `offset` along the gradient, after spreading it.
+    #[must_use]
+    pub fn color_at(&self, offset: f32) -> LinearRgba {
+        self.interpolate(self.spread.apply(offset))
+    }
+
+    /// The colors at `len` offsets evenly spaced from `0.0` to `1.0`, ignoring
+    /// the spread.
+    ///
+    /// Offsets in between the samples can be linearly interpolated between
+    /// them, which is how the renderer draws gradients.
+    #[must_use]
+    pub fn ramp(&self, len: usize) -> Vec<PremulColor> {
+        let step = 1.0 / len.saturating_sub(1).max(1) as f32;
+
+        (0..len).map(|i| self.interpolate(i as f32 * step).premultiply()).collect()
+    }
+
+    /// The color at `offset` between the stops, or transparent without any.
+    fn interpolate(&self, offset: f32) -> LinearRgba {
+        let (Some(first), Some(last)) = (self.stops.first(), self.stops.last()) else {
+            return LinearRgba::TRANSPARENT;
+        };
+
+        if offset <= first.offset {
+            return first.color.into();
+        }
+
+        if offset >= last.offset {
+            return last.color.into();
+        }
+
+        // between the last stop at or before the offset and the one after it
+        let i = self.stops.partition_point(|stop| stop.offset <= offset);
+        let (from, to) = (self.stops[i - 1], self.stops[i]);
+        let t = (offset - from.offset) / (to.offset - from.offset);
+
+        self.space.mix(from.color.into(), to.color.into(), t)
+    }
+}
+
+impl GradientKind {
+    /// The offset of `point` along the gradient, before spreading it.
+    ///
+    /// Returns `None` for linear gradients that start and end at the same
+    /// point and radial gradients with a radius of `0.0`.
+    #[must_use]
+    pub fn offset(self, point: Point2) -> Option<f32> {
+        match self {
+            Self::Linear { start, end } => {
+                let direction = end - start;
+                let length = direction.dot(direction);
+
+                (length > 0.0).then(|| (point - start).dot(direction) / length)
+            },
+            Self::Radial { center, radius } => {
+                (radius > 0.0).then(|| (point - center).length() / radius)
+            },
+            Self::Conic { center, angle } => {
+                let v = point - center;
+
+                Some(((v.y.atan2(v.x) - angle) / TAU).rem_euclid(1.0))
+            },
+        }
+    }
+}
+
+impl Spread {
+    /// Bring `offset` into `0.0..=1.0`, or leave it for the stops at the ends
+    /// to cover if padding.
+    fn apply(self, offset: f32) -> f32 {
+        match self {
+            Self::Pad => offset,
+            Self::Repeat => offset.rem_euclid(1.0),
+            Self::Reflect => {
+                let offset = offset.rem_euclid(2.0);
+
+                if offset > 1.0 {
+                    2.0 - offset
+                } else {
+                    offset
+                }
+            },
+        }
+    }
+}
+
+impl From<Color> for Paint {
+    fn from(color: Color) -> Self {
+        Self::Solid(color.into())
+    }
+}
+
+impl From<LinearRgba> for Paint {
+    fn from(color: LinearRgba) -> Self {
+        Self::Solid(color)
+    }
+}
+
+impl From<Gradient> for Paint {
+    fn from(gradient: Gradient) -> Self {
+        Self::Gradient(gradient)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use std::f32::consts::FRAC_PI_2;
+
+    use super::super::{Gradient, GradientKind, Spread};
+    use crate::color::{Color, ColorSpace};
+    use crate::geometry::Point2;
+
+    fn assert_close(a: f32, b: f32) {
+        assert!((a - b).abs() < 1e-5, "{a} is not close to {b}");
+    }
+
+    #[test]
+    fn offsets() {
+        let offset = |kind: GradientKind, x, y| kind.offset(Point2::new(x, y)).unwrap();
+
+        let linear = GradientKind::Linear {
+            start: Point2::new(10.0, 10.0),
+            end: Point2::new(10.0, 30.0),
+        };
+        assert_close(offset(linear, 10.0, 10.0), 0.0);
+        assert_close(offset(linear, -50.0, 20.0), 0.5);
+        assert_close(offset(linear, 0.0, 50.0), 2.0);
+        assert_close(offset(linear, 0.0, 0.0), -0.5);
+
+        let radial = GradientKind::Radial { center: Point2::new(0.0, 0.0), radius: 4.0 };
+        assert_close(offset(radial, 0.0, 0.0), 0.0);
+        assert_close(offset(radial, 3.0, 4.0), 1.25);
+
+        // clockwise on screen, starting straight down
+        let conic =
+            GradientKind::Conic { center: Point2::new(0.0, 0.0), angle: FRAC_PI_2 };
+        assert_close(offset(conic, 0.0, 1.0), 0.0);
+        assert_close(offset(conic, -1.0, 0.0), 0.25);
+        assert_close(offset(conic, 0.0, -1.0), 0.5);
+        assert_close(offset(conic, 1.0, 0.0), 0.75);
+
+        let point = Point2::new(1.0, 1.0);
+        assert_eq!(GradientKind::Linear { start: point, end: point }.offset(point), None);
+        assert_eq!(
+            GradientKind::Radial { center: point, radius: 0.0 }.offset(point),
+            None
+        );
+    }
+
+    #[test]
+    fn spread() {
+        let gradient = Gradient::linear(Point2::new(0.0, 0.0), Point2::new(1.0, 0.0))
+            .stop(0.0, Color::BLACK)
+            .stop(1.0, Color::WHITE)
+            .space(ColorSpace::Srgb);
+        let color = |gradient: &Gradient, offset| Color::from(gradient.color_at(offset));
+        let gray = Color::splat_opaque(64);
+
+        assert_eq!(color(&gradient, -0.75), Color::BLACK);
+        assert_eq!(color(&gradient, 0.25), gray);
+        assert_eq!(color(&gradient, 1.75), Color::WHITE);
+
+        let gradient = gradient.spread(Spread::Repeat);
+        assert_eq!(color(&gradient, -0.75), gray);
+        assert_eq!(color(&gradient, 1.25), gray);
+
+        let gradient = gradient.spread(Spread::Reflect);
+        assert_eq!(color(&gradient, -0.25), gray);
+        assert_eq!(color(&gradient, 1.75), gray);
+        assert_eq!(color(&gradient, 2.25), gray);
+    }
+
+    #[test]
+    fn stops() {
+        let empty = Gradient::radial(Point2::new(0.0, 0.0), 1.0);
+        assert_eq!(Color::from(empty.color_at(0.5)), Color::TRANSPARENT);
+
+        // added out of order, with a hard edge at 0.5
+        let gradient = empty
+            .stop(0.5, Color::BLUE)
+            .stop(0.5, Color::GREEN)
+            .stop(0.25, Color::RED)
+            .stop(0.75, Color::WHITE);
+        let offsets: Vec<f32> = gradient.stops.iter().map(|stop| stop.offset).collect();
+        let color = |offset| Color::from(gradient.color_at(offset));
+
+        assert_eq!(offsets, [0.25, 0.5, 0.5, 0.75]);
+        assert_eq!(color(0.0), Color::RED);
+        assert_eq!(
+            color(0.375),
+            Color::from(gradient.space.mix(Color::RED.into(), Color::BLUE.into(), 0.5))
+        );
+        assert_eq!(color(0.5), Color::GREEN);
+        assert_eq!(color(1.0), Color::WHITE);
+
+        let point = Gradient::radial(Point2::new(0.0, 0.0), 0.0)
+            .stop(0.0, Color::RED)
+            .stop(1.0, Color::BLUE);
+        assert_eq!(Color::from(point.sample(Point2::new(0.0, 0.0))), Color::BLUE);
+    }
+
+    #[test]
+    fn ramp() {
+        let gradient = Gradient::conic(Point2::new(0.0, 0.0), 0.0)
+            .stop(0.0, Color::new(255, 0, 0, 0))
+            .stop(1.0, Color::BLUE)
+            .spread(Spread::Repeat);
+        let ramp = gradient.ramp(5);
+
+        assert_eq!(ramp.len(), 5);
+        assert_eq!(Color::from(ramp[0]), Color::TRANSPARENT);
+        assert_eq!(Color::from(ramp[4]), Color::BLUE);
+        assert_eq!(ramp[2], gradient.color_at(0.5).premultiply());
+    }
+}
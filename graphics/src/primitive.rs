@@ -1,18 +1,48 @@
 use bytemuck::{Pod, Zeroable};
 
-use crate::color::Color;
 use crate::geometry::{Point2, Size2};
+use crate::paint::Paint;
 
 /// A drawable rectangle.
-#[repr(C)]
-#[derive(Debug, Clone, Copy, PartialEq, PartialOrd, Pod, Zeroable)]
+#[derive(Debug, Clone, PartialEq)]
 pub struct Quad {
     /// The location of the quad's upper-left corner.
     pub loc: Point2,
     /// The size of the quad.
-    pub size: Size2,
-    /// The color of the quad's fill.
-    pub color: Color,
+    pub size: Size2<f32>,
+    /// What the quad is filled with.
+    ///
+    /// Gradients are positioned in the same coordinates as the quad, not
+    /// relative to it.
+    pub paint: Paint,
+}
+
+impl Quad {
+    /// A new quad filled with a color or gradient.
+    ///
+    /// ```
+    /// # use crossd_graphics::color::Color;
+    /// # use crossd_graphics::geometry::{Point2, Size2};
+    /// # use crossd_graphics::paint::{Gradient, Paint};
+    /// # use crossd_graphics::primitive::Quad;
+    /// #
+    /// let loc = Point2::new(10.0, 10.0);
+    /// let size = Size2::new(100.0, 50.0);
+    ///
+    /// let solid = Quad::new(loc, size, Color::RED);
+    /// assert_eq!(solid.paint, Paint::from(Color::RED));
+    ///
+    /// // the gradient goes across the quad, from it's left to it's right side
+    /// let gradient = Gradient::linear(loc, Point2::new(110.0, 10.0))
+    ///     .stop(0.0, Color::RED)
+    ///     .stop(1.0, Color::BLUE);
+    /// let shaded = Quad::new(loc, size, gradient);
+    /// ```
+    #[inline]
+    #[must_use]
+    pub fn new(loc: Point2, size: Size2<f32>, paint: impl Into<Paint>) -> Self {
+        Self { loc, size, paint: paint.into() }
+    }
 }
 
 /// A vertex, the building block of larger shapes.
@@ -0,0 +1,179 @@
+// Blend modes that read the target, appended to the shaders using them.
+//
+// Must match `BlendMode::blend`.
+
+// a copy of the target from before the draw
+@group(1) @binding(0)
+var backdrop: texture_2d<f32>;
+
+// the premultiplied color to composite over the target with source-over, so
+// that `src` is blended with `mode` at the fragment `position`
+fn blend(src: vec4<f32>, mode: u32, position: vec4<f32>) -> vec4<f32> {
+    let dst = textureLoad(backdrop, vec2<i32>(position.xy), 0);
+
+    if src.a <= 0.0 || dst.a <= 0.0 {
+        return src;
+    }
+
+    let mixed = mix_colors(mode, dst.rgb / dst.a, src.rgb / src.a);
+
+    // source-over adds the backdrop where the source doesn't cover it
+    return vec4<f32>(src.rgb * (1.0 - dst.a) + src.a * dst.a * mixed, src.a);
+}
+
+// mix straight source and backdrop colors, with `mode` as the index of a
+// `BlendMode`
+fn mix_colors(mode: u32, b: vec3<f32>, s: vec3<f32>) -> vec3<f32> {
+    switch mode {
+        // multiply
+        case 1u: {
+            return b * s;
+        }
+        // screen
+        case 2u: {
+            return screen(b, s);
+        }
+        // overlay
+        case 3u: {
+            return hard_light(s, b);
+        }
+        // darken
+        case 4u: {
+            return min(b, s);
+        }
+        // lighten
+        case 5u: {
+            return max(b, s);
+        }
+        // color dodge
+        case 6u: {
+            return vec3<f32>(
+                color_dodge(b.r, s.r),
+                color_dodge(b.g, s.g),
+                color_dodge(b.b, s.b),
+            );
+        }
+        // color burn
+        case 7u: {
+            return vec3<f32>(
+                color_burn(b.r, s.r),
+                color_burn(b.g, s.g),
+                color_burn(b.b, s.b),
+            );
+        }
+        // hard light
+        case 8u: {
+            return hard_light(b, s);
+        }
+        // soft light
+        case 9u: {
+            return vec3<f32>(
+                soft_light(b.r, s.r),
+                soft_light(b.g, s.g),
+                soft_light(b.b, s.b),
+            );
+        }
+        // difference
+        case 10u: {
+            return abs(b - s);
+        }
+        // exclusion
+        case 11u: {
+            return b + s - 2.0 * b * s;
+        }
+        // hue
+        case 12u: {
+            return set_lum(set_sat(s, sat(b)), lum(b));
+        }
+        // saturation
+        case 13u: {
+            return set_lum(set_sat(b, sat(s)), lum(b));
+        }
+        // color
+        case 14u: {
+            return set_lum(s, lum(b));
+        }
+        // luminosity
+        case 15u: {
+            return set_lum(b, lum(s));
+        }
+        default: {
+            return s;
+        }
+    }
+}
+
+fn screen(b: vec3<f32>, s: vec3<f32>) -> vec3<f32> {
+    return b + s - b * s;
+}
+
+fn hard_light(b: vec3<f32>, s: vec3<f32>) -> vec3<f32> {
+    return select(screen(b, 2.0 * s - 1.0), b * 2.0 * s, s <= vec3<f32>(0.5));
+}
+
+fn color_dodge(b: f32, s: f32) -> f32 {
+    if b == 0.0 {
+        return 0.0;
+    } else if s >= 1.0 {
+        return 1.0;
+    }
+
+    return min(b / (1.0 - s), 1.0);
+}
+
+fn color_burn(b: f32, s: f32) -> f32 {
+    if b >= 1.0 {
+        return 1.0;
+    } else if s == 0.0 {
+        return 0.0;
+    }
+
+    return 1.0 - min((1.0 - b) / s, 1.0);
+}
+
+fn soft_light(b: f32, s: f32) -> f32 {
+    if s <= 0.5 {
+        return b - (1.0 - 2.0 * s) * b * (1.0 - b);
+    }
+
+    let d = select(sqrt(b), ((16.0 * b - 12.0) * b + 4.0) * b, b <= 0.25);
+
+    return b + (2.0 * s - 1.0) * (d - b);
+}
+
+fn lum(c: vec3<f32>) -> f32 {
+    return dot(c, vec3<f32>(0.3, 0.59, 0.11));
+}
+
+fn set_lum(color: vec3<f32>, lightness: f32) -> vec3<f32> {
+    var c = color + (lightness - lum(color));
+
+    let l = lum(c);
+    let low = min(c.r, min(c.g, c.b));
+    let high = max(c.r, max(c.g, c.b));
+
+    if low < 0.0 {
+        c = l + (c - l) * l / (l - low);
+    }
+
+    if high > 1.0 {
+        c = l + (c - l) * (1.0 - l) / (high - l);
+    }
+
+    return c;
+}
+
+fn sat(c: vec3<f32>) -> f32 {
+    return max(c.r, max(c.g, c.b)) - min(c.r, min(c.g, c.b));
+}
+
+fn set_sat(c: vec3<f32>, s: f32) -> vec3<f32> {
+    let low = min(c.r, min(c.g, c.b));
+    let high = max(c.r, max(c.g, c.b));
+
+    if high > low {
+        return (c - low) * s / (high - low);
+    }
+
+    return vec3<f32>(0.0);
+}
@@ -1,15 +1,28 @@
 struct Uniforms {
-    trans: Mat4x4<f32>,
+    trans: mat4x4<f32>,
     scale: f32,
 }
 
 struct VertexInput {
+    // corner of the unit square
     @location(0)
-    loc: vec2<f32>,
+    vertex: vec2<f32>,
     @location(1)
-    color: vec4<f32>,
+    loc: vec2<f32>,
     @location(2)
-    scale: vec2<f32>,
+    size: vec2<f32>,
+    @location(3)
+    color: vec4<f32>,
+    @location(4)
+    params: vec4<f32>,
+    @location(5)
+    kind: u32,
+    @location(6)
+    spread: u32,
+    @location(7)
+    ramp: u32,
+    @location(8)
+    blend: u32,
 }
 
 struct VertexOutput {
@@ -17,43 +30,110 @@ struct VertexOutput {
     position: vec4<f32>,
     @location(0)
     color: vec4<f32>,
+    // in logical pixels, like the gradient
     @location(1)
-    loc: vec2<f32>,
+    point: vec2<f32>,
     @location(2)
-    scale: vec2<f32>,
+    params: vec4<f32>,
+    @location(3) @interpolate(flat)
+    kind: u32,
+    @location(4) @interpolate(flat)
+    spread: u32,
+    @location(5) @interpolate(flat)
+    ramp: u32,
+    // `BlendMode` of quads reading the backdrop
+    @location(6) @interpolate(flat)
+    blend: u32,
 }
 
+// `kind` of solid quads, gradients are linear, radial and conic after it
+const SOLID: u32 = 0u;
+// colors per gradient
+const RAMP_LEN: u32 = 256u;
+const TAU: f32 = 6.283185307179586;
+
 @group(0) @binding(0)
 var<uniform> uniforms: Uniforms;
+// premultiplied colors of gradients
+@group(0) @binding(1)
+var<storage, read> ramps: array<vec4<f32>>;
 
 @vertex
 fn vert(in: VertexInput) -> VertexOutput {
-    var loc: vec2<f32> = in.pos * uniforms.scale;
-    var scale: vec2<f32> = in.scale * uniforms.scale;
-
-    var trans: mat4x4<f32> = mat4x4<f32>(
-        vec4<f32>(scale.x + 1.0, 0.0, 0.0, 0.0),
-        vec4<f32>(0.0, scale.y + 1.0, 0.0, 0.0),
-        vec4<f32>(0.0, 0.0, 1.0, 0.0),
-        vec4<f32>(loc - vec2<f32>(0.5, 0.5), 0.0, 1.0)
-    );
+    let point = in.loc + in.vertex * in.size;
 
     var out: VertexOutput;
 
-    out.position =
-        uniforms.trans
-        * trans
-        * vec4<f32>(in.loc, 0.0, 1.0);
+    out.position = uniforms.trans * vec4<f32>(point * uniforms.scale, 0.0, 1.0);
     out.color = in.color;
-    out.loc = loc;
-    out.scale = scale;
+    out.point = point;
+    out.params = in.params;
+    out.kind = in.kind;
+    out.spread = in.spread;
+    out.ramp = in.ramp;
+    out.blend = in.blend;
 
     return out;
 }
 
+// the offset along the gradient, before spreading it
+fn offset(in: VertexOutput) -> f32 {
+    switch in.kind {
+        // linear
+        case 1u: {
+            let start = in.params.xy;
+            let direction = in.params.zw - start;
+
+            return dot(in.point - start, direction) / dot(direction, direction);
+        }
+        // radial
+        case 2u: {
+            return distance(in.point, in.params.xy) / in.params.z;
+        }
+        // conic
+        default: {
+            let v = in.point - in.params.xy;
+
+            return fract((atan2(v.y, v.x) - in.params.z) / TAU);
+        }
+    }
+}
+
+// bring the offset into `0.0..=1.0` by padding, repeating or reflecting it
+fn spread(offset: f32, mode: u32) -> f32 {
+    switch mode {
+        case 1u: {
+            return fract(offset);
+        }
+        case 2u: {
+            return 1.0 - abs(fract(offset / 2.0) * 2.0 - 1.0);
+        }
+        default: {
+            return clamp(offset, 0.0, 1.0);
+        }
+    }
+}
+
+// the premultiplied color of the quad
+fn color(in: VertexOutput) -> vec4<f32> {
+    if in.kind == SOLID {
+        return in.color;
+    }
+
+    let x = spread(offset(in), in.spread) * f32(RAMP_LEN - 1u);
+    let i = min(u32(x), RAMP_LEN - 2u);
+    let start = in.ramp + i;
+
+    return mix(ramps[start], ramps[start + 1u], x - f32(i));
+}
+
 @fragment
 fn frag(in: VertexOutput) -> @location(0) vec4<f32> {
-    var out: vec4<f32> = in.color;
+    return color(in);
+}
 
-    return out;
-}
\ No newline at end of file
+// for blend modes reading the target, see `blend.wgsl`
+@fragment
+fn frag_backdrop(in: VertexOutput) -> @location(0) vec4<f32> {
+    return blend(color(in), in.blend, in.position);
+}
@@ -1,11 +1,40 @@
-struct Globals {
-    transform: mat4x4<f32>,
+struct Uniforms {
+    trans: mat4x4<f32>,
+    color: vec4<f32>,
+    scale: f32,
+    // `BlendMode` of meshes reading the backdrop
+    blend: u32,
 }
 
 struct VertexInput {
+    @location(0)
+    loc: vec2<f32>,
+}
+
+struct VertexOutput {
     @builtin(position)
-    point: vec3<f32>,
+    position: vec4<f32>,
 }
 
 @group(0) @binding(0)
-var<uniform> globals: Globals;
+var<uniform> uniforms: Uniforms;
+
+@vertex
+fn vert(in: VertexInput) -> VertexOutput {
+    var out: VertexOutput;
+
+    out.position = uniforms.trans * vec4<f32>(in.loc * uniforms.scale, 0.0, 1.0);
+
+    return out;
+}
+
+@fragment
+fn frag(in: VertexOutput) -> @location(0) vec4<f32> {
+    return uniforms.color;
+}
+
+// for blend modes reading the target, see `blend.wgsl`
+@fragment
+fn frag_backdrop(in: VertexOutput) -> @location(0) vec4<f32> {
+    return blend(uniforms.color, uniforms.blend, in.position);
+}
@@ -0,0 +1,46 @@
+//! Fast lookup of rectangles by area or point.
+//!
+//! A [`SpatialIndex`] answers which items intersect a rectangle, for culling
+//! whatever is outside the viewport, and which items are under a point, for
+//! picking. Items are identified by their index, for example in a list of
+//! quads.
+//!
+//! ```
+//! # use crossd_graphics::geometry::{Point2, Rect, Size2};
+//! # use crossd_graphics::spatial::SpatialIndex;
+//! #
+//! let rect = |x, y| Rect::new(Point2::new(x, y), Size2::new(10.0, 10.0));
+//! let mut index =
+//!     SpatialIndex::from_rects([rect(0.0, 0.0), rect(5.0, 5.0), rect(100.0, 0.0)]);
+//!
+//! assert_eq!(index.query_point(Point2::new(7.0, 7.0)), [0, 1]);
+//! assert_eq!(index.query_rect(rect(95.0, -5.0)), [2]);
+//!
+//! // move the last item next to the others
+//! index.insert(2, rect(8.0, 0.0));
+//! assert_eq!(index.query_point(Point2::new(9.0, 1.0)), [0, 2]);
+//! ```
+
+use std::collections::HashMap;
+
+use crate::geometry::Rect;
+
+/// Implementations for [`SpatialIndex`].
+mod index;
+
+/// A uniform grid of square cells, each listing the items overlapping it.
+///
+/// Items covering very many cells are kept in a separate list that every
+/// query goes through.
+#[derive(Debug, Clone)]
+pub struct SpatialIndex {
+    /// The width and height of each cell, in logical pixels.
+    cell_size: f32,
+    /// The items overlapping each cell, by the cell's column and row.
+    cells: HashMap<(i32, i32), Vec<usize>>,
+    /// Items that are in no cell, because they cover too many.
+    large: Vec<usize>,
+    /// The bounds of each item, or `None` if there is no item with that
+    /// index.
+    items: Vec<Option<Rect>>,
+}
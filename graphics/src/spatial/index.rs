@@ -0,0 +1,297 @@
+use std::collections::HashMap;
+use std::ops::RangeInclusive;
+
+use super::SpatialIndex;
+use crate::geometry::{Point2, Rect};
+
+impl SpatialIndex {
+    /// The default cell size, in logical pixels.
+    pub const DEFAULT_CELL_SIZE: f32 = 64.0;
+
+    /// A new empty index with cells of the given size.
+    ///
+    /// Cells a bit larger than typical items work best.
+    #[inline]
+    #[must_use]
+    pub fn new(cell_size: f32) -> Self {
+        Self { cell_size, cells: HashMap::new(), large: Vec::new(), items: Vec::new() }
+    }
+
+    /// An index of the rectangles, identified by their position.
+    #[must_use]
+    pub fn from_rects(rects: impl IntoIterator<Item = Rect>) -> Self {
+        let mut index = Self::default();
+
+        for (id, rect) in rects.into_iter().enumerate() {
+            index.insert(id, rect);
+        }
+
+        index
+    }
+
+    /// The amount of items.
+    #[must_use]
+    pub fn len(&self) -> usize {
+        self.items.iter().flatten().count()
+    }
+
+    /// Whether there are no items.
+    #[must_use]
+    pub fn is_empty(&self) -> bool {
+        self.items.iter().all(Option::is_none)
+    }
+
+    /// The bounds of an item.
+    #[inline]
+    #[must_use]
+    pub fn get(&self, id: usize) -> Option<Rect> {
+        self.items.get(id).copied().flatten()
+    }
+
+    /// Add an item, or move it if there already is one with the same index.
+    ///
+    /// Returns the previous bounds of the item.
+    pub fn insert(&mut self, id: usize, rect: Rect) -> Option<Rect> {
+        let old = self.remove(id);
+
+        if self.items.len() <= id {
+            self.items.resize(id + 1, None);
+        }
+
+        self.items[id] = Some(rect);
+
+        match self.cell_range(rect) {
+            Some((columns, rows)) => {
+                for column in columns {
+                    for row in rows.clone() {
+                        self.cells.entry((column, row)).or_default().push(id);
+                    }
+                }
+            },
+            None => self.large.push(id),
+        }
+
+        old
+    }
+
+    /// Remove an item.
+    ///
+    /// Returns the bounds of the item, if there was one.
+    pub fn remove(&mut self, id: usize) -> Option<Rect> {
+        let rect = self.items.get_mut(id)?.take()?;
+
+        match self.cell_range(rect) {
+            Some((columns, rows)) => {
+                for column in columns {
+                    for row in rows.clone() {
+                        let Some(items) = self.cells.get_mut(&(column, row)) else {
+                            continue;
+                        };
+
+                        items.retain(|item| *item != id);
+
+                        if items.is_empty() {
+                            self.cells.remove(&(column, row));
+                        }
+                    }
+                }
+            },
+            None => self.large.retain(|item| *item != id),
+        }
+
+        Some(rect)
+    }
+
+    /// Remove all items.
+    pub fn clear(&mut self) {
+        self.cells.clear();
+        self.large.clear();
+        self.items.clear();
+    }
+
+    /// The items intersecting a rectangle, in order of their index.
+    ///
+    /// Items that only share an edge with the rectangle don't intersect it.
+    #[must_use]
+    pub fn query_rect(&self, rect: Rect) -> Vec<usize> {
+        self.query(rect, |bounds| bounds.intersects(rect))
+    }
+
+    /// The items containing a point, in order of their index.
+    ///
+    /// Items are usually drawn in order, so the last one is on top.
+    ///
+    /// ```
+    /// # use crossd_graphics::geometry::{Point2, Rect, Size2};
+    /// # use crossd_graphics::spatial::SpatialIndex;
+    /// #
+    /// let back = Rect::new(Point2::new(0.0, 0.0), Size2::new(100.0, 100.0));
+    /// let front = Rect::new(Point2::new(40.0, 40.0), Size2::new(20.0, 20.0));
+    /// let index = SpatialIndex::from_rects([back, front]);
+    ///
+    /// assert_eq!(index.query_point(Point2::new(50.0, 50.0)).last(), Some(&1));
+    /// assert_eq!(index.query_point(Point2::new(10.0, 10.0)).last(), Some(&0));
+    /// assert!(index.query_point(Point2::new(-1.0, 10.0)).is_empty());
+    /// ```
+    #[must_use]
+    pub fn query_point(&self, point: Point2) -> Vec<usize> {
+        let rect = Rect::from_points(point, point);
+
+        self.query(rect, |bounds| bounds.contains(point))
+    }
+
+    /// The items in cells overlapping `rect` for which `filter` holds.
+    fn query(&self, rect: Rect, filter: impl Fn(Rect) -> bool) -> Vec<usize> {
+        let mut found = self.large.clone();
+
+        if let Some((columns, rows)) = self.cell_range(rect) {
+            let area = columns.clone().count() * rows.clone().count();
+
+            // visit only the cells there are, if that's faster
+            if area > self.cells.len() {
+                found.extend(
+                    self.cells
+                        .iter()
+                        .filter(|(cell, _)| {
+                            columns.contains(&cell.0) && rows.contains(&cell.1)
+                        })
+                        .flat_map(|(_, items)| items),
+                );
+            } else {
+                for column in columns {
+                    for row in rows.clone() {
+                        found
+                            .extend(self.cells.get(&(column, row)).into_iter().flatten());
+                    }
+                }
+            }
+        } else {
+            found.extend(self.cells.values().flatten());
+        }
+
+        found.sort_unstable();
+        found.dedup();
+        found.retain(|id| self.get(*id).is_some_and(&filter));
+
+        found
+    }
+
+    /// The columns and rows of the cells overlapping a rectangle, or `None` if
+    /// there are too many of them.
+    fn cell_range(
+        &self,
+        rect: Rect,
+    ) -> Option<(RangeInclusive<i32>, RangeInclusive<i32>)> {
+        let (min, max) = (rect.min(), rect.max());
+        let cell = |v: f32| (v / self.cell_size).floor();
+        let (left, top, right, bottom) =
+            (cell(min.x), cell(min.y), cell(max.x), cell(max.y));
+
+        let cells = (right - left + 1.0) * (bottom - top + 1.0);
+
+        if !cells.is_finite() || cells > MAX_CELLS {
+            return None;
+        }
+
+        Some((left as i32..=right as i32, top as i32..=bottom as i32))
+    }
+}
+
+impl Default for SpatialIndex {
+    fn default() -> Self {
+        Self::new(Self::DEFAULT_CELL_SIZE)
+    }
+}
+
+/// The most cells an item can be in, before it's in the list of large items.
+const MAX_CELLS: f32 = 256.0;
+
+#[cfg(test)]
+mod test {
+    use super::super::SpatialIndex;
+    use crate::geometry::{Point2, Rect, Size2};
+
+    fn rect(x: f32, y: f32, w: f32, h: f32) -> Rect {
+        Rect::new(Point2::new(x, y), Size2::new(w, h))
+    }
+
+    #[test]
+    fn moving() {
+        let mut index = SpatialIndex::new(10.0);
+        let (a, b) = (rect(0.0, 0.0, 5.0, 5.0), rect(50.0, 50.0, 25.0, 25.0));
+
+        assert_eq!(index.insert(0, a), None);
+        assert_eq!(index.insert(0, b), Some(a));
+        assert_eq!(index.len(), 1);
+        assert_eq!(index.get(0), Some(b));
+
+        // nothing is left behind in the old cells
+        assert!(index.query_rect(rect(-10.0, -10.0, 20.0, 20.0)).is_empty());
+        assert_eq!(index.query_point(Point2::new(70.0, 70.0)), [0]);
+        assert_eq!(index.cells.values().flatten().count(), 9);
+    }
+
+    #[test]
+    fn removing() {
+        let mut index = SpatialIndex::from_rects([
+            rect(0.0, 0.0, 30.0, 30.0),
+            rect(10.0, 10.0, 5.0, 5.0),
+            rect(-1e6, -1e6, 2e6, 2e6),
+        ]);
+
+        assert_eq!(index.remove(1), Some(rect(10.0, 10.0, 5.0, 5.0)));
+        assert_eq!(index.remove(1), None);
+        assert_eq!(index.remove(7), None);
+        assert_eq!(index.query_point(Point2::new(12.0, 12.0)), [0, 2]);
+
+        assert!(index.remove(2).is_some());
+        assert!(index.remove(0).is_some());
+        assert!(index.is_empty());
+        assert!(index.cells.is_empty());
+        assert!(index.large.is_empty());
+    }
+
+    #[test]
+    fn large_items() {
+        let mut index = SpatialIndex::new(1.0);
+        let huge = rect(-500.0, -500.0, 1000.0, 1000.0);
+
+        index.insert(0, huge);
+        index.insert(1, rect(0.0, 0.0, f32::INFINITY, 1.0));
+        index.insert(2, rect(2.0, 2.0, 1.0, 1.0));
+
+        // in the list of large items, rather than in very many cells
+        assert_eq!(index.large, [0, 1]);
+        assert_eq!(index.query_point(Point2::new(2.5, 2.5)), [0, 2]);
+        assert_eq!(index.query_point(Point2::new(2.5, 0.5)), [0, 1]);
+        assert_eq!(
+            index.query_rect(rect(-600.0, 100.0, 10.0, 10.0)),
+            Vec::<usize>::new()
+        );
+
+        // and moved out of it when no longer large
+        index.insert(0, rect(0.0, 0.0, 1.0, 1.0));
+        assert_eq!(index.large, [1]);
+        assert_eq!(index.query_point(Point2::new(-100.0, -100.0)), Vec::<usize>::new());
+    }
+
+    #[test]
+    fn out_of_order_ids() {
+        let mut index = SpatialIndex::default();
+
+        index.insert(5, rect(0.0, 0.0, 10.0, 10.0));
+        index.insert(2, rect(5.0, 5.0, 10.0, 10.0));
+        index.insert(9, rect(100.0, 100.0, 10.0, 10.0));
+
+        assert_eq!(index.len(), 3);
+        assert_eq!(index.get(0), None);
+        assert_eq!(index.get(2), Some(rect(5.0, 5.0, 10.0, 10.0)));
+        // sorted by index, whatever the order of insertion
+        assert_eq!(index.query_point(Point2::new(7.0, 7.0)), [2, 5]);
+        assert_eq!(index.query_rect(rect(0.0, 0.0, 200.0, 200.0)), [2, 5, 9]);
+
+        index.clear();
+        assert!(index.is_empty());
+        assert_eq!(index.get(5), None);
+    }
+}
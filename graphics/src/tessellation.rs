@@ -0,0 +1,155 @@
+//! Conversion of paths and polygons into triangle meshes for the GPU.
+//!
+//! Curves are flattened into lines within [`FillOptions::tolerance`], and the
+//! inside of the outline (decided by a [`FillRule`]) is split into triangles.
+//! Strokes are outlined with a [`StrokeTessellator`] and filled the same way.
+//! The resulting [`VertexBuffers`] can be uploaded as-is, and everything here
+//! runs on the CPU.
+//!
+//! ```
+//! # use crossd_graphics::geometry::{FillRule, Path, Point2, Rect, Size2};
+//! # use crossd_graphics::tessellation::{FillOptions, FillTessellator, VertexBuffers};
+//! #
+//! let rect = Rect::new(Point2::new(0.0, 0.0), Size2::new(10.0, 10.0));
+//! let path = Path::builder().rect(rect).build();
+//!
+//! let mut mesh: VertexBuffers<u16> = VertexBuffers::new();
+//! FillTessellator::new().fill_path(&path, &FillOptions::default(), &mut mesh).unwrap();
+//!
+//! assert_eq!(mesh.vertices.len(), 4);
+//! assert_eq!(mesh.indices.len(), 6);
+//! ```
+
+use std::collections::HashMap;
+use std::fmt;
+
+use bytemuck::Pod;
+use wgpu::IndexFormat;
+
+use crate::geometry::sweep::{Sweep, Trapezoid};
+use crate::geometry::{FillRule, Polygon};
+use crate::primitive::Vertex;
+
+/// Implementations for [`FillTessellator`] and [`FillOptions`].
+mod fill;
+/// Implementations for [`StrokeTessellator`] and [`StrokeOptions`].
+mod stroke;
+/// Implementations for [`VertexBuffers`] and [`Index`].
+mod vertex_buffers;
+
+/// Options for [`FillTessellator`].
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct FillOptions {
+    /// The furthest flattened curves may be from the real curves, in logical
+    /// pixels.
+    ///
+    /// Defaults to [`FillOptions::DEFAULT_TOLERANCE`].
+    pub tolerance: f32,
+    /// How the inside of the shape is decided.
+    pub fill_rule: FillRule,
+}
+
+/// Tessellates the inside of paths and polygons into triangles.
+///
+/// The tessellator keeps it's allocations between uses, so reuse it when
+/// tessellating many shapes.
+#[derive(Debug, Default, Clone)]
+pub struct FillTessellator {
+    sweep: Sweep,
+    trapezoids: Vec<Trapezoid>,
+    /// The `x` of every trapezoid corner by the bits of it's `y`, sorted.
+    rows: HashMap<u32, Vec<f32>>,
+    /// The index of each vertex by it's position, so that vertices are
+    /// shared.
+    vertices: HashMap<(u32, u32), usize>,
+}
+
+/// Options for [`StrokeTessellator`].
+#[derive(Debug, Clone, PartialEq)]
+pub struct StrokeOptions {
+    /// The width of the stroke, in logical pixels.
+    pub width: f32,
+    /// The shape of the corners between segments.
+    pub line_join: LineJoin,
+    /// The shape of the ends of open subpaths and dashes.
+    pub line_cap: LineCap,
+    /// The longest a [`LineJoin::Miter`] may be, relative to the width,
+    /// before it's beveled instead.
+    ///
+    /// Defaults to [`StrokeOptions::DEFAULT_MITER_LIMIT`].
+    pub miter_limit: f32,
+    /// The furthest flattened curves may be from the real curves, in logical
+    /// pixels.
+    ///
+    /// Defaults to [`FillOptions::DEFAULT_TOLERANCE`].
+    pub tolerance: f32,
+    /// Alternating lengths of dashes and gaps, starting with a dash.
+    ///
+    /// An odd number of lengths is repeated to make it even. The stroke is
+    /// solid if there are none, any is negative or they add up to zero.
+    pub dashes: Vec<f32>,
+    /// How far into the dash pattern the stroke starts.
+    pub dash_offset: f32,
+}
+
+/// The shape of the corner where two segments of a stroke meet.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum LineJoin {
+    /// Extend the outer edges until they meet, within
+    /// [`StrokeOptions::miter_limit`].
+    #[default]
+    Miter,
+    /// A circular arc around the corner.
+    Round,
+    /// Connect the outer edges with a straight line.
+    Bevel,
+}
+
+/// The shape of the ends of a stroke.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum LineCap {
+    /// End exactly at the end point.
+    #[default]
+    Butt,
+    /// A half circle around the end point.
+    Round,
+    /// Extend past the end point by half the width.
+    Square,
+}
+
+/// Tessellates the outline of paths and polylines into triangles.
+///
+/// The tessellator keeps it's allocations between uses, so reuse it when
+/// tessellating many shapes.
+#[derive(Debug, Default, Clone)]
+pub struct StrokeTessellator {
+    fill: FillTessellator,
+    /// The pieces of the stroke, which are filled together.
+    polygons: Vec<Polygon>,
+}
+
+/// Vertices and the indices of the triangles connecting them.
+///
+/// All triangles are wound clockwise on screen, where `y` points down.
+#[derive(Debug, Default, Clone, PartialEq)]
+pub struct VertexBuffers<I = u16> {
+    pub vertices: Vec<Vertex>,
+    pub indices: Vec<I>,
+}
+
+/// Types that can be used as indices into [`VertexBuffers::vertices`],
+/// implemented for `u16` and `u32`.
+pub trait Index: Pod + Eq + fmt::Debug {
+    /// The format of the index buffer on the GPU.
+    const FORMAT: IndexFormat;
+
+    /// Convert from a `usize`, if it fits.
+    fn from_usize(index: usize) -> Option<Self>;
+}
+
+/// Errors that can arise while tessellating.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, thiserror::Error)]
+pub enum TessellationError {
+    #[error("too many vertices for the index type")]
+    TooManyVertices,
+}
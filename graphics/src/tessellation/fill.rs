@@ -0,0 +1,407 @@
+use std::collections::HashMap;
+
+use super::{FillOptions, FillTessellator, Index, TessellationError, VertexBuffers};
+use crate::geometry::sweep::Trapezoid;
+use crate::geometry::{FillRule, Path, Polygon};
+use crate::primitive::Vertex;
+
+impl FillOptions {
+    /// The default [`FillOptions::tolerance`], a tenth of a logical pixel.
+    pub const DEFAULT_TOLERANCE: f32 = 0.1;
+
+    /// Options using the default tolerance and the non-zero fill rule.
+    #[inline]
+    #[must_use]
+    pub const fn new() -> Self {
+        Self { tolerance: Self::DEFAULT_TOLERANCE, fill_rule: FillRule::NonZero }
+    }
+
+    /// Set the tolerance used to flatten curves.
+    #[inline]
+    #[must_use]
+    pub const fn tolerance(self, tolerance: f32) -> Self {
+        Self { tolerance, ..self }
+    }
+
+    /// Set the fill rule.
+    #[inline]
+    #[must_use]
+    pub const fn fill_rule(self, fill_rule: FillRule) -> Self {
+        Self { fill_rule, ..self }
+    }
+}
+
+impl Default for FillOptions {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl FillTessellator {
+    /// A new tessellator.
+    #[inline]
+    #[must_use]
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Tessellate the inside of a path, adding the triangles to `out`.
+    ///
+    /// Open subpaths are filled as if they were closed.
+    ///
+    /// ## Errors
+    ///
+    /// Returns [`TessellationError::TooManyVertices`] if the vertices can't be
+    /// indexed by `I`, leaving `out` as it was.
+    pub fn fill_path<I: Index>(
+        &mut self,
+        path: &Path,
+        options: &FillOptions,
+        out: &mut VertexBuffers<I>,
+    ) -> Result<(), TessellationError> {
+        self.sweep.clear();
+
+        for polygon in path.flatten(options.tolerance) {
+            self.sweep.add_polygon(&polygon.points, 0);
+        }
+
+        self.tessellate(options.fill_rule, out)
+    }
+
+    /// Tessellate the inside of a list of polygons, adding the triangles to
+    /// `out`.
+    ///
+    /// The polygons are filled together, so they can form holes in each
+    /// other.
+    ///
+    /// ```
+    /// # use crossd_graphics::geometry::{FillRule, Point2, Polygon};
+    /// # use crossd_graphics::tessellation::{FillOptions, FillTessellator, VertexBuffers};
+    /// #
+    /// let square = |min: f32, max: f32| -> Polygon {
+    ///     [(min, min), (max, min), (max, max), (min, max)]
+    ///         .into_iter()
+    ///         .map(|(x, y)| Point2::new(x, y))
+    ///         .collect()
+    /// };
+    /// // both squares wind the same way, so only even-odd makes a hole
+    /// let polygons = [square(0.0, 30.0), square(10.0, 20.0)];
+    /// let options = FillOptions::new().fill_rule(FillRule::EvenOdd);
+    ///
+    /// let mut mesh: VertexBuffers<u32> = VertexBuffers::new();
+    /// FillTessellator::new().fill_polygons(&polygons, &options, &mut mesh).unwrap();
+    ///
+    /// // the slabs above and below the hole are split at it's corners
+    /// assert_eq!(mesh.indices.len() / 3, 12);
+    /// ```
+    ///
+    /// ## Errors
+    ///
+    /// Returns [`TessellationError::TooManyVertices`] if the vertices can't be
+    /// indexed by `I`, leaving `out` as it was.
+    pub fn fill_polygons<I: Index>(
+        &mut self,
+        polygons: &[Polygon],
+        options: &FillOptions,
+        out: &mut VertexBuffers<I>,
+    ) -> Result<(), TessellationError> {
+        self.sweep.clear();
+
+        for polygon in polygons {
+            self.sweep.add_polygon(&polygon.points, 0);
+        }
+
+        self.tessellate(options.fill_rule, out)
+    }
+
+    /// Triangulate the polygons added to the sweep.
+    fn tessellate<I: Index>(
+        &mut self,
+        fill_rule: FillRule,
+        out: &mut VertexBuffers<I>,
+    ) -> Result<(), TessellationError> {
+        let (vertex_len, index_len) = (out.vertices.len(), out.indices.len());
+        let trapezoids = &mut self.trapezoids;
+
+        trapezoids.clear();
+        self.sweep.trapezoids(
+            |winding| fill_rule.is_inside(winding[0]),
+            |t| trapezoids.push(t),
+        );
+
+        self.rows.clear();
+
+        for trapezoid in &self.trapezoids {
+            let Trapezoid { top, bottom, left, right } = *trapezoid;
+
+            self.rows
+                .entry(key(top))
+                .or_default()
+                .extend([left[0] + 0.0, right[0] + 0.0]);
+            self.rows
+                .entry(key(bottom))
+                .or_default()
+                .extend([left[1] + 0.0, right[1] + 0.0]);
+        }
+
+        for xs in self.rows.values_mut() {
+            xs.sort_by(f32::total_cmp);
+            xs.dedup();
+        }
+
+        self.vertices.clear();
+
+        let result = self
+            .trapezoids
+            .iter()
+            .try_for_each(|t| add_trapezoid(*t, &self.rows, &mut self.vertices, out));
+
+        if result.is_err() {
+            out.vertices.truncate(vertex_len);
+            out.indices.truncate(index_len);
+        }
+
+        result
+    }
+}
+
+/// Add the triangles of a trapezoid, sharing vertices at the same position.
+///
+/// The top and bottom edges are split at every corner in `rows` on them, so
+/// that trapezoids meeting there don't leave T-junctions.
+fn add_trapezoid<I: Index>(
+    trapezoid: Trapezoid,
+    rows: &HashMap<u32, Vec<f32>>,
+    ids: &mut HashMap<(u32, u32), usize>,
+    out: &mut VertexBuffers<I>,
+) -> Result<(), TessellationError> {
+    let Trapezoid { top, bottom, left, right } = trapezoid;
+
+    if right[0] <= left[0] && right[1] <= left[1] {
+        return Ok(());
+    }
+
+    let vertices = &mut out.vertices;
+    let mut vertex = |x: f32, y: f32| {
+        // `-0.0` and `0.0` are the same position
+        let (x, y) = (x + 0.0, y + 0.0);
+        let index = *ids.entry((x.to_bits(), y.to_bits())).or_insert_with(|| {
+            vertices.push(Vertex::new(x, y));
+            vertices.len() - 1
+        });
+
+        I::from_usize(index).ok_or(TessellationError::TooManyVertices)
+    };
+
+    let top_xs = corners(rows, top, left[0], right[0]);
+    let bottom_xs = corners(rows, bottom, left[1], right[1]);
+    let (mut i, mut j) = (0, 0);
+
+    while i + 1 < top_xs.len() || j + 1 < bottom_xs.len() {
+        // advance along the edge whose next corner is further left
+        let along_top = j + 1 == bottom_xs.len()
+            || (i + 1 < top_xs.len() && top_xs[i + 1] <= bottom_xs[j + 1]);
+
+        let triangle = if along_top {
+            i += 1;
+            [
+                vertex(top_xs[i - 1], top)?,
+                vertex(top_xs[i], top)?,
+                vertex(bottom_xs[j], bottom)?,
+            ]
+        } else {
+            j += 1;
+            [
+                vertex(top_xs[i], top)?,
+                vertex(bottom_xs[j], bottom)?,
+                vertex(bottom_xs[j - 1], bottom)?,
+            ]
+        };
+
+        out.indices.extend(triangle);
+    }
+
+    Ok(())
+}
+
+/// The `x` of the corners at `y` from `left` to `right`, which is a single
+/// corner for an edge with no width.
+fn corners(rows: &HashMap<u32, Vec<f32>>, y: f32, left: f32, right: f32) -> &[f32] {
+    // both ends are in the row, as they are corners themselves
+    let xs = &rows[&key(y)];
+    let start = xs.partition_point(|x| *x < left);
+    let end = xs.partition_point(|x| *x <= right).max(start + 1);
+
+    &xs[start..end]
+}
+
+/// The key of a height in the rows of corners, where `-0.0` and `0.0` are the
+/// same.
+fn key(y: f32) -> u32 {
+    (y + 0.0).to_bits()
+}
+
+#[cfg(test)]
+mod test {
+    use super::super::{
+        FillOptions,
+        FillTessellator,
+        Index,
+        TessellationError,
+        VertexBuffers,
+    };
+    use crate::geometry::{FillRule, Line, Path, Point2, Polygon};
+
+    /// The area of each triangle, which is positive if it's clockwise on
+    /// screen.
+    fn areas<I: Index + Into<u32>>(mesh: &VertexBuffers<I>) -> Vec<f32> {
+        mesh.indices
+            .chunks(3)
+            .map(|triangle| {
+                let [a, b, c] =
+                    [0, 1, 2].map(|i| mesh.vertices[triangle[i].into() as usize].loc);
+
+                ((b.x - a.x) * (c.y - a.y) - (b.y - a.y) * (c.x - a.x)) / 2.0
+            })
+            .collect()
+    }
+
+    /// The unsigned area enclosed by `polygon`.
+    fn shoelace(polygon: &Polygon) -> f32 {
+        let points = &polygon.points;
+        let twice = (0..points.len())
+            .map(|i| {
+                let (a, b) = (points[i], points[(i + 1) % points.len()]);
+
+                a.x * b.y - b.x * a.y
+            })
+            .sum::<f32>();
+
+        twice.abs() / 2.0
+    }
+
+    fn fill<I: Index>(path: &str, fill_rule: FillRule) -> VertexBuffers<I> {
+        let path = Path::from_svg(path).unwrap();
+        let options = FillOptions::new().fill_rule(fill_rule);
+        let mut mesh = VertexBuffers::new();
+
+        FillTessellator::new().fill_path(&path, &options, &mut mesh).unwrap();
+
+        mesh
+    }
+
+    #[test]
+    fn fill_rules() {
+        // two squares winding the same way, overlapping by 10x10
+        let path = "M0 0H20V20H0Z M10 10H30V30H10Z";
+
+        let nonzero = fill::<u32>(path, FillRule::NonZero);
+        let even_odd = fill::<u32>(path, FillRule::EvenOdd);
+
+        assert_eq!(areas(&nonzero).iter().sum::<f32>(), 700.0);
+        assert_eq!(areas(&even_odd).iter().sum::<f32>(), 600.0);
+
+        // a single outline going around the middle twice
+        let loops = "M0 0H30V30H0V0L10 10H20V20H10V10Z";
+
+        assert_eq!(
+            areas(&fill::<u32>(loops, FillRule::NonZero)).iter().sum::<f32>(),
+            900.0
+        );
+        assert_eq!(
+            areas(&fill::<u32>(loops, FillRule::EvenOdd)).iter().sum::<f32>(),
+            800.0
+        );
+    }
+
+    #[test]
+    fn area() {
+        // a square with a hole, and a circle
+        let svg =
+            "M0 0H40V40H0Z M10 10V30H30V10Z M50 20A10 10 0 1 1 70 20A10 10 0 1 1 50 20Z";
+        let path = Path::from_svg(svg).unwrap();
+
+        let outlines = path.flatten(FillOptions::DEFAULT_TOLERANCE);
+        let expected =
+            shoelace(&outlines[0]) - shoelace(&outlines[1]) + shoelace(&outlines[2]);
+
+        for fill_rule in [FillRule::NonZero, FillRule::EvenOdd] {
+            let areas = areas(&fill::<u16>(svg, fill_rule));
+
+            assert!(areas.iter().all(|&area| area > 0.0), "{areas:?}");
+
+            let total = areas.iter().sum::<f32>();
+            assert!((total - expected).abs() < 1e-2, "{total} != {expected}");
+        }
+    }
+
+    #[test]
+    fn no_t_junctions() {
+        // the corners of the hole and the triangle's tip end in the middle of
+        // the edges of the slabs above and below them
+        let svg = "M0 0H30V30H0Z M10 10V20H20V10Z M40 0L50 10L60 0V30H40Z";
+        let mesh = fill::<u32>(svg, FillRule::NonZero);
+
+        for triangle in mesh.indices.chunks(3) {
+            for k in 0..3 {
+                let (a, b) = (triangle[k], triangle[(k + 1) % 3]);
+                let edge = Line::new(
+                    mesh.vertices[a as usize].loc,
+                    mesh.vertices[b as usize].loc,
+                );
+
+                for (i, vertex) in mesh.vertices.iter().enumerate() {
+                    let on_edge = i as u32 != a
+                        && i as u32 != b
+                        && edge.distance_to(vertex.loc) < 1e-4;
+
+                    assert!(!on_edge, "{:?} is on {edge:?}", vertex.loc);
+                }
+            }
+        }
+
+        assert!(areas(&mesh).iter().all(|&area| area > 0.0));
+        assert_eq!(areas(&mesh).iter().sum::<f32>(), 800.0 + 500.0);
+    }
+
+    #[test]
+    fn index_ranges() {
+        let u16_mesh = fill::<u16>("M0 0H10V10H0Z M20 0H30L25 10Z", FillRule::NonZero);
+        let u32_mesh = fill::<u32>("M0 0H10V10H0Z M20 0H30L25 10Z", FillRule::NonZero);
+
+        assert_eq!(u16_mesh.vertices, u32_mesh.vertices);
+        assert!(u16_mesh
+            .indices
+            .iter()
+            .all(|&i| usize::from(i) < u16_mesh.vertices.len()));
+        assert!(u32_mesh.indices.iter().all(|&i| (i as usize) < u32_mesh.vertices.len()));
+        assert!(u16_mesh.indices.iter().map(|&i| u32::from(i)).eq(u32_mesh.indices));
+
+        // more vertices than `u16` can index
+        let squares: Vec<Polygon> = (0..17_000)
+            .map(|i| {
+                let (x, y) = (i as f32 * 2.0, i as f32 * 2.0);
+
+                [(x, y), (x + 1.0, y), (x + 1.0, y + 1.0), (x, y + 1.0)]
+                    .into_iter()
+                    .map(|(x, y)| Point2::new(x, y))
+                    .collect()
+            })
+            .collect();
+        let options = FillOptions::new();
+        let mut tessellator = FillTessellator::new();
+
+        let mut u16_mesh = u16_mesh.clone();
+        let before = u16_mesh.clone();
+        let result = tessellator.fill_polygons(&squares, &options, &mut u16_mesh);
+
+        assert_eq!(result, Err(TessellationError::TooManyVertices));
+        assert_eq!(u16_mesh, before);
+
+        let mut u32_mesh = VertexBuffers::<u32>::new();
+        tessellator.fill_polygons(&squares, &options, &mut u32_mesh).unwrap();
+
+        assert_eq!(u32_mesh.vertices.len(), 68_000);
+        assert_eq!(u32_mesh.indices.iter().max(), Some(&67_999));
+    }
+}
@@ -0,0 +1,726 @@
+use std::f32::consts::{FRAC_PI_2, TAU};
+use std::{mem, slice};
+
+use super::{
+    FillOptions,
+    Index,
+    LineCap,
+    LineJoin,
+    StrokeOptions,
+    StrokeTessellator,
+    TessellationError,
+    VertexBuffers,
+};
+use crate::geometry::boolean::combine;
+use crate::geometry::{BooleanOp, FillRule, Path, Point2, Polygon};
+use crate::math::Vec2;
+
+impl StrokeOptions {
+    /// The default [`StrokeOptions::miter_limit`], the same as in SVG.
+    pub const DEFAULT_MITER_LIMIT: f32 = 4.0;
+
+    /// Options for a solid stroke with the given width, miter joins and butt
+    /// caps.
+    #[inline]
+    #[must_use]
+    pub const fn new(width: f32) -> Self {
+        Self {
+            width,
+            line_join: LineJoin::Miter,
+            line_cap: LineCap::Butt,
+            miter_limit: Self::DEFAULT_MITER_LIMIT,
+            tolerance: FillOptions::DEFAULT_TOLERANCE,
+            dashes: Vec::new(),
+            dash_offset: 0.0,
+        }
+    }
+
+    /// Set the width.
+    #[inline]
+    #[must_use]
+    pub fn width(self, width: f32) -> Self {
+        Self { width, ..self }
+    }
+
+    /// Set the line join.
+    #[inline]
+    #[must_use]
+    pub fn line_join(self, line_join: LineJoin) -> Self {
+        Self { line_join, ..self }
+    }
+
+    /// Set the line cap.
+    #[inline]
+    #[must_use]
+    pub fn line_cap(self, line_cap: LineCap) -> Self {
+        Self { line_cap, ..self }
+    }
+
+    /// Set the miter limit.
+    #[inline]
+    #[must_use]
+    pub fn miter_limit(self, miter_limit: f32) -> Self {
+        Self { miter_limit, ..self }
+    }
+
+    /// Set the tolerance used to flatten curves and round joins and caps.
+    #[inline]
+    #[must_use]
+    pub fn tolerance(self, tolerance: f32) -> Self {
+        Self { tolerance, ..self }
+    }
+
+    /// Set the lengths of dashes and gaps.
+    #[inline]
+    #[must_use]
+    pub fn dashes(self, dashes: impl Into<Vec<f32>>) -> Self {
+        Self { dashes: dashes.into(), ..self }
+    }
+
+    /// Set the offset into the dash pattern.
+    #[inline]
+    #[must_use]
+    pub fn dash_offset(self, dash_offset: f32) -> Self {
+        Self { dash_offset, ..self }
+    }
+
+    /// The dash pattern with an even amount of lengths, if the stroke is
+    /// dashed.
+    fn dash_pattern(&self) -> Option<Vec<f32>> {
+        let valid = self.dashes.iter().all(|len| len.is_finite() && *len >= 0.0);
+        let total: f32 = self.dashes.iter().sum();
+
+        if !valid || total <= 0.0 || !total.is_finite() {
+            return None;
+        }
+
+        let mut pattern = self.dashes.clone();
+
+        if pattern.len() % 2 == 1 {
+            pattern.extend_from_within(..);
+        }
+
+        Some(pattern)
+    }
+}
+
+impl Default for StrokeOptions {
+    fn default() -> Self {
+        Self::new(1.0)
+    }
+}
+
+impl StrokeTessellator {
+    /// A new tessellator.
+    #[inline]
+    #[must_use]
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Tessellate the stroke of a path, adding the triangles to `out`.
+    ///
+    /// Overlapping parts of the stroke are only covered once, so it can be
+    /// drawn with a translucent color.
+    ///
+    /// ```
+    /// # use crossd_graphics::geometry::{Path, Point2};
+    /// # use crossd_graphics::tessellation::{StrokeOptions, StrokeTessellator, VertexBuffers};
+    /// #
+    /// let path = Path::builder()
+    ///     .move_to(Point2::new(0.0, 0.0))
+    ///     .line_to(Point2::new(10.0, 0.0))
+    ///     .build();
+    ///
+    /// let mut mesh: VertexBuffers<u16> = VertexBuffers::new();
+    /// StrokeTessellator::new()
+    ///     .stroke_path(&path, &StrokeOptions::new(2.0), &mut mesh)
+    ///     .unwrap();
+    ///
+    /// assert_eq!(mesh.vertices.len(), 4);
+    /// assert_eq!(mesh.indices.len(), 6);
+    /// ```
+    ///
+    /// ## Errors
+    ///
+    /// Returns [`TessellationError::TooManyVertices`] if the vertices can't be
+    /// indexed by `I`, leaving `out` as it was.
+    pub fn stroke_path<I: Index>(
+        &mut self,
+        path: &Path,
+        options: &StrokeOptions,
+        out: &mut VertexBuffers<I>,
+    ) -> Result<(), TessellationError> {
+        self.polygons.clear();
+
+        for (points, closed) in path.flatten_polylines(options.tolerance) {
+            self.add_polyline(points, closed, options);
+        }
+
+        self.tessellate(out)
+    }
+
+    /// Tessellate the stroke of a polyline, adding the triangles to `out`.
+    ///
+    /// A closed polyline also connects it's last point to it's first.
+    ///
+    /// ```
+    /// # use crossd_graphics::geometry::Point2;
+    /// # use crossd_graphics::tessellation::{StrokeOptions, StrokeTessellator, VertexBuffers};
+    /// #
+    /// let points = [Point2::new(0.0, 0.0), Point2::new(30.0, 0.0)];
+    /// // two dashes of 10, the second one ending at the last point
+    /// let options = StrokeOptions::new(2.0).dashes([10.0]);
+    ///
+    /// let mut mesh: VertexBuffers<u16> = VertexBuffers::new();
+    /// StrokeTessellator::new()
+    ///     .stroke_polyline(&points, false, &options, &mut mesh)
+    ///     .unwrap();
+    ///
+    /// assert_eq!(mesh.indices.len() / 3, 4);
+    /// ```
+    ///
+    /// ## Errors
+    ///
+    /// Returns [`TessellationError::TooManyVertices`] if the vertices can't be
+    /// indexed by `I`, leaving `out` as it was.
+    pub fn stroke_polyline<I: Index>(
+        &mut self,
+        points: &[Point2],
+        closed: bool,
+        options: &StrokeOptions,
+        out: &mut VertexBuffers<I>,
+    ) -> Result<(), TessellationError> {
+        self.polygons.clear();
+        self.add_polyline(points.to_vec(), closed, options);
+        self.tessellate(out)
+    }
+
+    /// Move the outline of a polygon outwards by `distance`, or inwards if
+    /// it's negative.
+    ///
+    /// Corners are joined as they would be in a stroke of the outline, using
+    /// the default miter limit, and arcs are flattened within `tolerance`.
+    /// Insetting can split the polygon in several. Outlines go clockwise on
+    /// screen, so their signed area is negative, and outlines around holes go
+    /// counter-clockwise.
+    ///
+    /// ```
+    /// # use crossd_graphics::geometry::{Point2, Polygon};
+    /// # use crossd_graphics::tessellation::{LineJoin, StrokeTessellator};
+    /// #
+    /// let square: Polygon = [(0.0, 0.0), (10.0, 0.0), (10.0, 10.0), (0.0, 10.0)]
+    ///     .into_iter()
+    ///     .map(|(x, y)| Point2::new(x, y))
+    ///     .collect();
+    /// let mut tessellator = StrokeTessellator::new();
+    ///
+    /// let outset = tessellator.offset_polygon(&square, 2.0, LineJoin::Miter, 0.1);
+    /// assert_eq!(outset[0].signed_area(), -14.0 * 14.0);
+    ///
+    /// let inset = tessellator.offset_polygon(&square, -2.0, LineJoin::Miter, 0.1);
+    /// assert_eq!(inset[0].signed_area(), -6.0 * 6.0);
+    ///
+    /// // round corners cut off a bit less than the corners of a square
+    /// let rounded = tessellator.offset_polygon(&square, 2.0, LineJoin::Round, 0.01);
+    /// let area = -rounded[0].signed_area();
+    /// assert!((area - (14.0 * 14.0 - (16.0 - 4.0 * std::f32::consts::PI))).abs() < 0.2);
+    /// ```
+    #[must_use]
+    pub fn offset_polygon(
+        &mut self,
+        polygon: &Polygon,
+        distance: f32,
+        join: LineJoin,
+        tolerance: f32,
+    ) -> Vec<Polygon> {
+        let options =
+            StrokeOptions::new(2.0 * distance.abs()).line_join(join).tolerance(tolerance);
+
+        self.polygons.clear();
+        self.add_polyline(polygon.points.clone(), true, &options);
+
+        let op = if distance < 0.0 { BooleanOp::Difference } else { BooleanOp::Union };
+
+        combine(slice::from_ref(polygon), &self.polygons, op, FillRule::NonZero)
+            .flatten(tolerance)
+    }
+
+    /// Add the pieces of a polyline's stroke.
+    fn add_polyline(
+        &mut self,
+        points: Vec<Point2>,
+        closed: bool,
+        options: &StrokeOptions,
+    ) {
+        outline(points, closed, options, &mut self.polygons);
+    }
+
+    /// Fill the union of the pieces of the stroke.
+    fn tessellate<I: Index>(
+        &mut self,
+        out: &mut VertexBuffers<I>,
+    ) -> Result<(), TessellationError> {
+        // every piece winds the same way, so they add up
+        self.fill.fill_polygons(&self.polygons, &FillOptions::new(), out)
+    }
+}
+
+/// Outline the stroke of a polyline with overlapping polygons, all going
+/// clockwise on screen.
+fn outline(
+    points: Vec<Point2>,
+    closed: bool,
+    options: &StrokeOptions,
+    polygons: &mut Vec<Polygon>,
+) {
+    if !(options.width.is_finite() && options.width > 0.0) || points.is_empty() {
+        return;
+    }
+
+    let mut pen = Pen { polygons, half: options.width / 2.0, options };
+
+    match options.dash_pattern() {
+        Some(pattern) => {
+            for dash in dash(&points, closed, &pattern, options.dash_offset) {
+                // a dash all the way around a closed polyline has no ends
+                let around = closed && dash.len() > 2 && dash.first() == dash.last();
+
+                pen.polyline(dash, around);
+            }
+        },
+        None => pen.polyline(points, closed),
+    }
+}
+
+/// Outlines the pieces of a stroke as polygons wound the same way.
+struct Pen<'a> {
+    polygons: &'a mut Vec<Polygon>,
+    /// Half of the stroke width.
+    half: f32,
+    options: &'a StrokeOptions,
+}
+
+impl Pen<'_> {
+    /// Outline a polyline with segments, joins and caps.
+    fn polyline(&mut self, mut points: Vec<Point2>, closed: bool) {
+        points.dedup();
+
+        if closed && points.len() > 1 && points.first() == points.last() {
+            points.pop();
+        }
+
+        let len = points.len();
+
+        if len < 2 {
+            // a subpath with no length only has caps
+            if let Some(p) = points.first() {
+                self.dot(*p);
+            }
+
+            return;
+        }
+
+        let segments = if closed { len } else { len - 1 };
+
+        for i in 0..segments {
+            self.segment(points[i], points[(i + 1) % len]);
+        }
+
+        let joins = if closed { 0..len } else { 1..len - 1 };
+
+        for i in joins {
+            let (prev, p, next) =
+                (points[(i + len - 1) % len], points[i], points[(i + 1) % len]);
+
+            self.join(p, (p - prev).normalize(), (next - p).normalize());
+        }
+
+        if !closed {
+            self.cap(points[0], (points[0] - points[1]).normalize());
+            self.cap(points[len - 1], (points[len - 1] - points[len - 2]).normalize());
+        }
+    }
+
+    /// Outline a straight segment.
+    fn segment(&mut self, from: Point2, to: Point2) {
+        let n = perp((to - from).normalize()).scale(self.half);
+
+        self.push(vec![from + n, to + n, to - n, from - n]);
+    }
+
+    /// Fill the outer corner at `p`, between segments going in the directions
+    /// `d0` and `d1`.
+    fn join(&mut self, p: Point2, d0: Vec2, d1: Vec2) {
+        let (cross, dot) = (d0.cross(d1), d0.dot(d1));
+
+        if cross == 0.0 {
+            // no corner if the segments are in line, and if they double back
+            // only a round join has anything to fill
+            if dot < 0.0 && self.options.line_join == LineJoin::Round {
+                self.round_cap(p, d0);
+            }
+
+            return;
+        }
+
+        // the outer side is away from the turn
+        let side = if cross > 0.0 { -1.0 } else { 1.0 };
+        let (n0, n1) = (perp(d0).scale(side), perp(d1).scale(side));
+        let (outer0, outer1) = (p + n0.scale(self.half), p + n1.scale(self.half));
+
+        match self.options.line_join {
+            LineJoin::Miter => {
+                let cos_half = ((1.0 + dot) / 2.0).sqrt();
+
+                if cos_half > 0.0 && cos_half * self.options.miter_limit >= 1.0 {
+                    let tip = p + (n0 + n1).normalize().scale(self.half / cos_half);
+
+                    self.push(vec![p, outer0, tip, outer1]);
+                } else {
+                    self.push(vec![p, outer0, outer1]);
+                }
+            },
+            LineJoin::Round => {
+                let mut points = vec![p];
+                points.extend(self.arc(p, n0, n0.cross(n1).atan2(dot)));
+
+                self.push(points);
+            },
+            LineJoin::Bevel => self.push(vec![p, outer0, outer1]),
+        }
+    }
+
+    /// Add the cap at the end `p` of an open polyline, going outwards in the
+    /// direction `d`.
+    fn cap(&mut self, p: Point2, d: Vec2) {
+        match self.options.line_cap {
+            LineCap::Butt => {},
+            LineCap::Round => self.round_cap(p, d),
+            LineCap::Square => {
+                let (n, e) = (perp(d).scale(self.half), d.scale(self.half));
+
+                self.push(vec![p + n, p + n + e, p - n + e, p - n]);
+            },
+        }
+    }
+
+    /// Add a half circle around `p`, bulging in the direction `d`.
+    fn round_cap(&mut self, p: Point2, d: Vec2) {
+        // turning from the left of `d` to it's right passes through `d`
+        let points = self.arc(p, perp(d), -TAU / 2.0);
+
+        self.push(points);
+    }
+
+    /// Add the caps of a polyline with a single point, facing left and right.
+    fn dot(&mut self, p: Point2) {
+        match self.options.line_cap {
+            LineCap::Butt => {},
+            LineCap::Round => {
+                let points = self.arc(p, Vec2::new(1.0, 0.0), TAU);
+                self.push(points);
+            },
+            LineCap::Square => {
+                self.cap(p, Vec2::new(1.0, 0.0));
+                self.cap(p, Vec2::new(-1.0, 0.0));
+            },
+        }
+    }
+
+    /// The points of an arc around `center` with a radius of half the width,
+    /// starting in the direction `from` and turning by `sweep` radians.
+    fn arc(&self, center: Point2, from: Vec2, sweep: f32) -> Vec<Point2> {
+        let (half, tolerance) = (self.half, self.options.tolerance);
+
+        // the angle of a chord that is at most `tolerance` from the arc
+        let step = if tolerance < half {
+            2.0 * (1.0 - tolerance / half).acos()
+        } else {
+            FRAC_PI_2
+        };
+        let steps = ((sweep.abs() / step).ceil() as usize).clamp(1, MAX_ARC_STEPS);
+
+        (0..=steps)
+            .map(|i| {
+                let (sin, cos) = (sweep * i as f32 / steps as f32).sin_cos();
+                let dir =
+                    Vec2::new(from.x * cos - from.y * sin, from.x * sin + from.y * cos);
+
+                center + dir.scale(half)
+            })
+            .collect()
+    }
+
+    /// Add a piece of the stroke, wound the same way as all others.
+    fn push(&mut self, mut points: Vec<Point2>) {
+        let next = points.iter().cycle().skip(1);
+        let area: f32 =
+            points.iter().zip(next).map(|(a, b)| a.to_vec2().cross(b.to_vec2())).sum();
+
+        if area < 0.0 {
+            points.reverse();
+        }
+
+        self.polygons.push(Polygon::new(points));
+    }
+}
+
+/// The most segments used for a round join or cap.
+const MAX_ARC_STEPS: usize = 256;
+
+/// The vector rotated a quarter turn, to the left of it on screen.
+fn perp(v: Vec2) -> Vec2 {
+    Vec2::new(-v.y, v.x)
+}
+
+/// Split a polyline into dashes along a pattern with an even amount of
+/// lengths and a positive total length.
+///
+/// On a closed polyline a dash running past the closing point continues into
+/// the first one.
+fn dash(
+    points: &[Point2],
+    closed: bool,
+    pattern: &[f32],
+    offset: f32,
+) -> Vec<Vec<Point2>> {
+    let total: f32 = pattern.iter().sum();
+    let mut phase = offset.rem_euclid(total);
+    let mut index = 0;
+
+    // a dash with no length at the start is kept, for dots with caps
+    while phase > 0.0 && phase >= pattern[index] {
+        phase -= pattern[index];
+        index = (index + 1) % pattern.len();
+    }
+
+    let starts_in_dash = index % 2 == 0;
+    let mut remaining = pattern[index] - phase;
+    let mut dashes = Vec::new();
+    let mut dash = if index % 2 == 0 { vec![points[0]] } else { Vec::new() };
+
+    let next = points.iter().cycle().skip(1);
+    let segments = if closed { points.len() } else { points.len() - 1 };
+
+    for (from, to) in points.iter().zip(next).take(segments) {
+        let mut from = *from;
+        let mut len = (*to - from).length();
+
+        // every dash and gap ending within the segment
+        while len > remaining {
+            let p = from + (*to - from).scale(remaining / len);
+
+            // either ends a dash or starts the next one
+            dash.push(p);
+
+            if index % 2 == 0 {
+                dashes.push(mem::take(&mut dash));
+            }
+
+            index = (index + 1) % pattern.len();
+            len -= remaining;
+            remaining = pattern[index];
+            from = p;
+        }
+
+        remaining -= len;
+
+        if index % 2 == 0 {
+            dash.push(*to);
+        }
+    }
+
+    if index % 2 == 0 && !dash.is_empty() {
+        match dashes.first_mut() {
+            Some(first) if closed && starts_in_dash => {
+                // both start at the first point
+                dash.extend(first.drain(1..));
+                *first = dash;
+            },
+            _ => dashes.push(dash),
+        }
+    }
+
+    dashes
+}
+
+#[cfg(test)]
+mod test {
+    use super::super::{
+        LineCap,
+        LineJoin,
+        StrokeOptions,
+        StrokeTessellator,
+        VertexBuffers,
+    };
+    use super::dash;
+    use crate::geometry::{Point2, Polygon};
+
+    fn p(x: f32, y: f32) -> Point2 {
+        Point2::new(x, y)
+    }
+
+    fn stroke(
+        points: &[Point2],
+        closed: bool,
+        options: &StrokeOptions,
+    ) -> VertexBuffers<u32> {
+        let mut mesh = VertexBuffers::new();
+
+        StrokeTessellator::new()
+            .stroke_polyline(points, closed, options, &mut mesh)
+            .unwrap();
+
+        mesh
+    }
+
+    /// The area covered by the triangles, which don't overlap.
+    fn area(mesh: &VertexBuffers<u32>) -> f32 {
+        mesh.indices
+            .chunks(3)
+            .map(|triangle| {
+                let [a, b, c] =
+                    [0, 1, 2].map(|i| mesh.vertices[triangle[i] as usize].loc);
+
+                ((b.x - a.x) * (c.y - a.y) - (b.y - a.y) * (c.x - a.x)) / 2.0
+            })
+            .sum()
+    }
+
+    /// The smallest and largest `x` of the vertices.
+    fn x_range(mesh: &VertexBuffers<u32>) -> (f32, f32) {
+        mesh.vertices.iter().fold((f32::INFINITY, f32::NEG_INFINITY), |(min, max), v| {
+            (min.min(v.loc.x), max.max(v.loc.x))
+        })
+    }
+
+    /// The length of a polyline.
+    fn length(points: &[Point2]) -> f32 {
+        points.windows(2).map(|pair| (pair[1] - pair[0]).length()).sum()
+    }
+
+    #[test]
+    fn straight() {
+        let points = [p(0.0, 0.0), p(30.0, 0.0)];
+
+        assert_eq!(area(&stroke(&points, false, &StrokeOptions::new(4.0))), 120.0);
+
+        // square caps extend both ends by half the width
+        let options = StrokeOptions::new(4.0).line_cap(LineCap::Square);
+        let mesh = stroke(&points, false, &options);
+
+        assert_eq!(area(&mesh), 136.0);
+        assert_eq!(x_range(&mesh), (-2.0, 32.0));
+    }
+
+    #[test]
+    fn miter_limit() {
+        let options = StrokeOptions::new(2.0);
+        let bevel = options.clone().line_join(LineJoin::Bevel);
+
+        // a right angle is within the limit, adding the corner the bevel
+        // cuts off
+        let corner = [p(0.0, 0.0), p(10.0, 0.0), p(10.0, 10.0)];
+        let miter_area = area(&stroke(&corner, false, &options));
+        let bevel_area = area(&stroke(&corner, false, &bevel));
+
+        assert!(
+            (miter_area - bevel_area - 0.5).abs() < 1e-4,
+            "{miter_area} {bevel_area}"
+        );
+
+        // a sharp turn is beyond the limit, and beveled instead
+        let sharp = [p(0.0, 0.0), p(10.0, 0.0), p(0.0, 1.0)];
+        let miter = stroke(&sharp, false, &options);
+        let beveled = stroke(&sharp, false, &bevel);
+
+        assert_eq!(area(&miter), area(&beveled));
+        assert!(x_range(&miter).1 < 11.0);
+
+        // unless the limit is raised
+        let raised = stroke(&sharp, false, &options.clone().miter_limit(100.0));
+
+        assert!(x_range(&raised).1 > 20.0);
+    }
+
+    #[test]
+    fn dashes() {
+        let line = [p(0.0, 0.0), p(35.0, 0.0)];
+        let lengths = |dashes: Vec<Vec<Point2>>| -> Vec<f32> {
+            dashes.iter().map(|dash| length(dash)).collect()
+        };
+
+        assert_eq!(lengths(dash(&line, false, &[10.0, 5.0], 0.0)), [10.0, 10.0, 5.0]);
+        assert_eq!(lengths(dash(&line, false, &[10.0, 5.0], 5.0)), [5.0, 10.0, 10.0]);
+        // an offset into the gap starts with it
+        assert_eq!(lengths(dash(&line, false, &[10.0, 5.0], 12.0)), [10.0, 10.0, 2.0]);
+
+        // dashes turn corners
+        let square = [p(0.0, 0.0), p(10.0, 0.0), p(10.0, 10.0), p(0.0, 10.0)];
+
+        assert_eq!(lengths(dash(&square, false, &[12.0, 4.0], 0.0)), [12.0, 12.0]);
+        assert_eq!(dash(&square, false, &[12.0, 4.0], 0.0)[0][1], p(10.0, 0.0));
+
+        // ending in a gap at the closing point
+        assert_eq!(lengths(dash(&square, true, &[12.0, 8.0], 0.0)), [12.0, 12.0]);
+
+        // the last dash runs past the closing point into the first
+        let dashes = dash(&square, true, &[15.0, 10.0], 0.0);
+
+        assert_eq!(lengths(dashes.clone()), [30.0]);
+        assert_eq!(dashes[0].first(), Some(&p(5.0, 10.0)));
+        assert_eq!(dashes[0].last(), Some(&p(10.0, 5.0)));
+
+        // so there are only caps at it's two ends, each adding a 1x2 square
+        let butt = StrokeOptions::new(2.0).dashes([15.0, 10.0]);
+        let square_caps = butt.clone().line_cap(LineCap::Square);
+        let added = area(&stroke(&square, true, &square_caps))
+            - area(&stroke(&square, true, &butt));
+
+        assert!((added - 4.0).abs() < 1e-4, "{added}");
+
+        // and a dash all the way around is the same as no dashes
+        let solid = StrokeOptions::new(2.0);
+
+        assert_eq!(
+            stroke(&square, true, &solid.clone().dashes([100.0, 10.0])),
+            stroke(&square, true, &solid),
+        );
+    }
+
+    #[test]
+    fn offset_polygon() {
+        let square: Polygon = [p(0.0, 0.0), p(10.0, 0.0), p(10.0, 10.0), p(0.0, 10.0)]
+            .into_iter()
+            .collect();
+        let mut tessellator = StrokeTessellator::new();
+        let mut area = |distance: f32, join: LineJoin| -> f32 {
+            tessellator
+                .offset_polygon(&square, distance, join, 0.01)
+                .iter()
+                .map(|polygon| -polygon.signed_area())
+                .sum()
+        };
+
+        // the sides move out by 2, and the corners are filled differently
+        let round = 100.0 + 4.0 * 20.0 + 4.0 * std::f32::consts::PI;
+
+        assert_eq!(area(2.0, LineJoin::Miter), 14.0 * 14.0);
+        assert_eq!(area(2.0, LineJoin::Bevel), 14.0 * 14.0 - 4.0 * 2.0);
+        assert!((area(2.0, LineJoin::Round) - round).abs() < 0.1);
+
+        // the inner corners of a square need no joins
+        for join in [LineJoin::Miter, LineJoin::Bevel, LineJoin::Round] {
+            assert_eq!(area(-2.0, join), 6.0 * 6.0);
+        }
+
+        // shrinking by half the width or more leaves nothing
+        assert!(tessellator
+            .offset_polygon(&square, -5.0, LineJoin::Miter, 0.01)
+            .is_empty());
+        assert!(tessellator
+            .offset_polygon(&square, -6.0, LineJoin::Round, 0.01)
+            .is_empty());
+    }
+}
@@ -0,0 +1,42 @@
+use wgpu::IndexFormat;
+
+use super::{Index, VertexBuffers};
+
+impl<I> VertexBuffers<I> {
+    /// New empty buffers.
+    #[inline]
+    #[must_use]
+    pub const fn new() -> Self {
+        Self { vertices: Vec::new(), indices: Vec::new() }
+    }
+
+    /// Whether there are no triangles.
+    #[inline]
+    #[must_use]
+    pub fn is_empty(&self) -> bool {
+        self.indices.is_empty()
+    }
+
+    /// Remove all vertices and indices, keeping the allocations.
+    #[inline]
+    pub fn clear(&mut self) {
+        self.vertices.clear();
+        self.indices.clear();
+    }
+}
+
+impl Index for u16 {
+    const FORMAT: IndexFormat = IndexFormat::Uint16;
+
+    fn from_usize(index: usize) -> Option<Self> {
+        index.try_into().ok()
+    }
+}
+
+impl Index for u32 {
+    const FORMAT: IndexFormat = IndexFormat::Uint32;
+
+    fn from_usize(index: usize) -> Option<Self> {
+        index.try_into().ok()
+    }
+}
@@ -74,15 +74,16 @@ impl<T: Pod> GpuBuf<T> {
     ///
     /// Returns `true` if a re-allocation occured.
     pub fn write(&mut self, device: &Device, queue: &Queue, items: &[T]) -> bool {
-        if items.len() > self.cap {
-            self.resize(device, next_copy_size::<T>(items.len()) as _);
+        let realloc = items.len() > self.cap;
 
-            true
-        } else {
-            queue.write_buffer(&self.buffer, 0, bytemuck::cast_slice(items));
-
-            false
+        if realloc {
+            self.resize(device, items.len());
         }
+
+        queue.write_buffer(&self.buffer, 0, bytemuck::cast_slice(items));
+        self.len = items.len();
+
+        realloc
     }
 
     /// The amount of items in the buffer.
@@ -95,6 +96,11 @@ impl<T: Pod> GpuBuf<T> {
         self.cap
     }
 
+    /// The internal buffer.
+    pub fn buffer(&self) -> &Buffer {
+        &self.buffer
+    }
+
     /// A slice into the [buffer on the gpu](Buffer).
     pub fn slice(&self, bounds: impl RangeBounds<BufferAddress>) -> BufferSlice<'_> {
         self.buffer.slice(bounds)
@@ -109,7 +115,7 @@ impl<T: Pod> GpuBuf<T> {
     ///
     /// This clears all existing content.
     pub fn resize(&mut self, device: &Device, cap: usize) {
-        let new = (cap * mem::size_of::<T>()) as BufferAddress;
+        let new = next_copy_size::<T>(cap);
 
         if self.buffer.size() < new {
             self.buffer = device.create_buffer(&BufferDescriptor {
@@ -119,7 +125,7 @@ impl<T: Pod> GpuBuf<T> {
                 mapped_at_creation: false,
             });
             self.len = 0;
-            self.cap = new as _;
+            self.cap = new as usize / mem::size_of::<T>();
         }
     }
 }
@@ -0,0 +1,299 @@
+//! Compares compositing and blending on the GPU with the CPU reference.
+//!
+//! Skipped if there is no GPU adapter.
+
+use crossd_graphics::backend::Backend;
+use crossd_graphics::color::{BlendMode, PorterDuff, PremulColor};
+use crossd_graphics::wgpu::{
+    self,
+    BindGroupDescriptor,
+    BindGroupEntry,
+    BindingResource,
+    BlendState,
+    BufferDescriptor,
+    BufferUsages,
+    Color,
+    ColorTargetState,
+    ColorWrites,
+    CommandEncoderDescriptor,
+    Extent3d,
+    FragmentState,
+    ImageCopyBuffer,
+    ImageDataLayout,
+    LoadOp,
+    MapMode,
+    MultisampleState,
+    Operations,
+    PrimitiveState,
+    RenderPassColorAttachment,
+    RenderPassDescriptor,
+    RenderPipelineDescriptor,
+    ShaderModuleDescriptor,
+    ShaderSource,
+    TextureDescriptor,
+    TextureDimension,
+    TextureFormat,
+    TextureUsages,
+    TextureViewDescriptor,
+    VertexState,
+};
+
+/// The target format, linear so that results aren't encoded.
+const FORMAT: TextureFormat = TextureFormat::Rgba8Unorm;
+
+/// Blend modes that read the backdrop.
+const BLEND_SHADER: &str = include_str!("../src/shader/blend.wgsl");
+
+/// Draw the fragment shader body `frag` with `blend` over a single pixel
+/// cleared to `dst`, and read it back.
+///
+/// With `backdrop`, the pixel is copied into `@group(1)` before drawing, for
+/// [`BLEND_SHADER`].
+fn draw(
+    backend: &Backend,
+    frag: &str,
+    blend: BlendState,
+    dst: PremulColor,
+    backdrop: bool,
+) -> [f32; 4] {
+    let (device, queue) = (backend.device(), backend.queue());
+
+    let mut source = format!(
+        "
+        @vertex
+        fn vert(@builtin(vertex_index) i: u32) -> @builtin(position) vec4<f32> {{
+            // a triangle covering the whole target
+            let uv = vec2<f32>(f32((i << 1u) & 2u), f32(i & 2u));
+
+            return vec4<f32>(uv * 2.0 - 1.0, 0.0, 1.0);
+        }}
+
+        @fragment
+        fn frag(@builtin(position) position: vec4<f32>) -> @location(0) vec4<f32> {{
+            {frag}
+        }}
+        "
+    );
+
+    if backdrop {
+        source.push_str(BLEND_SHADER);
+    }
+
+    let module = device.create_shader_module(ShaderModuleDescriptor {
+        label: None,
+        source: ShaderSource::Wgsl(source.into()),
+    });
+    let pipeline = device.create_render_pipeline(&RenderPipelineDescriptor {
+        label: None,
+        layout: None,
+        vertex: VertexState { module: &module, entry_point: "vert", buffers: &[] },
+        primitive: PrimitiveState::default(),
+        depth_stencil: None,
+        multisample: MultisampleState::default(),
+        fragment: Some(FragmentState {
+            module: &module,
+            entry_point: "frag",
+            targets: &[Some(ColorTargetState {
+                format: FORMAT,
+                blend: Some(blend),
+                write_mask: ColorWrites::ALL,
+            })],
+        }),
+        multiview: None,
+    });
+
+    let size = Extent3d { width: 1, height: 1, depth_or_array_layers: 1 };
+    let texture = device.create_texture(&TextureDescriptor {
+        label: None,
+        size,
+        mip_level_count: 1,
+        sample_count: 1,
+        dimension: TextureDimension::D2,
+        format: FORMAT,
+        usage: TextureUsages::RENDER_ATTACHMENT | TextureUsages::COPY_SRC,
+        view_formats: &[],
+    });
+    let view = texture.create_view(&TextureViewDescriptor::default());
+    let copy = device.create_texture(&TextureDescriptor {
+        label: None,
+        size,
+        mip_level_count: 1,
+        sample_count: 1,
+        dimension: TextureDimension::D2,
+        format: FORMAT,
+        usage: TextureUsages::TEXTURE_BINDING | TextureUsages::COPY_DST,
+        view_formats: &[],
+    });
+    let copy_view = copy.create_view(&TextureViewDescriptor::default());
+    let buffer = device.create_buffer(&BufferDescriptor {
+        label: None,
+        size: u64::from(wgpu::COPY_BYTES_PER_ROW_ALIGNMENT),
+        usage: BufferUsages::COPY_DST | BufferUsages::MAP_READ,
+        mapped_at_creation: false,
+    });
+
+    let mut encoder = device.create_command_encoder(&CommandEncoderDescriptor::default());
+
+    let [r, g, b, a] = dst.to_array().map(f64::from);
+    let attachment = |load| {
+        [Some(RenderPassColorAttachment {
+            view: &view,
+            resolve_target: None,
+            ops: Operations { load, store: true },
+        })]
+    };
+
+    encoder.begin_render_pass(&RenderPassDescriptor {
+        label: None,
+        color_attachments: &attachment(LoadOp::Clear(Color { r, g, b, a })),
+        depth_stencil_attachment: None,
+    });
+
+    let bgroups = backdrop.then(|| {
+        encoder.copy_texture_to_texture(
+            texture.as_image_copy(),
+            copy.as_image_copy(),
+            size,
+        );
+
+        // `@group(0)` is unused, but still needs to be bound
+        let empty = device.create_bind_group(&BindGroupDescriptor {
+            label: None,
+            layout: &pipeline.get_bind_group_layout(0),
+            entries: &[],
+        });
+        let backdrop = device.create_bind_group(&BindGroupDescriptor {
+            label: None,
+            layout: &pipeline.get_bind_group_layout(1),
+            entries: &[BindGroupEntry {
+                binding: 0,
+                resource: BindingResource::TextureView(&copy_view),
+            }],
+        });
+
+        [empty, backdrop]
+    });
+
+    {
+        let mut pass = encoder.begin_render_pass(&RenderPassDescriptor {
+            label: None,
+            color_attachments: &attachment(LoadOp::Load),
+            depth_stencil_attachment: None,
+        });
+
+        pass.set_pipeline(&pipeline);
+
+        for (i, bgroup) in bgroups.iter().flatten().enumerate() {
+            pass.set_bind_group(i as u32, bgroup, &[]);
+        }
+
+        pass.draw(0..3, 0..1);
+    }
+
+    encoder.copy_texture_to_buffer(
+        texture.as_image_copy(),
+        ImageCopyBuffer {
+            buffer: &buffer,
+            layout: ImageDataLayout {
+                offset: 0,
+                bytes_per_row: Some(wgpu::COPY_BYTES_PER_ROW_ALIGNMENT),
+                rows_per_image: None,
+            },
+        },
+        size,
+    );
+    queue.submit([encoder.finish()]);
+
+    let slice = buffer.slice(..);
+    slice.map_async(MapMode::Read, |result| result.unwrap());
+    device.poll(wgpu::Maintain::Wait);
+
+    let data = slice.get_mapped_range();
+
+    [0, 1, 2, 3].map(|i| f32::from(data[i]) / 255.0)
+}
+
+/// A fragment shader body returning `color`.
+fn returning(color: PremulColor) -> String {
+    let [r, g, b, a] = color.to_array();
+
+    format!("return vec4<f32>({r:?}, {g:?}, {b:?}, {a:?});")
+}
+
+#[test]
+fn porter_duff_matches_gpu() {
+    let Ok(backend) = Backend::new_using(FORMAT) else {
+        eprintln!("no GPU adapter, skipping");
+        return;
+    };
+
+    let colors = [
+        PremulColor::new(0.4, 0.0, 0.2, 0.6),
+        PremulColor::new(0.0, 0.5, 0.25, 0.5),
+        PremulColor::new(1.0, 1.0, 1.0, 1.0),
+        PremulColor::TRANSPARENT,
+    ];
+
+    for op in PorterDuff::ALL {
+        for src in colors {
+            for dst in colors {
+                let gpu = draw(&backend, &returning(src), op.blend_state(), dst, false);
+                let cpu = op.composite(src, dst).to_array();
+
+                // the clear color and result are rounded to bytes
+                let close =
+                    gpu.iter().zip(cpu).all(|(gpu, cpu)| (gpu - cpu).abs() < 3.0 / 255.0);
+
+                assert!(
+                    close,
+                    "{op:?} of {src:?} onto {dst:?}: {gpu:?} on the GPU, {cpu:?} on the \
+                     CPU"
+                );
+            }
+        }
+    }
+}
+
+#[test]
+fn blend_modes_match_gpu() {
+    let Ok(backend) = Backend::new_using(FORMAT) else {
+        eprintln!("no GPU adapter, skipping");
+        return;
+    };
+
+    // components that are whole bytes, so that the backdrop isn't rounded
+    let colors = [
+        PremulColor::new(0.4, 0.0, 0.2, 0.6),
+        PremulColor::new(0.0, 0.4, 0.2, 0.8),
+        PremulColor::new(0.2, 0.6, 0.8, 1.0),
+        PremulColor::new(1.0, 1.0, 1.0, 1.0),
+        PremulColor::TRANSPARENT,
+    ];
+
+    for mode in BlendMode::ALL {
+        for src in colors {
+            for dst in colors {
+                // every mode goes through the shader, and is drawn with
+                // source-over like the quads reading the backdrop
+                let [r, g, b, a] = src.to_array();
+                let frag = format!(
+                    "return blend(vec4<f32>({r:?}, {g:?}, {b:?}, {a:?}), {}u, position);",
+                    mode as u32
+                );
+                let blend = PorterDuff::SrcOver.blend_state();
+
+                let gpu = draw(&backend, &frag, blend, dst, true);
+                let cpu = mode.blend(src, dst).to_array();
+
+                let close =
+                    gpu.iter().zip(cpu).all(|(gpu, cpu)| (gpu - cpu).abs() < 3.0 / 255.0);
+
+                assert!(
+                    close,
+                    "{mode:?} of {src:?} onto {dst:?}: {gpu:?} on the GPU, {cpu:?} on \
+                     the CPU"
+                );
+            }
+        }
+    }
+}
@@ -102,3 +102,10 @@ pub const fn mat4<T>(x: [T; 4], y: [T; 4], z: [T; 4], w: [T; 4]) -> Mat4<T> {
 pub const fn vec4<T>(x: T, y: T, z: T, w: T) -> Vec4<T> {
     Vec4::new(x, y, z, w)
 }
+
+/// Shorthand for [`Vec2::new`].
+#[inline]
+#[must_use]
+pub const fn vec2<T>(x: T, y: T) -> Vec2<T> {
+    Vec2::new(x, y)
+}
@@ -1,8 +1,21 @@
 use std::mem::ManuallyDrop;
+use std::ops::{
+    Add,
+    AddAssign,
+    Deref,
+    DerefMut,
+    Div,
+    DivAssign,
+    Mul,
+    MulAssign,
+    Sub,
+    SubAssign,
+};
 
 use bytemuck::{Pod, Zeroable};
+use mint::IntoMint;
 
-use super::Vec2;
+use super::{NegOne, Num, One, Vec2, Zero};
 
 /// A union for casting between different representations of a [`Vec2`].
 #[repr(C)]
@@ -61,5 +74,223 @@ impl<T: Copy> Vec2<T> {
     }
 }
 
+impl<T: Num> Vec2<T> {
+    /// The dot product of two vectors.
+    #[inline]
+    #[must_use]
+    pub fn dot(self, rhs: Self) -> T {
+        self.x * rhs.x + self.y * rhs.y
+    }
+
+    /// The z component of the 3D cross product of two vectors.
+    ///
+    /// Positive when `rhs` is counter-clockwise from `self` (in a y-up
+    /// coordinate system).
+    #[inline]
+    #[must_use]
+    pub fn cross(self, rhs: Self) -> T {
+        self.x * rhs.y - self.y * rhs.x
+    }
+
+    /// Multiply each component by `v`.
+    #[inline]
+    #[must_use]
+    pub fn scale(self, v: T) -> Self {
+        Self::new(self.x * v, self.y * v)
+    }
+}
+
+impl Vec2<f32> {
+    /// The length of the vector.
+    #[inline]
+    #[must_use]
+    pub fn length(self) -> f32 {
+        self.x.hypot(self.y)
+    }
+
+    /// The vector with the same direction and a length of one.
+    ///
+    /// The zero vector has no direction, so the result is `NaN`.
+    #[inline]
+    #[must_use]
+    pub fn normalize(self) -> Self {
+        self.scale(1.0 / self.length())
+    }
+}
+
+// -----------------
+// constants and num
+// -----------------
+
+impl<T: One> Vec2<T> {
+    /// Vector with each component set to `T::ONE`.
+    pub const ONE: Self = Self::splat(T::ONE);
+}
+
+impl<T: Zero> Vec2<T> {
+    /// Vector with each component set to `T::ZERO`.
+    pub const ZERO: Self = Self::splat(T::ZERO);
+}
+
+impl<T: NegOne> Vec2<T> {
+    /// Vector with each component set to `T::NEG_ONE`.
+    pub const NEG_ONE: Self = Self::splat(T::NEG_ONE);
+}
+
+impl<T: One + Zero> Vec2<T> {
+    /// A vector with `x` set to one and `y` set to zero.
+    pub const X: Self = Self::new(T::ONE, T::ZERO);
+    /// A vector with `y` set to one and `x` set to zero.
+    pub const Y: Self = Self::new(T::ZERO, T::ONE);
+}
+
+// ----------
+// operations
+// ----------
+
+// basic ---
+
+impl<T: Num> Add for Vec2<T> {
+    type Output = Self;
+
+    fn add(self, rhs: Self) -> Self::Output {
+        Self::new(self.x + rhs.x, self.y + rhs.y)
+    }
+}
+
+impl<T: Num> Sub for Vec2<T> {
+    type Output = Self;
+
+    fn sub(self, rhs: Self) -> Self::Output {
+        Self::new(self.x - rhs.x, self.y - rhs.y)
+    }
+}
+
+impl<T: Num> Mul for Vec2<T> {
+    type Output = Self;
+
+    fn mul(self, rhs: Self) -> Self::Output {
+        Self::new(self.x * rhs.x, self.y * rhs.y)
+    }
+}
+
+impl<T: Num> Div for Vec2<T> {
+    type Output = Self;
+
+    fn div(self, rhs: Self) -> Self::Output {
+        Self::new(self.x / rhs.x, self.y / rhs.y)
+    }
+}
+
+// assign ---
+
+impl<T: Num> AddAssign for Vec2<T> {
+    fn add_assign(&mut self, rhs: Self) {
+        *self = *self + rhs
+    }
+}
+
+impl<T: Num> SubAssign for Vec2<T> {
+    fn sub_assign(&mut self, rhs: Self) {
+        *self = *self - rhs
+    }
+}
+
+impl<T: Num> MulAssign for Vec2<T> {
+    fn mul_assign(&mut self, rhs: Self) {
+        *self = *self * rhs
+    }
+}
+
+impl<T: Num> DivAssign for Vec2<T> {
+    fn div_assign(&mut self, rhs: Self) {
+        *self = *self / rhs
+    }
+}
+
+// -----------
+// conversions
+// -----------
+
+impl<T> Deref for Vec2<T> {
+    type Target = [T; 2];
+
+    fn deref(&self) -> &Self::Target {
+        unsafe { &*(self as *const Self).cast() }
+    }
+}
+
+impl<T> DerefMut for Vec2<T> {
+    fn deref_mut(&mut self) -> &mut Self::Target {
+        unsafe { &mut *(self as *mut Self).cast() }
+    }
+}
+
+impl<T> IntoMint for Vec2<T> {
+    type MintType = mint::Vector2<T>;
+}
+
+impl<T> From<[T; 2]> for Vec2<T> {
+    fn from(array: [T; 2]) -> Self {
+        Self::from_array(array)
+    }
+}
+
+impl<T> From<Vec2<T>> for [T; 2] {
+    fn from(vec2: Vec2<T>) -> Self {
+        vec2.to_array()
+    }
+}
+
+impl<T> From<Vec2<T>> for mint::Vector2<T> {
+    fn from(vec2: Vec2<T>) -> Self {
+        let Vec2 { x, y } = vec2;
+
+        Self { x, y }
+    }
+}
+
+impl<T> From<mint::Vector2<T>> for Vec2<T> {
+    fn from(mint: mint::Vector2<T>) -> Self {
+        let mint::Vector2 { x, y } = mint;
+
+        Self { x, y }
+    }
+}
+
 unsafe impl<T: Pod> Pod for Vec2<T> {}
 unsafe impl<T: Zeroable> Zeroable for Vec2<T> {}
+
+#[cfg(test)]
+mod test {
+    use super::super::Vec2;
+
+    #[test]
+    fn from_to() {
+        let array = [1.0, 2.0];
+        let tuple = (0, 1);
+
+        assert_eq!(Vec2::from_array(array).to_array(), array);
+        assert_eq!(Vec2::from_tuple(tuple).to_tuple(), tuple);
+    }
+
+    #[test]
+    fn ops() {
+        let a = Vec2::new(1, 2);
+        let b = Vec2::new(3, 4);
+
+        assert_eq!(a + b, Vec2::new(4, 6));
+        assert_eq!(b - a, Vec2::new(2, 2));
+        assert_eq!(a.dot(b), 11);
+        assert_eq!(a.cross(b), -2);
+    }
+
+    #[test]
+    fn length() {
+        let v = Vec2::new(3.0, -4.0);
+
+        assert_eq!(v.length(), 5.0);
+        assert_eq!(v.normalize(), Vec2::new(0.6, -0.8));
+        assert!(Vec2::new(0.0, 0.0).normalize().x.is_nan());
+    }
+}